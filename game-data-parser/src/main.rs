@@ -1,5 +1,5 @@
-use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote, ToTokens};
 use regex::Regex;
 use shared_structs::items_cdragon::ItemDataCdragon;
 
@@ -9,7 +9,7 @@ use serde_json::Value;
 use shared_structs::champions::Champion;
 use shared_structs::items_meraki::ItemDataMeraki;
 use std::collections::HashMap;
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::{prelude::*, BufReader};
 use std::path::Path;
 
@@ -54,6 +54,7 @@ fn item_ids() -> Vec<u64> {
         3110, // Frozen Heart
         6631, // Stridebreaker
         3153, // Blade of the Ruined King
+        3031, // Infinity Edge
     ];
 
     item_ids
@@ -62,74 +63,122 @@ fn item_ids() -> Vec<u64> {
 fn champions_gen() -> Result<(), Box<dyn std::error::Error>> {
     let mut buffer: File = File::create("../league-sim-backend/src/data_input/champions_gen.rs")?;
 
-    let file = File::open("source_3/champions.json").unwrap();
-    let reader: BufReader<File> = BufReader::new(file);
+    // source_3/champions.json holds every champion. Without it, the champions with a file of
+    // their own in source_3/champions are generated
     let characters: HashMap<String, HashMap<String, Value>> =
-        serde_json::from_reader(reader).unwrap();
-    let character = characters.get("Khazix").unwrap();
-
-    let id = character["id"].as_u64().unwrap();
-    let key = character["key"].as_str().unwrap().to_string();
-    let attack_type = character["attackType"].as_str().unwrap();
-    let adaptive_type = character["adaptiveType"].as_str().unwrap();
-
-    let armor_flat = character["stats"]["armor"]["flat"].as_f64().unwrap();
-    let armor_per_level = character["stats"]["armor"]["perLevel"].as_f64().unwrap();
-    let attack_damage_flat = character["stats"]["attackDamage"]["flat"].as_f64().unwrap();
-    let attack_damage_per_level = character["stats"]["attackDamage"]["perLevel"]
-        .as_f64()
-        .unwrap();
-    let attack_speed_flat = character["stats"]["attackSpeed"]["flat"].as_f64().unwrap();
-    let attack_speed_per_level = character["stats"]["attackSpeed"]["perLevel"]
-        .as_f64()
-        .unwrap()
-        / 100.0;
-    let attack_speed_ratio = character["stats"]["attackSpeedRatio"]["flat"]
-        .as_f64()
-        .unwrap();
-    let attack_delay_offset = character["stats"]["attackDelayOffset"]["flat"]
-        .as_f64()
-        .unwrap();
-    let attack_cast_time = character["stats"]["attackCastTime"]["flat"]
-        .as_f64()
-        .unwrap();
-    let attack_total_time = character["stats"]["attackTotalTime"]["flat"]
-        .as_f64()
-        .unwrap();
-    let base_movement_speed = character["stats"]["movespeed"]["flat"].as_f64().unwrap();
+        match File::open("source_3/champions.json") {
+            Ok(file) => serde_json::from_reader(BufReader::new(file)).unwrap(),
+            Err(_) => fs::read_dir("source_3/champions")?
+                .map(|entry| {
+                    let file = File::open(entry.unwrap().path()).unwrap();
+                    let character: HashMap<String, Value> =
+                        serde_json::from_reader(BufReader::new(file)).unwrap();
+                    (character["key"].as_str().unwrap().to_string(), character)
+                })
+                .collect(),
+        };
+
+    // sorted, so that the generated file is stable from one run to the next
+    let mut keys: Vec<&String> = characters.keys().collect();
+    keys.sort();
+
+    let mut variants: Vec<Ident> = Vec::new();
+    let mut champion_data_arms: Vec<TokenStream> = Vec::new();
+    let mut champion_stats_arms: Vec<TokenStream> = Vec::new();
+
+    for key in keys {
+        let character = characters.get(key).unwrap();
+        let variant = format_ident!("{}", key);
+
+        let id = character["id"].as_u64().unwrap();
+        let attack_type = character["attackType"].as_str().unwrap();
+        let adaptive_type = character["adaptiveType"].as_str().unwrap();
+        let resource = character["resource"].as_str().unwrap();
+
+        let stat = |name: &str, field: &str| character["stats"][name][field].as_f64().unwrap();
+
+        let health_flat = stat("health", "flat");
+        let health_per_level = stat("health", "perLevel");
+        let mana_flat = stat("mana", "flat");
+        let mana_per_level = stat("mana", "perLevel");
+        let mana_regen_flat = stat("manaRegen", "flat");
+        let mana_regen_per_level = stat("manaRegen", "perLevel");
+        let armor_flat = stat("armor", "flat");
+        let armor_per_level = stat("armor", "perLevel");
+        let magic_resistance_flat = stat("magicResistance", "flat");
+        let magic_resistance_per_level = stat("magicResistance", "perLevel");
+        let attack_damage_flat = stat("attackDamage", "flat");
+        let attack_damage_per_level = stat("attackDamage", "perLevel");
+        let attack_speed_flat = stat("attackSpeed", "flat");
+        let attack_speed_per_level = stat("attackSpeed", "perLevel") / 100.0;
+        let attack_speed_ratio = stat("attackSpeedRatio", "flat");
+        let attack_delay_offset = stat("attackDelayOffset", "flat");
+        let attack_cast_time = stat("attackCastTime", "flat");
+        let attack_total_time = stat("attackTotalTime", "flat");
+        let base_movement_speed = stat("movespeed", "flat");
+        let attack_range = stat("attackRange", "flat");
+        let critical_strike_damage = stat("criticalStrikeDamage", "flat") / 100.0;
+        let critical_strike_damage_modifier = stat("criticalStrikeDamageModifier", "flat");
+
+        champion_data_arms.push(quote! {
+            Champion::#variant => ChampionData {
+                name: Champion::#variant,
+                id: #id,
+                key: #key.to_string(),
+                attack_type: AttackType::from_str(#attack_type),
+                adaptive_type: AdaptiveType::from_str(#adaptive_type),
+                resource: ResourceType::from_str(#resource),
+            },
+        });
+
+        champion_stats_arms.push(quote! {
+            Champion::#variant => ChampionStats {
+                health_flat: #health_flat,
+                health_per_level: #health_per_level,
+                mana_flat: #mana_flat,
+                mana_per_level: #mana_per_level,
+                mana_regen_flat: #mana_regen_flat,
+                mana_regen_per_level: #mana_regen_per_level,
+                armor_flat: #armor_flat,
+                armor_per_level: #armor_per_level,
+                magic_resistance_flat: #magic_resistance_flat,
+                magic_resistance_per_level: #magic_resistance_per_level,
+                attack_damage_flat: #attack_damage_flat,
+                attack_damage_per_level: #attack_damage_per_level,
+                attack_speed_flat: #attack_speed_flat,
+                attack_speed_per_level: #attack_speed_per_level,
+                attack_speed_ratio: #attack_speed_ratio,
+                attack_delay_offset: #attack_delay_offset,
+                attack_cast_time: #attack_cast_time,
+                attack_total_time: #attack_total_time,
+                base_movement_speed: #base_movement_speed,
+                attack_range: #attack_range,
+                critical_strike_damage: #critical_strike_damage,
+                critical_strike_damage_modifier: #critical_strike_damage_modifier,
+            },
+        });
+
+        variants.push(variant);
+    }
 
     let tokens = quote! {
         use super::{
-            champions::{AdaptiveType, AttackType, ChampionStats},
-            common::Champion,
+            champions::{AdaptiveType, AttackType, ChampionStats, ResourceType},
             ChampionData,
         };
 
+        #[derive(PartialEq, Eq, Hash, Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+        pub enum Champion {
+            #(#variants,)*
+        }
+
         pub fn get_base_champion_stats(champion: Champion) -> (ChampionData, ChampionStats) {
             let champion_data = match champion {
-                Champion::Khazix => ChampionData {
-                    name: Champion::Khazix,
-                    id: #id,
-                    key: #key.to_string(),
-                    attack_type: AttackType::from_str(#attack_type),
-                    adaptive_type: AdaptiveType::from_str(#adaptive_type),
-                },
+                #(#champion_data_arms)*
             };
 
             let champion_stats = match champion {
-                Champion::Khazix => ChampionStats {
-                    armor_flat: #armor_flat,
-                    armor_per_level: #armor_per_level,
-                    attack_damage_flat: #attack_damage_flat,
-                    attack_damage_per_level: #attack_damage_per_level,
-                    attack_speed_flat: #attack_speed_flat,
-                    attack_speed_per_level: #attack_speed_per_level,
-                    attack_speed_ratio: #attack_speed_ratio,
-                    attack_delay_offset: #attack_delay_offset,
-                    attack_cast_time: #attack_cast_time,
-                    attack_total_time: #attack_total_time,
-                    base_movement_speed: #base_movement_speed,
-                },
+                #(#champion_stats_arms)*
             };
 
             (champion_data, champion_stats)
@@ -193,6 +242,7 @@ fn items_meraki_gen(item_ids: &Vec<u64>) -> Result<(), Box<dyn std::error::Error
                         attack_speed: none_if_empty(stats.attack_speed.clone()),
                         cooldown_reduction: none_if_empty(stats.cooldown_reduction.clone()),
                         critical_strike_chance: none_if_empty(stats.critical_strike_chance.clone()),
+                        critical_strike_damage: none_if_empty(stats.critical_strike_damage.clone()),
                         gold_per10: none_if_empty(stats.gold_per10.clone()),
                         heal_and_shield_power: none_if_empty(stats.heal_and_shield_power.clone()),
                         health: none_if_empty(stats.health.clone()),
@@ -252,6 +302,7 @@ fn convert_to_none_if_empty(
         attack_speed: none_if_empty(stats.attack_speed.clone()),
         cooldown_reduction: none_if_empty(stats.cooldown_reduction.clone()),
         critical_strike_chance: none_if_empty(stats.critical_strike_chance.clone()),
+        critical_strike_damage: none_if_empty(stats.critical_strike_damage.clone()),
         gold_per10: none_if_empty(stats.gold_per10.clone()),
         heal_and_shield_power: none_if_empty(stats.heal_and_shield_power.clone()),
         health: none_if_empty(stats.health.clone()),
@@ -275,6 +326,7 @@ fn convert_to_none_if_empty(
         && stats.attack_speed.is_none()
         && stats.cooldown_reduction.is_none()
         && stats.critical_strike_chance.is_none()
+        && stats.critical_strike_damage.is_none()
         && stats.gold_per10.is_none()
         && stats.heal_and_shield_power.is_none()
         && stats.health.is_none()
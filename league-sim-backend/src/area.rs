@@ -0,0 +1,457 @@
+use crate::{
+    attack::AttackType,
+    data_input::{
+        common::{AttackerStats, Aura, AuraApplication, DamageType, GameParams, TargetStats, Unit},
+        items::Item,
+    },
+    simulation::{DamageInfo, DamageSource, State},
+};
+
+// Position on the battlefield, in game units. The primary target stands at the origin.
+#[derive(Debug, Clone, Copy, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Position {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Position {
+    pub fn distance(&self, other: &Position) -> f64 {
+        f64::hypot(self.x - other.x, self.y - other.y)
+    }
+
+    // the position reached after moving the given distance towards the other position.
+    // A negative distance moves away from it
+    pub fn towards(&self, other: &Position, distance: f64) -> Position {
+        let length = self.distance(other);
+        if length == 0.0 {
+            return *self;
+        }
+
+        Position {
+            x: self.x + (other.x - self.x) * distance / length,
+            y: self.y + (other.y - self.y) * distance / length,
+        }
+    }
+}
+
+// units hit by an ability, on top of the primary target which is always hit
+#[derive(Debug, Clone, PartialEq)]
+pub enum HitPattern {
+    Single,
+    // every unit within the radius of the attacker or of the primary target
+    Circle { radius: f64, center: Unit },
+    // every unit within range of the attacker, in the cone aimed at the primary target
+    Cone { range: f64, angle_deg: f64 },
+}
+
+// enemies standing around the primary target. They take damage from the abilities hitting
+// an area and from cleave, but do not trigger the on-hit effects of the items and runes
+#[derive(Debug, Clone)]
+pub struct Battlefield {
+    // at the start of the fight. The attacker then walks to its target (see on_movement)
+    pub attacker_position: Position,
+    pub secondary_targets: Vec<SecondaryTarget>,
+    // the primary target runs away from the attacker at this speed. 0 when it stands still
+    pub target_movement_speed: f64,
+    // once the primary target is dead, the attacker turns to the closest secondary target
+    // instead of ending the fight
+    pub continue_after_kill: bool,
+}
+
+impl Default for Battlefield {
+    fn default() -> Self {
+        Battlefield {
+            attacker_position: DEFAULT_ATTACKER_POSITION,
+            secondary_targets: Vec::new(),
+            target_movement_speed: 0.0,
+            continue_after_kill: false,
+        }
+    }
+}
+
+// in melee range of the primary target
+pub const DEFAULT_ATTACKER_POSITION: Position = Position { x: -125.0, y: 0.0 };
+
+// Kha'Zix: an enemy is isolated when no other enemy stands within this range
+const ISOLATION_RANGE: f64 = 425.0;
+
+// Flash teleports the attacker this far towards its target
+const FLASH_DISTANCE: f64 = 400.0;
+
+// movement speed lost by a slowed target, unless its slow aura holds the strength of the slow
+// (see slow_stacks). The slows vary between their sources and most of them decay over time:
+// an average is used
+const SLOW_PERC: f64 = 0.5;
+
+// the walk times are rounded up to the millisecond
+const RANGE_LEEWAY: f64 = 1.0;
+
+#[derive(Debug, Clone)]
+pub struct SecondaryTarget {
+    pub stats: TargetStats,
+    pub position: Position,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct SecondaryTargetResult {
+    pub damage: f64,
+    pub kill: bool,
+}
+
+// position of the unit at the current time of the fight. Only the attacker and the primary
+// target move, the secondary targets stand still
+pub fn position(unit: Unit, state: &State, game_params: &GameParams) -> Position {
+    match unit {
+        Unit::Attacker => state.attacker_position,
+        Unit::Target => state.target_position,
+        Unit::SecondaryTarget(index) => game_params.battlefield.secondary_targets[index].position,
+    }
+}
+
+pub fn distance_to_target(state: &State, game_params: &GameParams) -> f64 {
+    state
+        .attacker_position
+        .distance(&position(state.target, state, game_params))
+}
+
+pub fn attacker_movement_speed(attacker_stats: &AttackerStats) -> f64 {
+    (attacker_stats.movement_speed_base + attacker_stats.movement_speed_flat_bonus)
+        * (1.0 + attacker_stats.movement_speed_perc_bonus)
+}
+
+// speed at which the current target runs away from the attacker
+pub fn target_movement_speed(state: &State, game_params: &GameParams) -> f64 {
+    if state.target != Unit::Target {
+        return 0.0;
+    }
+
+    let movement_speed = game_params.battlefield.target_movement_speed;
+    match state.target_auras.get(&Aura::Slow) {
+        Some(AuraApplication {
+            stacks: Some(slow_stacks),
+            ..
+        }) => movement_speed * (1.0 - *slow_stacks as f64 / 100.0),
+        Some(_) => movement_speed * (1.0 - SLOW_PERC),
+        None => movement_speed,
+    }
+}
+
+// the stacks of a slow aura are the movement speed lost, in percent
+pub fn slow_stacks(slow_perc: f64) -> u64 {
+    (slow_perc * 100.0).round() as u64
+}
+
+// The primary target runs away from the attacker. When it is not casting, the attacker walks
+// towards its current target until it stands within the given range.
+pub fn on_movement(
+    attacker_stats: &AttackerStats,
+    chase_range: f64,
+    attacker_moves: bool,
+    state: &mut State,
+    game_params: &GameParams,
+    duration_ms: u64,
+) {
+    let duration_s = duration_ms as f64 / 1000.0;
+
+    let flee_distance = target_movement_speed(state, game_params) * duration_s;
+    if flee_distance > 0.0 {
+        state.target_position = state
+            .target_position
+            .towards(&state.attacker_position, -flee_distance);
+    }
+
+    if attacker_moves {
+        let target_position = position(state.target, state, game_params);
+        let walk_distance = f64::min(
+            attacker_movement_speed(attacker_stats) * duration_s,
+            f64::max(distance_to_target(state, game_params) - chase_range, 0.0),
+        );
+        state.attacker_position = state
+            .attacker_position
+            .towards(&target_position, walk_distance);
+    }
+}
+
+// time the attacker needs to walk within range of its current target. None when the target
+// runs away faster than the attacker can chase it
+pub fn walk_time_ms(
+    range: f64,
+    attacker_stats: &AttackerStats,
+    state: &State,
+    game_params: &GameParams,
+) -> Option<u64> {
+    let gap = distance_to_target(state, game_params) - range;
+    if gap <= RANGE_LEEWAY {
+        return Some(0);
+    }
+
+    let closing_speed =
+        attacker_movement_speed(attacker_stats) - target_movement_speed(state, game_params);
+    (closing_speed > 0.0).then(|| (gap / closing_speed * 1000.0).ceil() as u64)
+}
+
+// the dashes land the attacker within attack range of its target. Flash only covers a fixed
+// distance
+pub fn on_dash(attack_type: AttackType, state: &mut State, game_params: &GameParams) {
+    let target_position = position(state.target, state, game_params);
+    let gap = distance_to_target(state, game_params) - game_params.champion_stats.attack_range;
+    let dash_distance = match attack_type {
+        AttackType::Flash => f64::min(gap, FLASH_DISTANCE),
+        _ => gap,
+    };
+
+    if dash_distance > 0.0 {
+        state.attacker_position = state
+            .attacker_position
+            .towards(&target_position, dash_distance);
+    }
+}
+
+impl HitPattern {
+    // the target is the current target of the attacker
+    fn hits(
+        &self,
+        position: &Position,
+        attacker_position: Position,
+        target_position: Position,
+    ) -> bool {
+        match self {
+            HitPattern::Single => false,
+            HitPattern::Circle { radius, center } => {
+                let center = match center {
+                    Unit::Attacker => attacker_position,
+                    _ => target_position,
+                };
+                position.distance(&center) <= *radius
+            }
+            HitPattern::Cone { range, angle_deg } => {
+                if position.distance(&attacker_position) > *range {
+                    return false;
+                }
+
+                let aim = f64::atan2(
+                    target_position.y - attacker_position.y,
+                    target_position.x - attacker_position.x,
+                );
+                let angle = f64::atan2(
+                    position.y - attacker_position.y,
+                    position.x - attacker_position.x,
+                );
+                // difference of the two angles, within [0, PI]
+                let delta = (angle - aim).rem_euclid(std::f64::consts::TAU);
+                let delta = f64::min(delta, std::f64::consts::TAU - delta);
+
+                delta <= angle_deg.to_radians() / 2.0
+            }
+        }
+    }
+}
+
+// indices of the secondary targets still alive and hit by the pattern, on top of the
+// current target of the attacker
+pub fn secondary_targets_hit(
+    hit_pattern: &HitPattern,
+    state: &State,
+    game_params: &GameParams,
+) -> Vec<usize> {
+    let battlefield = game_params.battlefield;
+    let target_position = position(state.target, state, game_params);
+
+    battlefield
+        .secondary_targets
+        .iter()
+        .enumerate()
+        .filter(|(index, secondary_target)| {
+            state.target != Unit::SecondaryTarget(*index)
+                && secondary_target_health(*index, state, game_params) > 0.0
+                && hit_pattern.hits(
+                    &secondary_target.position,
+                    state.attacker_position,
+                    target_position,
+                )
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
+// closest secondary target still alive, once the current target is dead
+pub fn next_target(state: &State, game_params: &GameParams) -> Option<usize> {
+    let battlefield = game_params.battlefield;
+
+    (0..battlefield.secondary_targets.len())
+        .filter(|&index| secondary_target_health(index, state, game_params) > 0.0)
+        .min_by(|&a, &b| {
+            let distance = |index: usize| {
+                battlefield.secondary_targets[index]
+                    .position
+                    .distance(&state.attacker_position)
+            };
+            distance(a).total_cmp(&distance(b))
+        })
+}
+
+// true when no other enemy still alive stands near the unit. The allies of the target left
+// out of the battlefield (e.g. minions) are described by KhazixConfig::allies_nearby
+pub fn is_isolated(unit: Unit, state: &State, game_params: &GameParams) -> bool {
+    if game_params.config.champion.khazix.allies_nearby {
+        return false;
+    }
+
+    let battlefield = game_params.battlefield;
+    let unit_position = position(unit, state, game_params);
+    let target_alive = game_params.initial_target_stats.current_health > state.total_damage;
+    let other_enemies = target_alive.then_some(Unit::Target).into_iter().chain(
+        (0..battlefield.secondary_targets.len())
+            .filter(|&index| secondary_target_health(index, state, game_params) > 0.0)
+            .map(Unit::SecondaryTarget),
+    );
+
+    other_enemies
+        .filter(|&other| other != unit)
+        .all(|other| position(other, state, game_params).distance(&unit_position) > ISOLATION_RANGE)
+}
+
+// stats of the secondary target, with the damage taken so far
+pub fn secondary_target_stats(
+    index: usize,
+    state: &State,
+    game_params: &GameParams,
+) -> TargetStats {
+    let secondary_target = &game_params.battlefield.secondary_targets[index];
+
+    TargetStats {
+        current_health: secondary_target_health(index, state, game_params),
+        ..secondary_target.stats.clone()
+    }
+}
+
+fn secondary_target_health(index: usize, state: &State, game_params: &GameParams) -> f64 {
+    game_params.battlefield.secondary_targets[index]
+        .stats
+        .current_health
+        - state.secondary_targets[index].damage_taken
+}
+
+// deals damage to the secondary targets hit by the pattern. The damage of each target is
+// computed from its own stats. Returns the indices of the targets hit
+#[allow(clippy::too_many_arguments)]
+pub fn on_area_damage(
+    hit_pattern: &HitPattern,
+    damage_type: DamageType,
+    source: DamageSource,
+    source_ability: Option<AttackType>,
+    source_item: Option<Item>,
+    state: &mut State,
+    game_params: &GameParams,
+    mitigated_damage: impl Fn(&TargetStats) -> f64,
+) -> Vec<usize> {
+    let targets_hit = secondary_targets_hit(hit_pattern, state, game_params);
+
+    for &index in targets_hit.iter() {
+        let damage = mitigated_damage(&secondary_target_stats(index, state, game_params));
+
+        state.secondary_targets[index].damage_taken += damage;
+        state.damage_history.push(DamageInfo {
+            amount: damage,
+            damage_type,
+            time_ms: state.time_ms,
+            source: source.clone(),
+            source_ability,
+            source_rune: None,
+            source_item,
+            source_unit: Unit::Attacker,
+            target_unit: Unit::SecondaryTarget(index),
+        });
+    }
+
+    targets_hit
+}
+
+// the auras of the secondary targets have no callback, they only expire
+pub fn add_secondary_target_aura(index: usize, aura: Aura, duration: u64, state: &mut State) {
+    state.secondary_targets[index].auras.insert(
+        aura,
+        AuraApplication {
+            aura,
+            start_ms: state.time_ms,
+            end_ms: Some(state.time_ms + duration),
+            stacks: None,
+        },
+    );
+}
+
+// damage taken by each secondary target, read from the damage history of the fight
+pub fn secondary_target_results(
+    damage_history: &[DamageInfo],
+    battlefield: &Battlefield,
+) -> Vec<SecondaryTargetResult> {
+    battlefield
+        .secondary_targets
+        .iter()
+        .enumerate()
+        .map(|(index, secondary_target)| {
+            let damage: f64 = damage_history
+                .iter()
+                .filter(|damage_info| damage_info.target_unit == Unit::SecondaryTarget(index))
+                .map(|damage_info| damage_info.amount)
+                .sum();
+
+            SecondaryTargetResult {
+                damage,
+                kill: damage >= secondary_target.stats.current_health,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod area_tests {
+    use super::*;
+
+    const ATTACKER: Position = Position { x: -100.0, y: 0.0 };
+    const TARGET: Position = Position { x: 0.0, y: 0.0 };
+
+    #[test]
+    fn circles_are_centered_on_the_attacker_or_the_primary_target() {
+        let around_target = HitPattern::Circle {
+            radius: 300.0,
+            center: Unit::Target,
+        };
+        let around_attacker = HitPattern::Circle {
+            radius: 300.0,
+            center: Unit::Attacker,
+        };
+        let behind_target = Position { x: 250.0, y: 0.0 };
+
+        assert!(around_target.hits(&behind_target, ATTACKER, TARGET));
+        assert!(!around_attacker.hits(&behind_target, ATTACKER, TARGET));
+        assert!(!HitPattern::Single.hits(&behind_target, ATTACKER, TARGET));
+    }
+
+    #[test]
+    fn positions_move_towards_or_away_from_another_position() {
+        let closer = ATTACKER.towards(&TARGET, 40.0);
+        let further = TARGET.towards(&ATTACKER, -40.0);
+
+        assert_eq!(closer, Position { x: -60.0, y: 0.0 });
+        assert_eq!(further, Position { x: 40.0, y: 0.0 });
+        // no direction to move to
+        assert_eq!(TARGET.towards(&TARGET, 40.0), TARGET);
+    }
+
+    #[test]
+    fn cones_are_aimed_at_the_primary_target() {
+        let cone = HitPattern::Cone {
+            range: 450.0,
+            angle_deg: 90.0,
+        };
+
+        // 39 degrees away from the aim, within the cone
+        assert!(cone.hits(&Position { x: 150.0, y: 200.0 }, ATTACKER, TARGET));
+        assert!(!cone.hits(&Position { x: 0.0, y: 200.0 }, ATTACKER, TARGET));
+        // behind the attacker
+        assert!(!cone.hits(&Position { x: -200.0, y: 0.0 }, ATTACKER, TARGET));
+        // out of range
+        assert!(!cone.hits(&Position { x: 400.0, y: 0.0 }, ATTACKER, TARGET));
+    }
+}
@@ -1,15 +1,18 @@
-use std::{
-    collections::{BinaryHeap, HashMap},
-    fmt,
-    ops::{Add, Mul},
-};
+use std::{collections::BinaryHeap, fmt};
+
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
 
 use crate::{
+    area,
     data_input::{
-        abilities::{find_ability, SpellData},
+        abilities::{find_ability, find_ability_for_cast, CastContext, SpellData},
         common::{AttackerStats, CritHandlingChoice, DamageType, GameParams, TargetStats},
+        config::ScenarioConfig,
+        items::Item,
     },
-    simulation::{Event, State},
+    error::SimError,
+    simulation::{DamageSource, Event, State},
 };
 
 #[derive(Debug, PartialEq)]
@@ -19,7 +22,7 @@ pub struct SpellResult {
     pub cooldown: Option<u64>,
 }
 
-#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash, serde::Serialize)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash, serde::Serialize, serde::Deserialize)]
 pub enum AttackType {
     AA,
     Q,
@@ -27,18 +30,54 @@ pub enum AttackType {
     E,
     R,
     P,
-    // add item active?
+    // summoner spells
+    Ignite,
+    Smite,
+    Flash,
+    // item actives
+    ProfaneHydra,
+    RavenousHydra,
+    YoumuusGhostblade,
+    Stridebreaker,
+    RanduinsOmen,
 }
 impl AttackType {
-    pub(crate) fn from_str(ability: &str) -> AttackType {
+    // parses a command provided by the user. The passive is not a castable command.
+    pub(crate) fn from_str(ability: &str) -> Option<AttackType> {
         match ability {
-            "AA" => AttackType::AA,
-            "Q" => AttackType::Q,
-            "W" => AttackType::W,
-            "E" => AttackType::E,
-            "R" => AttackType::R,
-            "P" => AttackType::P,
-            _ => panic!(),
+            "AA" => Some(AttackType::AA),
+            "Q" => Some(AttackType::Q),
+            "W" => Some(AttackType::W),
+            "E" => Some(AttackType::E),
+            "R" => Some(AttackType::R),
+            "Ignite" => Some(AttackType::Ignite),
+            "Smite" => Some(AttackType::Smite),
+            "Flash" => Some(AttackType::Flash),
+            "ProfaneHydra" => Some(AttackType::ProfaneHydra),
+            "RavenousHydra" => Some(AttackType::RavenousHydra),
+            "YoumuusGhostblade" => Some(AttackType::YoumuusGhostblade),
+            "Stridebreaker" => Some(AttackType::Stridebreaker),
+            "RanduinsOmen" => Some(AttackType::RanduinsOmen),
+            _ => None,
+        }
+    }
+
+    pub fn is_summoner_spell(&self) -> bool {
+        matches!(
+            self,
+            AttackType::Ignite | AttackType::Smite | AttackType::Flash
+        )
+    }
+
+    // the item whose active is cast by the command
+    pub fn item(&self) -> Option<Item> {
+        match self {
+            AttackType::ProfaneHydra => Some(Item::ProfaneHydra),
+            AttackType::RavenousHydra => Some(Item::RavenousHydra),
+            AttackType::YoumuusGhostblade => Some(Item::YoumuusGhostblade),
+            AttackType::Stridebreaker => Some(Item::Stridebreaker),
+            AttackType::RanduinsOmen => Some(Item::RanduinsOmen),
+            _ => None,
         }
     }
 }
@@ -65,46 +104,155 @@ pub fn simulate_spell(
     spell_name: AttackType,
     event: &Event,
     events: &mut BinaryHeap<Event>,
-) -> SpellResult {
+) -> Result<SpellResult, SimError> {
     let mut ability: Option<&SpellData> = None;
     if spell_name != AttackType::AA {
-        ability = Some(find_ability(
+        let cast_context = CastContext {
+            isolated_target: area::is_isolated(state.target, state, game_params),
+        };
+        ability = Some(find_ability_for_cast(
             game_params.abilities,
             spell_name,
-            game_params.initial_config,
+            game_params.config,
+            &cast_context,
         ));
     }
 
-    let mut spell_result: SpellResult = match spell_name {
-        AttackType::AA => simulate_aa(attacker_stats, target_stats, game_params.crit_handling),
-        AttackType::Q => simulate_q(attacker_stats, target_stats, ability.unwrap()),
-        AttackType::W => simulate_w(attacker_stats, target_stats, ability.unwrap()),
-        AttackType::E => simulate_e(attacker_stats, target_stats, ability.unwrap()),
-        AttackType::R => simulate_r(attacker_stats, ability.unwrap()),
-        AttackType::P => panic!(),
-        // &_ => todo!(),
+    let command_index = current_command_index(state);
+    let spell_result: SpellResult = match spell_name {
+        AttackType::AA => simulate_aa(
+            attacker_stats,
+            target_stats,
+            game_params.crit_handling,
+            state.rng,
+        ),
+        AttackType::Q | AttackType::W | AttackType::E | AttackType::R => simulate_ability(
+            attacker_stats,
+            target_stats,
+            ability.unwrap(),
+            command_index,
+        )?,
+        AttackType::Ignite
+        | AttackType::Smite
+        | AttackType::Flash
+        | AttackType::ProfaneHydra
+        | AttackType::RavenousHydra
+        | AttackType::YoumuusGhostblade
+        | AttackType::Stridebreaker
+        | AttackType::RanduinsOmen => {
+            ensure_item_equipped(spell_name, game_params, command_index)?;
+            simulate_single_rank_spell(attacker_stats, target_stats, ability.unwrap())
+        }
+        AttackType::P => {
+            return Err(SimError::UnknownCommand {
+                command: spell_name.to_string(),
+                command_index,
+            })
+        }
     };
 
-    if ability.is_some() && ability.unwrap().active_effect.is_some() {
-        ability.unwrap().active_effect.unwrap().on_effect(
-            attacker_stats,
-            state,
-            game_params,
-            event,
-            events,
-        );
+    if let (Some(ability), Some(damage_type)) = (ability, spell_result.damage_type) {
+        on_spell_area_damage(attacker_stats, game_params, state, ability, damage_type)?;
+    }
+
+    if let Some(active_effect) = ability.and_then(|ability| ability.active_effect) {
+        active_effect.on_effect(attacker_stats, state, game_params, event, events);
     }
 
     // println!("damage: {:#?}", damage);
 
-    return spell_result;
+    Ok(spell_result)
+}
+
+// the secondary targets in the area of the ability take the same damage as the primary
+// target, mitigated by their own resistances. Summoner spells and item actives are left out:
+// their damage (if any) is dealt to the primary target only, or by their active effect
+fn on_spell_area_damage(
+    attacker_stats: &AttackerStats,
+    game_params: &GameParams,
+    state: &mut State,
+    ability: &SpellData,
+    damage_type: DamageType,
+) -> Result<(), SimError> {
+    if !matches!(
+        ability.attack_type,
+        AttackType::Q | AttackType::W | AttackType::E | AttackType::R
+    ) {
+        return Ok(());
+    }
+
+    let spell_rank = spell_rank(attacker_stats, ability)?;
+    area::on_area_damage(
+        &ability.hit_pattern,
+        damage_type,
+        DamageSource::Ability,
+        Some(ability.attack_type),
+        None,
+        state,
+        game_params,
+        |target_stats| compute_ability_damage(attacker_stats, target_stats, ability, spell_rank),
+    );
+
+    Ok(())
+}
+
+// the command being executed is the last one that has been scheduled
+fn current_command_index(state: &State) -> usize {
+    state.attack_history.len().saturating_sub(1)
+}
+
+fn ensure_spell_learned(
+    ability: &SpellData,
+    spell_rank: u64,
+    command_index: usize,
+) -> Result<(), SimError> {
+    if spell_rank == 0 {
+        return Err(SimError::SpellNotLearned {
+            command: ability.attack_type,
+            command_index,
+        });
+    }
+
+    Ok(())
+}
+
+fn ensure_item_equipped(
+    attack_type: AttackType,
+    game_params: &GameParams,
+    command_index: usize,
+) -> Result<(), SimError> {
+    if attack_type
+        .item()
+        .is_some_and(|item| !is_item_equipped(item, game_params))
+    {
+        return Err(SimError::ItemNotEquipped {
+            command: attack_type,
+            command_index,
+        });
+    }
+
+    Ok(())
+}
+
+pub fn is_item_equipped(item: Item, game_params: &GameParams) -> bool {
+    game_params
+        .items
+        .iter()
+        .any(|item_data| item_data.item == item)
+}
+
+fn level_out_of_range(level: u64) -> SimError {
+    SimError::invalid_input(
+        "champion.level",
+        format!("level must be between 1 and 18, got {}", level),
+    )
 }
 
 pub fn cast_time(
     attacker_stats: &AttackerStats,
     spell_name: AttackType,
-    config: &HashMap<String, String>,
-    abilities: &Vec<SpellData>,
+    config: &ScenarioConfig,
+    abilities: &[SpellData],
 ) -> u64 {
     if spell_name != AttackType::AA {
         let ability: &SpellData = find_ability(abilities, spell_name, config);
@@ -140,13 +288,6 @@ fn compute_ability_damage(
     // config: &HashMap<String, String>,
     spell_rank: u64,
 ) -> f64 {
-    if spell_rank == 0 {
-        panic!(
-            "the following ability has not been learned yet: {:#?}",
-            ability.key
-        );
-    }
-
     let base_damage: &f64 = ability.ad_damage.get(&spell_rank).unwrap();
     // println!("1 base_damage: {:#?}", base_damage);
 
@@ -176,14 +317,11 @@ fn simulate_aa(
     attacker_stats: &AttackerStats,
     target_stats: &TargetStats,
     crit_handling: CritHandlingChoice,
+    rng: &mut ChaCha8Rng,
 ) -> SpellResult {
     let base_damage: f64 = attacker_stats.ad_base + attacker_stats.ad_bonus;
-    let crit_damage: f64 = if attacker_stats.crit_chance > 0.0 {
-        base_damage * 1.75
-    } else {
-        base_damage
-    };
-    let avg_damage: f64 = base_damage * (1.0 + attacker_stats.crit_chance * 0.75);
+    let crit_multiplier = attacker_stats.crit_damage * attacker_stats.crit_damage_modifier;
+    let crit_damage: f64 = base_damage * crit_multiplier;
 
     // println!("1 base_damage: {:#?}", base_damage);
 
@@ -194,125 +332,94 @@ fn simulate_aa(
     let cooldown = (1000.0_f64 / total_attack_speed(attacker_stats)).round() as u64;
 
     let damage = match crit_handling {
-        CritHandlingChoice::Min => compute_mitigated_damage(
-            attacker_stats,
-            target_stats,
-            base_damage,
-            DamageType::Physical,
-        ),
-        CritHandlingChoice::Max => compute_mitigated_damage(
-            attacker_stats,
-            target_stats,
-            crit_damage,
-            DamageType::Physical,
-        ),
-        CritHandlingChoice::Avg => compute_mitigated_damage(
-            attacker_stats,
-            target_stats,
-            avg_damage,
-            DamageType::Physical,
-        ),
+        CritHandlingChoice::Min => base_damage,
+        CritHandlingChoice::Max => {
+            if attacker_stats.crit_chance > 0.0 {
+                crit_damage
+            } else {
+                base_damage
+            }
+        }
+        CritHandlingChoice::Avg => {
+            base_damage * (1.0 + attacker_stats.crit_chance * (crit_multiplier - 1.0))
+        }
+        CritHandlingChoice::Random { .. } => {
+            if rng.gen::<f64>() < attacker_stats.crit_chance {
+                crit_damage
+            } else {
+                base_damage
+            }
+        }
     };
 
     SpellResult {
-        damage: Some(damage),
+        damage: Some(compute_mitigated_damage(
+            attacker_stats,
+            target_stats,
+            damage,
+            DamageType::Physical,
+        )),
         cooldown: Some(cooldown),
         damage_type: Some(DamageType::Physical),
     }
 }
 
-fn simulate_q(
+// Q, W, E and R all follow the same rules: the rank comes from the skill order,
+// the damage (if any) from the rank and ratios of the ability.
+fn simulate_ability(
     attacker_stats: &AttackerStats,
     target_stats: &TargetStats,
     ability: &SpellData,
-) -> SpellResult {
-    let spell_rank: u64 = match attacker_stats.level {
-        1..=3 => 1,
-        4 => 2,
-        5..=6 => 3,
-        7..=8 => 4,
-        9..=18 => 5,
-        0_u64 | 19_u64..=u64::MAX => panic!(),
-    };
+    command_index: usize,
+) -> Result<SpellResult, SimError> {
+    let spell_rank = spell_rank(attacker_stats, ability)?;
 
-    return SpellResult {
-        damage: Some(compute_ability_damage(
-            attacker_stats,
-            target_stats,
-            ability,
-            spell_rank,
-        )),
-        cooldown: cooldown(ability, spell_rank, attacker_stats),
-        damage_type: Some(DamageType::Physical),
-    };
-}
+    ensure_spell_learned(ability, spell_rank, command_index)?;
 
-fn simulate_w(
-    attacker_stats: &AttackerStats,
-    target_stats: &TargetStats,
-    ability: &SpellData,
-) -> SpellResult {
-    let spell_rank = match attacker_stats.level {
-        1 => 0,
-        2..=7 => 1,
-        8..=9 => 2,
-        10..=11 => 3,
-        12 => 4,
-        13..=18 => 5,
-        0_u64 | 19_u64..=u64::MAX => panic!(),
-    };
+    let damage = ability
+        .damage_type
+        .map(|_| compute_ability_damage(attacker_stats, target_stats, ability, spell_rank));
 
-    SpellResult {
-        damage: Some(compute_ability_damage(
-            attacker_stats,
-            target_stats,
-            ability,
-            spell_rank,
-        )),
+    Ok(SpellResult {
+        damage,
         cooldown: cooldown(ability, spell_rank, attacker_stats),
-        damage_type: Some(DamageType::Physical),
-    }
+        damage_type: ability.damage_type,
+    })
 }
 
-fn simulate_e(
+// summoner spells and item actives have a single rank and are not affected by the damage
+// modifiers of abilities
+fn simulate_single_rank_spell(
     attacker_stats: &AttackerStats,
     target_stats: &TargetStats,
     ability: &SpellData,
 ) -> SpellResult {
-    let spell_rank = match attacker_stats.level {
-        1..=2 => 0,
-        3..=13 => 1,
-        14 => 2,
-        15..=16 => 3,
-        17 => 4,
-        18 => 5,
-        0_u64 | 19_u64..=u64::MAX => panic!(),
+    // the damage of summoner spells depends on the level, see pull_summoner_spells
+    let damage_key = if ability.attack_type.is_summoner_spell() {
+        attacker_stats.level
+    } else {
+        1
     };
-
-    SpellResult {
-        damage: Some(compute_ability_damage(
+    let damage = ability.damage_type.map(|damage_type| {
+        compute_mitigated_damage(
             attacker_stats,
             target_stats,
-            ability,
-            spell_rank,
-        )),
-        cooldown: cooldown(ability, spell_rank, attacker_stats),
-        damage_type: Some(DamageType::Physical),
+            *ability.ad_damage.get(&damage_key).unwrap(),
+            damage_type,
+        )
+    });
+
+    SpellResult {
+        damage,
+        cooldown: cooldown(ability, 1, attacker_stats),
+        damage_type: ability.damage_type,
     }
 }
-fn simulate_r(attacker_stats: &AttackerStats, ability: &SpellData) -> SpellResult {
-    let spell_rank = match attacker_stats.level {
-        1..=5 => 0,
-        6..=10 => 1,
-        11..=15 => 2,
-        16..=18 => 3,
-        0_u64 | 19_u64..=u64::MAX => panic!(),
-    };
 
-    SpellResult {
-        damage: None,
-        cooldown: cooldown(ability, spell_rank, attacker_stats),
-        damage_type: None,
+fn spell_rank(attacker_stats: &AttackerStats, ability: &SpellData) -> Result<u64, SimError> {
+    match attacker_stats.level {
+        1..=18 => Ok(attacker_stats.spell_rank(ability.attack_type)),
+        level => Err(level_out_of_range(level)),
     }
 }
 
@@ -326,57 +433,107 @@ pub fn compute_mitigated_damage(
         return base_damage;
     }
 
-    let mut damage_resistance = match damage_type {
-        DamageType::Physical => target_stats.armor,
-        DamageType::Magical => target_stats.magic_resistance,
+    let (resistance, resistance_bonus, modifiers) = match damage_type {
+        DamageType::Physical => (
+            target_stats.armor,
+            target_stats.armor_bonus,
+            ResistanceModifiers {
+                reduction_flat: target_stats.armor_reduction_flat,
+                reduction_perc: target_stats.armor_reduction_perc,
+                penetration_perc: attacker_stats.armor_penetration_perc,
+                penetration_bonus_perc: attacker_stats.armor_penetration_bonus_perc,
+                penetration_flat: attacker_stats.lethality,
+            },
+        ),
+        DamageType::Magical => (
+            target_stats.magic_resistance,
+            target_stats.magic_resistance_bonus,
+            ResistanceModifiers {
+                reduction_flat: target_stats.magic_resistance_reduction_flat,
+                reduction_perc: target_stats.magic_resistance_reduction_perc,
+                penetration_perc: attacker_stats.magic_penetration_perc,
+                penetration_bonus_perc: 0.0,
+                penetration_flat: attacker_stats.magic_penetration_flat,
+            },
+        ),
         DamageType::True => panic!(),
         DamageType::Unknown => panic!(),
     };
 
-    // println!("3 armor: {:#?}", armor);
+    let damage_resistance = effective_resistance(resistance, resistance_bonus, &modifiers);
 
-    // todo: 1) reduction, flat
-    // todo: 2) reduction, %
+    // see https://wiki.leagueoflegends.com/en-us/Armor#Damage_reduction
+    if damage_resistance >= 0.0 {
+        base_damage * 100.0 / (100.0 + damage_resistance)
+    } else {
+        base_damage * (2.0 - 100.0 / (100.0 - damage_resistance))
+    }
+}
 
-    // 3) penetration, %
-    let penetration_perc = match damage_type {
-        DamageType::Physical => attacker_stats.armor_penetration_perc,
-        DamageType::Magical => 0.0,
-        DamageType::True => panic!(),
-        DamageType::Unknown => panic!(),
-    };
+struct ResistanceModifiers {
+    reduction_flat: f64,
+    reduction_perc: f64,
+    penetration_perc: f64,
+    penetration_bonus_perc: f64,
+    penetration_flat: f64,
+}
 
-    damage_resistance *= 1.0 - penetration_perc;
+// resistance of the target once the reductions and the penetrations are applied, in this order:
+// flat reduction, % reduction, % penetration (% bonus penetration on the bonus part), flat penetration.
+// see https://wiki.leagueoflegends.com/en-us/Armor_penetration#Order_of_application
+fn effective_resistance(
+    resistance: f64,
+    resistance_bonus: f64,
+    modifiers: &ResistanceModifiers,
+) -> f64 {
+    let mut base = resistance - resistance_bonus;
+    let mut bonus = resistance_bonus;
+
+    // 1) reduction, flat. Split between base and bonus resistance based on their share
+    // of the total. It is the only step that can bring the resistance below 0
+    if resistance > 0.0 {
+        base -= modifiers.reduction_flat * base / resistance;
+        bonus -= modifiers.reduction_flat * bonus / resistance;
+    } else {
+        base -= modifiers.reduction_flat;
+    }
 
-    // 4) penetration, flat
-    let penetration_flat = match damage_type {
-        DamageType::Physical => attacker_stats.lethality,
-        DamageType::Magical => 0.0,
-        DamageType::True => panic!(),
-        DamageType::Unknown => panic!(),
-    };
-    damage_resistance = (damage_resistance - penetration_flat).max(0.0);
+    // the other steps only apply to a positive resistance
+    if base + bonus <= 0.0 {
+        return base + bonus;
+    }
 
-    // println!("5 armor: {:#?}", armor);
+    // 2) reduction, %
+    base *= 1.0 - modifiers.reduction_perc;
+    bonus *= 1.0 - modifiers.reduction_perc;
 
-    base_damage * 100.0 / (100.0 + damage_resistance)
+    // 3) penetration, %
+    base *= 1.0 - modifiers.penetration_perc;
+    bonus *= (1.0 - modifiers.penetration_perc) * (1.0 - modifiers.penetration_bonus_perc);
+
+    // 4) penetration, flat
+    (base + bonus - modifiers.penetration_flat).max(0.0)
 }
 
 fn cooldown(ability: &SpellData, spell_rank: u64, attacker_stats: &AttackerStats) -> Option<u64> {
-    if ability.cooldown_ms.is_some() {
-        let base_cd = *ability
-            .cooldown_ms
-            .as_ref()
-            .unwrap()
-            .get(&spell_rank)
-            .unwrap();
-
-        let mut haste = attacker_stats.ability_haste;
-        if ability.key == "R" {
-            haste += attacker_stats.ultimate_haste;
-        } else if vec!["Q", "W", "E"].contains(&ability.key.as_str()) {
-            haste += attacker_stats.basic_ability_haste;
-        }
+    if let Some(cooldown_ms) = &ability.cooldown_ms {
+        let base_cd = *cooldown_ms.get(&spell_rank).unwrap();
+
+        let haste = match ability.attack_type {
+            AttackType::R => attacker_stats.ability_haste + attacker_stats.ultimate_haste,
+            AttackType::Q | AttackType::W | AttackType::E => {
+                attacker_stats.ability_haste + attacker_stats.basic_ability_haste
+            }
+            AttackType::AA | AttackType::P => attacker_stats.ability_haste,
+            // todo: add summoner spell haste. Ability haste does not apply to summoner spells
+            AttackType::Ignite | AttackType::Smite | AttackType::Flash => 0.0,
+            // nor to item actives
+            AttackType::ProfaneHydra
+            | AttackType::RavenousHydra
+            | AttackType::YoumuusGhostblade
+            | AttackType::Stridebreaker
+            | AttackType::RanduinsOmen => 0.0,
+        };
 
         let reduced_cd: u64 = (base_cd as f64 * 100.0 / (100.0 + haste)) as u64;
 
@@ -388,3 +545,141 @@ fn cooldown(ability: &SpellData, spell_rank: u64, attacker_stats: &AttackerStats
         None
     }
 }
+
+#[cfg(test)]
+mod mitigation_tests {
+    use super::*;
+
+    fn modifiers() -> ResistanceModifiers {
+        ResistanceModifiers {
+            reduction_flat: 0.0,
+            reduction_perc: 0.0,
+            penetration_perc: 0.0,
+            penetration_bonus_perc: 0.0,
+            penetration_flat: 0.0,
+        }
+    }
+
+    #[test]
+    fn applies_reductions_then_penetrations() {
+        // 100 armor, of which 50 is bonus armor
+        let resistance = effective_resistance(
+            100.0,
+            50.0,
+            &ResistanceModifiers {
+                reduction_flat: 20.0,
+                reduction_perc: 0.25,
+                penetration_perc: 0.1,
+                penetration_bonus_perc: 0.5,
+                penetration_flat: 10.0,
+            },
+        );
+
+        // flat reduction: 40 base + 40 bonus, % reduction: 30 + 30,
+        // % penetration: 27 + 13.5, flat penetration: 40.5 - 10
+        assert!((resistance - 30.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn only_flat_reduction_goes_below_zero() {
+        let reduced = ResistanceModifiers {
+            reduction_flat: 30.0,
+            reduction_perc: 0.5,
+            penetration_flat: 10.0,
+            ..modifiers()
+        };
+        assert_eq!(effective_resistance(20.0, 0.0, &reduced), -10.0);
+
+        let penetrated = ResistanceModifiers {
+            penetration_flat: 30.0,
+            ..modifiers()
+        };
+        assert_eq!(effective_resistance(20.0, 0.0, &penetrated), 0.0);
+    }
+
+    #[test]
+    fn negative_resistance_amplifies_damage() {
+        let attacker_stats = AttackerStats::default();
+        let target_stats = TargetStats {
+            armor: 0.0,
+            armor_bonus: 0.0,
+            magic_resistance: 50.0,
+            magic_resistance_bonus: 0.0,
+            armor_reduction_flat: 0.0,
+            armor_reduction_perc: 0.0,
+            magic_resistance_reduction_flat: 100.0,
+            magic_resistance_reduction_perc: 0.0,
+            max_health: 1000.0,
+            current_health: 1000.0,
+        };
+
+        let damage =
+            compute_mitigated_damage(&attacker_stats, &target_stats, 100.0, DamageType::Magical);
+
+        // -50 magic resistance: 2 - 100 / 150
+        assert!((damage - 100.0 * (2.0 - 100.0 / 150.0)).abs() < 1e-9);
+    }
+}
+
+#[cfg(test)]
+mod crit_tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    fn attacker_stats() -> AttackerStats {
+        AttackerStats {
+            ad_base: 100.0,
+            crit_chance: 0.5,
+            crit_damage: 1.75,
+            crit_damage_modifier: 0.9,
+            attack_speed_base: 1.0,
+            attack_speed_ratio: 1.0,
+            ..Default::default()
+        }
+    }
+
+    fn target_stats() -> TargetStats {
+        TargetStats {
+            armor: 0.0,
+            armor_bonus: 0.0,
+            magic_resistance: 0.0,
+            magic_resistance_bonus: 0.0,
+            armor_reduction_flat: 0.0,
+            armor_reduction_perc: 0.0,
+            magic_resistance_reduction_flat: 0.0,
+            magic_resistance_reduction_perc: 0.0,
+            max_health: 1000.0,
+            current_health: 1000.0,
+        }
+    }
+
+    fn aa_damage(crit_handling: CritHandlingChoice, rng: &mut ChaCha8Rng) -> f64 {
+        simulate_aa(&attacker_stats(), &target_stats(), crit_handling, rng)
+            .damage
+            .unwrap()
+    }
+
+    #[test]
+    fn crit_damage_includes_the_champion_modifier() {
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+
+        assert!((aa_damage(CritHandlingChoice::Max, rng) - 157.5).abs() < 1e-9);
+        assert!((aa_damage(CritHandlingChoice::Avg, rng) - 128.75).abs() < 1e-9);
+        assert_eq!(aa_damage(CritHandlingChoice::Min, rng), 100.0);
+    }
+
+    #[test]
+    fn random_crits_are_reproducible_from_the_seed() {
+        let roll = |seed: u64| -> Vec<f64> {
+            let rng = &mut ChaCha8Rng::seed_from_u64(seed);
+            (0..50)
+                .map(|_| aa_damage(CritHandlingChoice::Random { seed }, rng))
+                .collect()
+        };
+
+        let rolls = roll(7);
+        assert_eq!(rolls, roll(7));
+        assert!(rolls.contains(&100.0));
+        assert!(rolls.iter().any(|&damage| (damage - 157.5).abs() < 1e-9));
+    }
+}
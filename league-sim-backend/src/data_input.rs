@@ -4,15 +4,27 @@ use abilities::{pull_abilities_data, AbilitiesExtraData, SpellData};
 use champions::{ChampionData, ChampionStats};
 use champions_gen::get_base_champion_stats;
 use common::Champion;
+use config::ScenarioConfig;
+
+use crate::error::SimError;
+use item_actives::pull_item_actives;
 use items::{pull_items_data, ItemData};
 use runes::{pull_runes, RunesData};
+use summoner_spells::pull_summoner_spells;
 
 pub mod abilities;
 pub mod champions;
 pub mod champions_gen;
 pub mod common;
+pub mod config;
+pub mod item_actives;
 pub mod items;
+pub mod kits;
+pub mod rune_page;
 pub mod runes;
+pub mod skill_order;
+pub mod summoner_spells;
+pub mod target;
 
 pub struct StaticData {
     pub items_map: HashMap<u64, ItemData>,
@@ -26,23 +38,25 @@ pub struct StaticData {
 pub fn parse_files(
     champion: Champion,
     item_ids: &[u64],
-    config: &HashMap<String, String>,
-) -> StaticData {
+    config: &ScenarioConfig,
+) -> Result<StaticData, SimError> {
     let (champion_data, base_champion_stats) = get_base_champion_stats(champion);
-    let items_map = pull_items_data(item_ids);
-    let (abilities, abilities_extra_data) = pull_abilities_data(config);
+    let items_map = pull_items_data(item_ids)?;
+    let (mut abilities, abilities_extra_data) = pull_abilities_data(champion, config)?;
+    abilities.extend(pull_summoner_spells());
+    abilities.extend(pull_item_actives());
     let runes_data = pull_runes();
 
     // println!("base_champion_stats: {:#?}", base_champion_stats);
     // println!("items_map: {:#?}", items_map);
     // println!("abilities: {:#?}", abilities);
 
-    StaticData {
+    Ok(StaticData {
         items_map,
         champion_data,
         base_champion_stats,
         abilities,
         abilities_extra_data,
         runes_data,
-    }
+    })
 }
@@ -1,14 +1,21 @@
 use std::collections::HashMap;
 
 use crate::{
+    area::{self, HitPattern},
     attack::{compute_mitigated_damage, AttackType, SpellCategory},
+    error::SimError,
     simulation::{self, DamageInfo},
 };
 
-use shared_structs::champions::*;
+use shared_structs::champions::{Abilities, Ability, Champion};
 
-use super::common::{
-    compute_attacker_stats, compute_target_stats, DamageType, EffectWithCallbacks,
+use super::{
+    common::{
+        compute_attacker_stats, compute_target_stats, Champion as CommonChampion, DamageType,
+        EffectWithCallbacks, Unit,
+    },
+    config::ScenarioConfig,
+    kits::{champion_kit, AbilityDefinition},
 };
 
 // #[derive(Debug)]
@@ -16,32 +23,54 @@ pub struct SpellData {
     pub key: String,
     pub attack_type: AttackType,
     pub coefficient_ad: f64,
+    #[allow(dead_code)]
     pub coefficient_ap: f64,
     pub ad_damage: HashMap<u64, f64>,
+    #[allow(dead_code)]
     pub ap_damage: HashMap<u64, f64>,
     pub variation_name: Option<String>,
+    pub selected_when: Option<fn(&ScenarioConfig, &CastContext) -> bool>,
+    // rank of the ability for each champion level (index 0 is level 1)
+    pub rank_by_level: [u64; 18],
     pub cast_time_ms: Option<u64>,
+    // distance from the target within which the ability can be cast. None for the abilities
+    // cast on the attacker, which need no target in range
+    pub range: Option<f64>,
     pub cooldown_ms: Option<HashMap<u64, u64>>,
+    // mana (or energy) spent per rank. None for the abilities free to cast
+    pub cost: Option<HashMap<u64, f64>>,
     // pub passive_effects: Vec<&'static dyn Effect>,
     pub category: Option<SpellCategory>,
     pub damage_type: Option<DamageType>,
+    // secondary targets hit along with the primary target
+    pub hit_pattern: HitPattern,
     pub active_effect: Option<&'static dyn ScriptedEffect>,
     pub recast_gap_duration: Option<u64>,
     pub recast_charges: Option<u64>,
     pub recast_window: Option<u64>,
+    // the spell can be cast again while a charge is left, see SpellCharges
+    pub charges: Option<SpellCharges>,
+}
+
+// the charges of a spell come back one after the other. The cooldown of the spell only stands
+// between two casts
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpellCharges {
+    pub count: u64,
+    pub recharge_ms: u64,
 }
 
 pub struct UnseenThreat {
-    base_damage: f64,
-    per_level_bonus: f64,
-    bonus_ad_ratio: f64,
+    pub base_damage: f64,
+    pub per_level_bonus: f64,
+    pub bonus_ad_ratio: f64,
 }
 
 impl EffectWithCallbacks for UnseenThreat {
     fn on_post_damage(
         &self,
         damage_info: &DamageInfo,
-        attacker_stats: &super::common::AttackerStats,
+        _attacker_stats: &super::common::AttackerStats,
         state: &mut crate::simulation::State<'_>,
         game_params: &super::common::GameParams<'_>,
         event: &crate::simulation::Event,
@@ -92,7 +121,7 @@ impl EffectWithCallbacks for UnseenThreat {
             events,
         );
 
-        let new_damage_info = DamageInfo {
+        let _new_damage_info = DamageInfo {
             amount: mitigated_dmg,
             damage_type: DamageType::Magical,
             time_ms: state.time_ms,
@@ -100,6 +129,8 @@ impl EffectWithCallbacks for UnseenThreat {
             source_ability: Some(AttackType::P),
             source_rune: None,
             source_item: None,
+            source_unit: Unit::Attacker,
+            target_unit: state.target,
         };
 
         // simulation::on_post_damage_events(
@@ -118,45 +149,50 @@ pub struct AbilitiesExtraData {
 }
 
 pub trait ScriptedEffect {
+    // on cast
     fn on_effect(
         &self,
-        attacker_stats: &super::common::AttackerStats,
-        state: &mut crate::simulation::State<'_>,
-        game_params: &super::common::GameParams<'_>,
-        event: &crate::simulation::Event,
-        events: &mut std::collections::BinaryHeap<crate::simulation::Event>,
-    );
+        _attacker_stats: &super::common::AttackerStats,
+        _state: &mut crate::simulation::State<'_>,
+        _game_params: &super::common::GameParams<'_>,
+        _event: &crate::simulation::Event,
+        _events: &mut std::collections::BinaryHeap<crate::simulation::Event>,
+    ) {
+    }
+
+    // the attacker scored the takedown of the unit
+    fn on_takedown(
+        &self,
+        _unit: Unit,
+        _state: &mut crate::simulation::State<'_>,
+        _game_params: &super::common::GameParams<'_>,
+        _event: &crate::simulation::Event,
+        _events: &mut std::collections::BinaryHeap<crate::simulation::Event>,
+    ) {
+    }
 }
 
-struct KhazixR {
-    base_duration: u64,
-    evolved_duration: u64,
+pub struct KhazixR {
+    pub base_duration: u64,
+    pub evolved_duration: u64,
 }
 
 impl ScriptedEffect for KhazixR {
     fn on_effect(
         &self,
-        attacker_stats: &super::common::AttackerStats,
+        _attacker_stats: &super::common::AttackerStats,
         state: &mut crate::simulation::State<'_>,
         game_params: &super::common::GameParams<'_>,
         event: &crate::simulation::Event,
         events: &mut std::collections::BinaryHeap<crate::simulation::Event>,
     ) {
-        let r_evolved: bool = game_params
-            .initial_config
-            .get("CHAMPION_KHAZIX_R_EVOLVED")
-            .unwrap_or(&"FALSE".to_string())
-            == "TRUE";
+        let r_evolved: bool = game_params.config.champion.khazix.r_evolved;
 
         // first cast scenario
         if !state.cooldowns.contains_key(&AttackType::R) {
             state.recast_charges.retain(|&x| x != AttackType::R);
 
-            let r_ability = find_ability(
-                game_params.abilities,
-                AttackType::R,
-                game_params.initial_config,
-            );
+            let r_ability = find_ability(game_params.abilities, AttackType::R, game_params.config);
 
             for _ in 1..=r_ability.recast_charges.unwrap() {
                 state.recast_charges.push(AttackType::R);
@@ -200,6 +236,93 @@ impl ScriptedEffect for KhazixR {
     }
 }
 
+// the evolved Leap resets on takedown
+pub struct KhazixE;
+
+impl ScriptedEffect for KhazixE {
+    fn on_takedown(
+        &self,
+        _unit: Unit,
+        state: &mut crate::simulation::State<'_>,
+        game_params: &super::common::GameParams<'_>,
+        _event: &crate::simulation::Event,
+        events: &mut std::collections::BinaryHeap<crate::simulation::Event>,
+    ) {
+        if game_params.config.champion.khazix.e_evolved {
+            simulation::reset_cooldown(AttackType::E, state, game_params, events);
+        }
+    }
+}
+
+// Void Spike heals Kha'Zix when he is within the explosion around the target. The evolved
+// spikes slow the enemies hit, and the isolated ones even more
+pub struct KhazixW {
+    pub heal_by_rank: [f64; 5],
+    pub ap_ratio: f64,
+    pub explosion_radius: f64,
+    pub evolved_slow_duration: u64,
+    pub evolved_slow_perc: f64,
+    pub evolved_isolated_slow_perc: f64,
+}
+
+impl ScriptedEffect for KhazixW {
+    fn on_effect(
+        &self,
+        attacker_stats: &super::common::AttackerStats,
+        state: &mut crate::simulation::State<'_>,
+        game_params: &super::common::GameParams<'_>,
+        _event: &crate::simulation::Event,
+        events: &mut std::collections::BinaryHeap<crate::simulation::Event>,
+    ) {
+        if game_params.config.champion.khazix.w_evolved {
+            let w_ability = find_ability(game_params.abilities, AttackType::W, game_params.config);
+            let slow_duration = self.evolved_slow_duration;
+            let slow_perc = if area::is_isolated(state.target, state, game_params) {
+                self.evolved_isolated_slow_perc
+            } else {
+                self.evolved_slow_perc
+            };
+
+            state.add_target_aura(
+                super::common::Aura::Slow,
+                Some(slow_duration),
+                Some(area::slow_stacks(slow_perc)),
+                events,
+            );
+            // the secondary targets stand still, the strength of their slow does not matter
+            for index in area::secondary_targets_hit(&w_ability.hit_pattern, state, game_params) {
+                area::add_secondary_target_aura(
+                    index,
+                    super::common::Aura::Slow,
+                    slow_duration,
+                    state,
+                );
+            }
+        }
+
+        let rank = attacker_stats.spell_rank(AttackType::W);
+        let Some(base_heal) = (rank as usize)
+            .checked_sub(1)
+            .and_then(|index| self.heal_by_rank.get(index))
+        else {
+            return;
+        };
+        if area::distance_to_target(state, game_params) > self.explosion_radius {
+            return;
+        }
+        let heal = base_heal + self.ap_ratio * attacker_stats.ability_power;
+
+        simulation::on_heal(
+            heal,
+            simulation::HealSource::Ability,
+            Some(AttackType::W),
+            None,
+            state,
+            game_params,
+        );
+    }
+}
+
 // fn has_desireable_stats(item: &Value) -> bool {
 //     return (not_nul(&item, "abilityHaste")
 //         || not_nul(&item, "armorPenetration")
@@ -227,269 +350,223 @@ impl ScriptedEffect for KhazixR {
 //     return false;
 // }
 
-pub fn pull_abilities_data(
-    config: &HashMap<String, String>,
-) -> (Vec<SpellData>, AbilitiesExtraData) {
-    let khazix: Champion = include!("champions_gen/khazix.rs");
-    let mut abilities_data = Vec::new();
-
-    // Q (variation 1)
-    let mut ad_damage: HashMap<u64, f64> = HashMap::new();
-    for rank in 1..=5usize {
-        ad_damage.insert(
-            rank.try_into().unwrap(),
-            khazix.abilities.q[0].effects[1].leveling[0].modifiers[0].values[rank - 1],
-        );
+// the game data holds either a number of seconds or "none"
+fn parse_cast_time_ms(cast_time: &Option<String>, key: &str) -> Result<Option<u64>, SimError> {
+    match cast_time.as_deref() {
+        None | Some("none") => Ok(None),
+        Some(cast_time_s) => cast_time_s
+            .parse::<f64>()
+            .map(|cast_time_s| Some((cast_time_s * 1000f64) as u64))
+            .map_err(|_| SimError::InvalidGameData {
+                reason: format!("invalid cast time for ability {}: {:?}", key, cast_time_s),
+            }),
     }
+}
 
-    let cast_time_s = khazix.abilities.q[0]
-        .cast_time
-        .as_ref()
-        .unwrap()
-        .parse::<f64>()
-        .unwrap();
-
-    let mut cooldown_ms: HashMap<u64, u64> = HashMap::new();
-    for rank in 1..=5usize {
-        cooldown_ms.insert(
-            rank.try_into().unwrap(),
-            khazix.abilities.q[0].cooldown.as_ref().unwrap().modifiers[0].values[rank - 1] as u64
-                * 1000u64,
-        );
+fn parse_range(target_range: &Option<String>, key: &str) -> Result<Option<f64>, SimError> {
+    match target_range.as_deref() {
+        None | Some("none") => Ok(None),
+        Some(range) => range
+            .parse::<f64>()
+            .map(Some)
+            .map_err(|_| SimError::InvalidGameData {
+                reason: format!("invalid target range for ability {}: {:?}", key, range),
+            }),
     }
+}
 
-    if config
-        .get("CHAMPION_KHAZIX_ISOLATED_TARGET")
-        .unwrap_or(&"FALSE".to_string())
-        == "TRUE"
-        && config
-            .get("CHAMPION_KHAZIX_Q_EVOLVED")
-            .unwrap_or(&"FALSE".to_string())
-            == "TRUE"
-    {
-        for cooldown in cooldown_ms.values_mut() {
-            *cooldown = (*cooldown as f64 * (1.0 - 0.45)) as u64;
-        }
+fn invalid_ability_data(key: &str, reason: &str) -> SimError {
+    SimError::InvalidGameData {
+        reason: format!("ability {}: {}", key, reason),
     }
+}
 
-    let ap_damage: HashMap<u64, f64> = HashMap::new();
-    abilities_data.push(SpellData {
-        ad_damage,
-        ap_damage,
-        coefficient_ad: khazix.abilities.q[0].effects[1].leveling[0].modifiers[1].values[0] * 0.01,
-        coefficient_ap: 0.0f64,
-        key: "Q".to_string(),
-        attack_type: AttackType::Q,
-        variation_name: Some("Physical Damage".to_string()),
-        cast_time_ms: Some((cast_time_s * 1000f64) as u64),
-        cooldown_ms: Some(cooldown_ms.clone()),
-        // passive_effects: Vec::new(),
-        category: None,
-        damage_type: Some(DamageType::Physical),
-        active_effect: None,
-        recast_gap_duration: None,
-        recast_charges: None,
-        recast_window: None,
-    });
-
-    // Q (variation 2)
-    let mut ad_damage: HashMap<u64, f64> = HashMap::new();
-    for rank in 1..=5usize {
-        ad_damage.insert(
-            rank.try_into().unwrap(),
-            khazix.abilities.q[0].effects[1].leveling[1].modifiers[0].values[rank - 1],
-        );
+fn abilities_by_key(abilities: &Abilities, attack_type: AttackType) -> &[Ability] {
+    match attack_type {
+        AttackType::Q => &abilities.q,
+        AttackType::W => &abilities.w,
+        AttackType::E => &abilities.e,
+        AttackType::R => &abilities.r,
+        AttackType::P => &abilities.p,
+        // summoner spells and item actives are not part of the champion data
+        _ => &[],
     }
+}
 
-    let ap_damage: HashMap<u64, f64> = HashMap::new();
-    abilities_data.push(SpellData {
-        ad_damage,
-        ap_damage,
-        coefficient_ad: khazix.abilities.q[0].effects[1].leveling[1].modifiers[1].values[0] * 0.01,
-        coefficient_ap: 0.0f64,
-        key: "Q".to_string(),
-        attack_type: AttackType::Q,
-        variation_name: Some("Increased Damage".to_string()),
-        cast_time_ms: Some((cast_time_s * 1000f64) as u64),
-        cooldown_ms: Some(cooldown_ms.clone()),
-        // passive_effects: Vec::new(),
-        category: None,
-        damage_type: Some(DamageType::Physical),
-        active_effect: None,
-        recast_gap_duration: None,
-        recast_charges: None,
-        recast_window: None,
-    });
-
-    // W
-    let mut ad_damage: HashMap<u64, f64> = HashMap::new();
-    for rank in 1..=5usize {
-        ad_damage.insert(
-            rank.try_into().unwrap(),
-            khazix.abilities.w[0].effects[0].leveling[0].modifiers[0].values[rank - 1],
+// reads the values of an ability from the game data, as described by its kit definition
+fn build_spell_data(
+    game_data: &Champion,
+    definition: &AbilityDefinition,
+    config: &ScenarioConfig,
+) -> Result<SpellData, SimError> {
+    let key = definition.attack_type.to_string();
+    let ability = abilities_by_key(&game_data.abilities, definition.attack_type)
+        .get(definition.index)
+        .ok_or_else(|| invalid_ability_data(&key, "missing from the game data"))?;
+
+    let mut cooldown_ms: Option<HashMap<u64, u64>> = None;
+    if let Some(cooldown) = &ability.cooldown {
+        let values = &cooldown
+            .modifiers
+            .first()
+            .ok_or_else(|| invalid_ability_data(&key, "cooldown without values"))?
+            .values;
+        cooldown_ms = Some(
+            (1..=values.len() as u64)
+                .zip(values.iter())
+                .map(|(rank, cooldown_s)| (rank, (cooldown_s * 1000f64) as u64))
+                .collect(),
         );
     }
 
-    let cast_time_s = khazix.abilities.w[0]
-        .cast_time
-        .as_ref()
-        .unwrap()
-        .parse::<f64>()
-        .unwrap();
-
-    let mut cooldown_ms: HashMap<u64, u64> = HashMap::new();
-    for rank in 1..=5usize {
-        cooldown_ms.insert(
-            rank.try_into().unwrap(),
-            khazix.abilities.w[0].cooldown.as_ref().unwrap().modifiers[0].values[rank - 1] as u64
-                * 1000u64,
+    // the costs in other resources (e.g. health, fury) are not simulated
+    let mut cost: Option<HashMap<u64, f64>> = None;
+    if let (Some(ability_cost), Some("MANA" | "ENERGY")) =
+        (&ability.cost, ability.resource.as_deref())
+    {
+        let values = &ability_cost
+            .modifiers
+            .first()
+            .ok_or_else(|| invalid_ability_data(&key, "cost without values"))?
+            .values;
+        cost = Some(
+            (1..=values.len() as u64)
+                .zip(values.iter().copied())
+                .collect(),
         );
     }
 
-    let ap_damage: HashMap<u64, f64> = HashMap::new();
-    abilities_data.push(SpellData {
-        ad_damage,
-        ap_damage,
-        coefficient_ad: khazix.abilities.w[0].effects[0].leveling[0].modifiers[1].values[0] * 0.01,
-        coefficient_ap: 0.0f64,
-        key: "W".to_string(),
-        attack_type: AttackType::W,
-        variation_name: None,
-        cast_time_ms: Some((cast_time_s * 1000f64) as u64),
-        cooldown_ms: Some(cooldown_ms.clone()),
-        // passive_effects: Vec::new(),
-        category: None,
-        damage_type: Some(DamageType::Physical),
-        active_effect: None,
-        recast_gap_duration: None,
-        recast_charges: None,
-        recast_window: None,
-    });
-
-    // E
     let mut ad_damage: HashMap<u64, f64> = HashMap::new();
-    for rank in 1..=5usize {
-        ad_damage.insert(
-            rank.try_into().unwrap(),
-            khazix.abilities.e[0].effects[0].leveling[0].modifiers[0].values[rank - 1],
-        );
-    }
+    let mut ap_damage: HashMap<u64, f64> = HashMap::new();
+    let mut coefficient_ad = 0.0f64;
+    let mut coefficient_ap = 0.0f64;
+    let mut variation_name: Option<String> = None;
+    let mut damage_type: Option<DamageType> = None;
+
+    if let Some(damage) = &definition.damage {
+        let leveling = ability
+            .effects
+            .get(damage.effect)
+            .and_then(|effect| effect.leveling.get(damage.leveling))
+            .ok_or_else(|| invalid_ability_data(&key, "missing damage values"))?;
+
+        // the unit of the modifier tells what it scales with
+        for modifier in leveling.modifiers.iter() {
+            let unit = modifier.units.first().map(|unit| unit.as_str());
+            match unit {
+                Some("") => {
+                    let damage_per_rank: HashMap<u64, f64> = (1..=modifier.values.len() as u64)
+                        .zip(modifier.values.iter().copied())
+                        .collect();
+                    match damage.damage_type {
+                        DamageType::Magical => ap_damage = damage_per_rank,
+                        _ => ad_damage = damage_per_rank,
+                    }
+                }
+                Some("% bonus AD") => coefficient_ad = modifier.values[0] * 0.01,
+                Some("% AP") => coefficient_ap = modifier.values[0] * 0.01,
+                _ => {
+                    return Err(invalid_ability_data(
+                        &key,
+                        &format!("unsupported damage unit {:?}", unit),
+                    ))
+                }
+            }
+        }
 
-    let mut cooldown_ms: HashMap<u64, u64> = HashMap::new();
-    for rank in 1..=5usize {
-        cooldown_ms.insert(
-            rank.try_into().unwrap(),
-            khazix.abilities.e[0].cooldown.as_ref().unwrap().modifiers[0].values[rank - 1] as u64
-                * 1000u64,
-        );
+        if definition.selected_when.is_some() {
+            variation_name = Some(leveling.attribute.clone());
+        }
+        damage_type = Some(damage.damage_type);
     }
 
-    let ap_damage: HashMap<u64, f64> = HashMap::new();
-    abilities_data.push(SpellData {
+    let mut spell_data = SpellData {
+        key: key.clone(),
+        attack_type: definition.attack_type,
+        coefficient_ad,
+        coefficient_ap,
         ad_damage,
         ap_damage,
-        coefficient_ad: khazix.abilities.e[0].effects[0].leveling[0].modifiers[1].values[0] * 0.01,
-        coefficient_ap: 0.0f64,
-        key: "E".to_string(),
-        attack_type: AttackType::E,
-        variation_name: None,
-        cast_time_ms: None,
-        cooldown_ms: Some(cooldown_ms.clone()),
-        // passive_effects: Vec::new(),
-        category: Some(SpellCategory::Dash),
-        damage_type: Some(DamageType::Physical),
-        active_effect: None,
-        recast_gap_duration: None,
-        recast_charges: None,
-        recast_window: None,
-    });
-
-    // R
-    let mut cooldown_ms: HashMap<u64, u64> = HashMap::new();
-    for rank in 1..=3usize {
-        cooldown_ms.insert(
-            rank.try_into().unwrap(),
-            khazix.abilities.r[0].cooldown.as_ref().unwrap().modifiers[0].values[rank - 1] as u64
-                * 1000u64,
-        );
+        variation_name,
+        selected_when: definition.selected_when,
+        rank_by_level: definition.rank_by_level,
+        cast_time_ms: parse_cast_time_ms(&ability.cast_time, &key)?,
+        range: parse_range(&ability.target_range, &key)?,
+        cooldown_ms,
+        cost,
+        category: definition.category.clone(),
+        damage_type,
+        hit_pattern: definition.hit_pattern.clone(),
+        active_effect: definition.active_effect,
+        recast_gap_duration: definition
+            .recast
+            .as_ref()
+            .map(|recast| recast.gap_duration_ms),
+        recast_charges: definition.recast.as_ref().map(|recast| recast.charges),
+        recast_window: definition.recast.as_ref().map(|recast| recast.window_ms),
+        charges: None,
+    };
+
+    if let Some(customize) = definition.customize {
+        customize(&mut spell_data, config);
     }
 
-    let recast_charges = if config
-        .get("CHAMPION_KHAZIX_R_EVOLVED")
-        .unwrap_or(&"FALSE".to_string())
-        == "TRUE"
-    {
-        2
-    } else {
-        1
-    };
+    Ok(spell_data)
+}
 
-    abilities_data.push(SpellData {
-        ad_damage: HashMap::new(),
-        ap_damage: HashMap::new(),
-        coefficient_ad: 0.0f64,
-        coefficient_ap: 0.0f64,
-        key: "R".to_string(),
-        attack_type: AttackType::R,
-        variation_name: None,
-        cast_time_ms: None,
-        cooldown_ms: Some(cooldown_ms.clone()),
-        // passive_effects: Vec::new(),
-        category: Some(SpellCategory::Stealth),
-        damage_type: None,
-        active_effect: Some(&KhazixR {
-            base_duration: 1250,
-            evolved_duration: 2000,
-        }),
-        recast_gap_duration: Some(2000),
-        recast_charges: Some(recast_charges),
-        recast_window: Some(12_000),
-    });
+pub fn pull_abilities_data(
+    champion: CommonChampion,
+    config: &ScenarioConfig,
+) -> Result<(Vec<SpellData>, AbilitiesExtraData), SimError> {
+    let (game_data, kit) = champion_kit(champion).ok_or_else(|| {
+        SimError::invalid_input(
+            "champion.champion",
+            format!("the abilities of {:?} are not supported yet", champion),
+        )
+    })?;
+
+    let abilities_data = kit
+        .abilities
+        .iter()
+        .map(|definition| build_spell_data(&game_data, definition, config))
+        .collect::<Result<Vec<SpellData>, SimError>>()?;
 
     // println!("abilities_data {:#?}", abilities_data);
 
-    (
-        abilities_data,
-        AbilitiesExtraData {
-            unseen_threat: UnseenThreat {
-                base_damage: 10.0,
-                per_level_bonus: 7.0,
-                bonus_ad_ratio: 0.5,
-            },
-        },
-    )
+    Ok((abilities_data, kit.extra_data))
 }
 
+// circumstances of a cast, read from the battlefield when the cast happens
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CastContext {
+    // Kha'Zix: no other enemy stands near the target of the cast
+    pub isolated_target: bool,
+}
+
+// the ability cast by the given command, outside of any cast context. Only meant for the
+// data shared by all the variations of a key (ranks, cast time, recasts...)
 pub fn find_ability<'a>(
-    abilities: &'a Vec<SpellData>,
+    abilities: &'a [SpellData],
     spell_name: AttackType,
-    configs: &HashMap<String, String>,
+    config: &ScenarioConfig,
 ) -> &'a SpellData {
-    // println!("abilities {:#?}", abilities);
-    // println!("spell_name {:#?}", spell_name);
-    // println!("configs {:#?}", configs);
-    let mut variation_name: Option<String> = None;
-
-    if spell_name == AttackType::Q {
-        // Khazix's Q
-        if configs.get("CHAMPION_KHAZIX_ISOLATED_TARGET").unwrap() == "TRUE" {
-            variation_name = Some("Increased Damage".to_string());
-        } else {
-            variation_name = Some("Physical Damage".to_string());
-        }
-    }
-
-    let ability: &SpellData = if variation_name.is_some() {
-        abilities
-            .iter()
-            .find(|&x| x.key == spell_name.to_string() && x.variation_name == variation_name)
-            .unwrap()
-    } else {
-        abilities
-            .iter()
-            .find(|&x| x.key == spell_name.to_string())
-            .unwrap()
-    };
+    find_ability_for_cast(abilities, spell_name, config, &CastContext::default())
+}
 
-    ability
+// the ability cast by the given command. When the key has several variations, the first
+// one selected by the config and the cast context is returned.
+pub fn find_ability_for_cast<'a>(
+    abilities: &'a [SpellData],
+    spell_name: AttackType,
+    config: &ScenarioConfig,
+    cast_context: &CastContext,
+) -> &'a SpellData {
+    abilities
+        .iter()
+        .find(|&x| {
+            x.attack_type == spell_name
+                && x.selected_when
+                    .is_none_or(|selected_when| selected_when(config, cast_context))
+        })
+        .unwrap()
 }
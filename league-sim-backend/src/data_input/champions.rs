@@ -1,13 +1,21 @@
-use std::{collections::HashMap, fs::File, io::BufReader};
-
-use serde_json::Value;
-
-use super::common::Champion;
+use super::champions_gen::Champion;
 
 #[derive(Clone, Debug)]
 pub struct ChampionStats {
+    #[allow(dead_code)]
+    pub health_flat: f64,
+    #[allow(dead_code)]
+    pub health_per_level: f64,
+    // mana, or energy for energy based champions
+    pub mana_flat: f64,
+    pub mana_per_level: f64,
+    // regenerated every 5 seconds
+    pub mana_regen_flat: f64,
+    pub mana_regen_per_level: f64,
     pub armor_flat: f64,
     pub armor_per_level: f64,
+    pub magic_resistance_flat: f64,
+    pub magic_resistance_per_level: f64,
     pub attack_damage_flat: f64,
     pub attack_damage_per_level: f64,
 
@@ -23,6 +31,13 @@ pub struct ChampionStats {
     pub attack_total_time: f64,
 
     pub base_movement_speed: f64,
+    // distance from the target within which the basic attacks can be cast
+    pub attack_range: f64,
+
+    // damage of a critical strike, as a multiplier of the attack damage (1.75 for most champions)
+    pub critical_strike_damage: f64,
+    // champion specific multiplier applied to the whole crit damage (e.g. Yasuo)
+    pub critical_strike_damage_modifier: f64,
 }
 
 pub enum AttackType {
@@ -40,6 +55,26 @@ impl AttackType {
     }
 }
 
+// resource spent to cast the abilities
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ResourceType {
+    Mana,
+    Energy,
+    // resourceless champions and the resources not simulated (e.g. fury, heat). The
+    // abilities of these champions are free to cast.
+    Other,
+}
+
+impl ResourceType {
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "MANA" => ResourceType::Mana,
+            "ENERGY" => ResourceType::Energy,
+            _ => ResourceType::Other,
+        }
+    }
+}
+
 pub enum AdaptiveType {
     Physical,
     Magic,
@@ -55,12 +90,14 @@ impl AdaptiveType {
     }
 }
 
+#[allow(dead_code)]
 pub struct ChampionData {
     pub name: Champion,
     pub id: u64,
     pub key: String,
     pub attack_type: AttackType,
     pub adaptive_type: AdaptiveType,
+    pub resource: ResourceType,
 }
 
 // source: https://leagueoflegends.fandom.com/wiki/Champion_statistic#Increasing_Statistics
@@ -1,7 +1,11 @@
 use super::{
-    champions::{AdaptiveType, AttackType, ChampionStats},
-    common::Champion, ChampionData,
+    champions::{AdaptiveType, AttackType, ChampionStats, ResourceType},
+    ChampionData,
 };
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+pub enum Champion {
+    Khazix,
+}
 pub fn get_base_champion_stats(champion: Champion) -> (ChampionData, ChampionStats) {
     let champion_data = match champion {
         Champion::Khazix => {
@@ -11,14 +15,23 @@ pub fn get_base_champion_stats(champion: Champion) -> (ChampionData, ChampionSta
                 key: "Khazix".to_string(),
                 attack_type: AttackType::from_str("MELEE"),
                 adaptive_type: AdaptiveType::from_str("PHYSICAL_DAMAGE"),
+                resource: ResourceType::from_str("MANA"),
             }
         }
     };
     let champion_stats = match champion {
         Champion::Khazix => {
             ChampionStats {
+                health_flat: 643f64,
+                health_per_level: 99f64,
+                mana_flat: 327f64,
+                mana_per_level: 40f64,
+                mana_regen_flat: 7.59f64,
+                mana_regen_per_level: 0.5f64,
                 armor_flat: 32f64,
                 armor_per_level: 4.2f64,
+                magic_resistance_flat: 32f64,
+                magic_resistance_per_level: 2.05f64,
                 attack_damage_flat: 60f64,
                 attack_damage_per_level: 3.1f64,
                 attack_speed_flat: 0.668f64,
@@ -28,6 +41,9 @@ pub fn get_base_champion_stats(champion: Champion) -> (ChampionData, ChampionSta
                 attack_cast_time: 0.3f64,
                 attack_total_time: 1.6f64,
                 base_movement_speed: 350f64,
+                attack_range: 125f64,
+                critical_strike_damage: 1.75f64,
+                critical_strike_damage_modifier: 1f64,
             }
         }
     };
@@ -1,40 +1,45 @@
 use std::{
-    cmp::{self, Ordering},
-    collections::{HashMap, HashSet},
-    f32::consts::E,
+    cmp::Ordering,
+    collections::HashSet,
     ops::{Add, AddAssign},
 };
 
 use itertools::Itertools;
 
 use crate::{
+    area::{self, Battlefield},
     attack::AttackType,
     simulation::{DamageInfo, State},
 };
 
 use super::{
-    abilities::{self, find_ability, AbilitiesExtraData, SpellData},
-    champions::{stat_increase, AdaptiveType, ChampionData, ChampionStats},
+    abilities::{find_ability, AbilitiesExtraData, SpellData},
+    champions::{stat_increase, AdaptiveType, ChampionData, ChampionStats, ResourceType},
+    config::ScenarioConfig,
     items::{Item, ItemData},
     runes::{collect_runes_stats, Rune, RunesData},
 };
 
-#[derive(PartialEq)]
-pub enum Champion {
-    Khazix,
-}
+pub use super::champions_gen::Champion;
 
 #[derive(PartialEq, Clone, Copy)]
 pub enum CritHandlingChoice {
     Min,
     Max,
     Avg,
+    // each auto attack rolls for a crit. The seed makes a simulation reproducible
+    Random { seed: u64 },
 }
 
 // see https://leagueoflegends.fandom.com/wiki/Champion_statistic?so=search#Offensive
 #[derive(Debug, Default)]
 pub struct AttackerStats {
     pub level: u64,
+    // ranks of the abilities at the level of the champion (0 when not learned yet)
+    pub q_rank: u64,
+    pub w_rank: u64,
+    pub e_rank: u64,
+    pub r_rank: u64,
     pub ability_haste: f64,
     pub basic_ability_haste: f64,
     pub ultimate_haste: f64,
@@ -43,7 +48,14 @@ pub struct AttackerStats {
     pub ad_bonus: f64,
     pub lethality: f64,
     pub armor_penetration_perc: f64,
+    // applies to the bonus armor of the target only, on top of armor_penetration_perc
+    pub armor_penetration_bonus_perc: f64,
+    pub magic_penetration_flat: f64,
+    pub magic_penetration_perc: f64,
     pub crit_chance: f64,
+    // crit damage multiplier, before the champion modifier is applied
+    pub crit_damage: f64,
+    pub crit_damage_modifier: f64,
     pub attack_speed_base: f64,
     pub attack_speed_bonus: f64,
     pub attack_speed_ratio: f64,
@@ -58,6 +70,25 @@ pub struct AttackerStats {
     pub movement_speed_base: f64,
     pub movement_speed_flat_bonus: f64,
     pub movement_speed_perc_bonus: f64,
+    // maximum mana (or energy) and its regeneration per second
+    pub mana: f64,
+    pub mana_regen: f64,
+    // life steal applies to the basic attacks, omnivamp to all damage
+    pub life_steal: f64,
+    pub omnivamp: f64,
+}
+
+impl AttackerStats {
+    // the other commands (auto attacks, summoner spells and item actives) have a single rank
+    pub fn spell_rank(&self, attack_type: AttackType) -> u64 {
+        match attack_type {
+            AttackType::Q => self.q_rank,
+            AttackType::W => self.w_rank,
+            AttackType::E => self.e_rank,
+            AttackType::R => self.r_rank,
+            _ => 1,
+        }
+    }
 }
 
 impl AddAssign for AttackerStats {
@@ -69,7 +100,11 @@ impl AddAssign for AttackerStats {
         self.ad_bonus += other.ad_bonus;
         self.lethality += other.lethality;
         self.armor_penetration_perc += other.armor_penetration_perc;
+        self.armor_penetration_bonus_perc += other.armor_penetration_bonus_perc;
+        self.magic_penetration_flat += other.magic_penetration_flat;
+        self.magic_penetration_perc += other.magic_penetration_perc;
         self.crit_chance = f64::min(self.crit_chance + other.crit_chance, 1.0);
+        self.crit_damage += other.crit_damage;
         self.attack_speed_base += other.attack_speed_base;
         self.attack_speed_bonus += other.attack_speed_bonus;
         // // multipliers are combined multiplicatively
@@ -80,6 +115,10 @@ impl AddAssign for AttackerStats {
             (self.damage_ability_multiplier + 1.0) * (other.damage_ability_multiplier + 1.0) - 1.0;
         self.adaptive_force += other.adaptive_force;
         self.ability_power += other.ability_power;
+        self.mana += other.mana;
+        self.mana_regen += other.mana_regen;
+        self.life_steal += other.life_steal;
+        self.omnivamp += other.omnivamp;
     }
 }
 
@@ -95,12 +134,28 @@ impl Add for AttackerStats {
 // see https://leagueoflegends.fandom.com/wiki/Champion_statistic?so=search#Defensive
 #[derive(Debug, Clone)]
 pub struct TargetStats {
+    // total armor. The bonus part (from items) is also kept apart
     pub armor: f64,
+    pub armor_bonus: f64,
     pub magic_resistance: f64,
+    pub magic_resistance_bonus: f64,
+    // debuffs applied on the target (e.g. Black Cleaver's Carve)
+    pub armor_reduction_flat: f64,
+    pub armor_reduction_perc: f64,
+    pub magic_resistance_reduction_flat: f64,
+    pub magic_resistance_reduction_perc: f64,
     pub max_health: f64,
     pub current_health: f64,
 }
 
+// stats granted by the items of the target
+#[derive(Debug, Clone, Default)]
+pub struct DefensiveStats {
+    pub health: f64,
+    pub armor: f64,
+    pub magic_resistance: f64,
+}
+
 // this is a container for data that is constant throughout the duration of each simulation
 pub struct GameParams<'a> {
     pub champion: Champion,
@@ -108,7 +163,7 @@ pub struct GameParams<'a> {
     pub champion_stats: &'a ChampionStats,
     pub level: u64,
     pub items: &'a Vec<&'a ItemData>,
-    pub initial_config: &'a HashMap<String, String>,
+    pub config: &'a ScenarioConfig,
     pub abilities: &'a Vec<SpellData>,
     pub abilities_extra_data: &'a AbilitiesExtraData,
     pub initial_target_stats: &'a TargetStats,
@@ -123,6 +178,7 @@ pub struct GameParams<'a> {
     pub capture_event_history: bool,
     // weave auto attacks in between commands if there is enough time between the 2
     pub weave_auto_attacks: bool,
+    pub battlefield: &'a Battlefield,
 }
 
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, serde::Serialize)]
@@ -145,6 +201,16 @@ pub enum PassiveEffect {
     // Runes
     DarkHarvest,
     SuddenImpact,
+    Electrocute,
+    HailOfBlades,
+    FirstStrike,
+    Conqueror,
+
+    // Items, only consulted when the attacker heals or scores a takedown
+    Ichorshield,
+    Defy,
+    // Items, hitting the secondary targets
+    Cleave,
 }
 
 impl PassiveEffect {
@@ -153,10 +219,10 @@ impl PassiveEffect {
             "Flux" => None,
             "Carve" => Some(Self::Carve),
             "Fervor" => None,
-            "Ichorshield" => None,
+            "Ichorshield" => Some(Self::Ichorshield),
             "Hackshorn" => None,
             "Ignore Pain" => None,
-            "Defy" => None,
+            "Defy" => Some(Self::Defy),
             "Ever Rising Moon" => Some(Self::EverRisingMoon),
             "Annul" => None,
             "Winter's Caress" => None,
@@ -167,7 +233,7 @@ impl PassiveEffect {
             "Grievous Wounds" => None,
             "Preparation" => Some(Self::Preparation),
             "Extraction" => None,
-            "Cleave" => None,
+            "Cleave" => Some(Self::Cleave),
             "Resilience" => None,
             "Shield Reaver" => None,
             "Bitter Cold" => None,
@@ -189,6 +255,20 @@ impl PassiveEffect {
         }
     }
 
+    // the attacker scored the takedown of the unit
+    pub fn handle_on_takedown(
+        &self,
+        _unit: Unit,
+        state: &mut State<'_>,
+        _game_params: &GameParams<'_>,
+        _event: &crate::simulation::Event,
+        events: &mut std::collections::BinaryHeap<crate::simulation::Event>,
+    ) {
+        if self == &PassiveEffect::Defy {
+            super::items::defy(state, events)
+        }
+    }
+
     pub fn handle_on_pre_damage(
         &self,
         damage_info: &DamageInfo,
@@ -234,6 +314,14 @@ impl PassiveEffect {
                 event,
                 events,
             ),
+            PassiveEffect::HailOfBlades => Rune::HailOfBlades.handle_on_pre_damage(
+                damage_info,
+                attacker_stats,
+                state,
+                game_params,
+                event,
+                events,
+            ),
             &_ => (),
         }
     }
@@ -285,6 +373,33 @@ impl PassiveEffect {
                 event,
                 events,
             ),
+            PassiveEffect::Electrocute => Rune::Electrocute.handle_on_post_damage(
+                damage_info,
+                attacker_stats,
+                state,
+                game_params,
+                event,
+                events,
+            ),
+            PassiveEffect::FirstStrike => Rune::FirstStrike.handle_on_post_damage(
+                damage_info,
+                attacker_stats,
+                state,
+                game_params,
+                event,
+                events,
+            ),
+            PassiveEffect::Conqueror => Rune::Conqueror.handle_on_post_damage(
+                damage_info,
+                attacker_stats,
+                state,
+                game_params,
+                event,
+                events,
+            ),
+            PassiveEffect::Cleave => {
+                super::items::cleave(damage_info, attacker_stats, state, game_params)
+            }
             &_ => (),
         }
     }
@@ -314,6 +429,13 @@ impl PassiveEffect {
             PassiveEffect::DarkHarvest => (),
             PassiveEffect::Galvanize => (),
             PassiveEffect::MistsEdge => (),
+            PassiveEffect::Electrocute => (),
+            PassiveEffect::HailOfBlades => (),
+            PassiveEffect::FirstStrike => (),
+            PassiveEffect::Conqueror => (),
+            PassiveEffect::Ichorshield => (),
+            PassiveEffect::Defy => (),
+            PassiveEffect::Cleave => (),
         }
     }
 
@@ -342,13 +464,20 @@ impl PassiveEffect {
             PassiveEffect::DarkHarvest => (),
             PassiveEffect::Galvanize => (),
             PassiveEffect::MistsEdge => (),
+            PassiveEffect::Electrocute => (),
+            PassiveEffect::HailOfBlades => (),
+            PassiveEffect::FirstStrike => (),
+            PassiveEffect::Conqueror => (),
+            PassiveEffect::Ichorshield => (),
+            PassiveEffect::Defy => (),
+            PassiveEffect::Cleave => (),
         }
     }
 
     fn offensive_stats(
         &self,
-        state: &State<'_>,
-        game_params: &GameParams<'_>,
+        _state: &State<'_>,
+        _game_params: &GameParams<'_>,
     ) -> Option<AttackerStats> {
         match self {
             PassiveEffect::IonianInsight => {
@@ -399,21 +528,20 @@ impl PassiveEffect {
         state: &mut State<'_>,
         duration: u64,
     ) {
-        match self {
-            PassiveEffect::Energized => Item::VoltaicCyclosword.handle_on_movement(
+        if self == &PassiveEffect::Energized {
+            Item::VoltaicCyclosword.handle_on_movement(
                 self,
                 duration,
                 state,
                 game_params,
                 event,
                 events,
-            ),
-            &_ => (),
+            )
         }
     }
 }
 
-#[derive(PartialEq, Debug, Clone, Copy, serde::Serialize)]
+#[derive(PartialEq, Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
 pub enum DamageType {
     Physical,
     Magical,
@@ -421,13 +549,15 @@ pub enum DamageType {
     Unknown,
 }
 
-#[derive(PartialEq, Debug, Clone, Copy)]
+#[derive(PartialEq, Debug, Clone, Copy, serde::Serialize)]
 pub enum Unit {
     Attacker,
     Target,
+    // index in the secondary targets of the battlefield
+    SecondaryTarget(usize),
 }
 
-#[derive(Clone, Copy, Eq, Hash, PartialEq, Debug, serde::Serialize)]
+#[derive(Clone, Copy, Eq, Hash, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
 pub enum Aura {
     SuddenImpactReady,
     Invisibility, // Stealth includes Camouflage & Invisibility
@@ -441,23 +571,34 @@ pub enum Aura {
     FocusedWill,
     LightshieldStrike,
     Energized,
+    Ignite,
+    GrievousWounds,
+    // Youmuu's Ghostblade active
+    WraithStep,
+    Slow,
+    // hits landed within the window of Electrocute
+    Electrocute,
+    // empowered attacks left
+    HailOfBlades,
+    FirstStrike,
+    Conqueror,
+    // shields of the attacker. The stacks hold the amount of the shield
+    Ichorshield,
+    EverRisingMoonShield,
 }
 
 impl Aura {
     pub fn on_start(
         &self,
         state: &mut State<'_>,
-        affected_unit: Unit,
+        _affected_unit: Unit,
         // game_params: &GameParams<'_>,
         // event: &crate::simulation::Event,
         // events: &mut std::collections::BinaryHeap<crate::simulation::Event>,
     ) {
         // println!("aura.on_start: {:#?} {:#?}", self, event.time_ms);
-        match self {
-            Aura::VoidAssaultRecastReady => {
-                state.recast_ready.insert(AttackType::R);
-            }
-            _ => (),
+        if self == &Aura::VoidAssaultRecastReady {
+            state.recast_ready.insert(AttackType::R);
         }
     }
 
@@ -465,35 +606,33 @@ impl Aura {
         &self,
         state: &mut State<'_>,
         game_params: &GameParams<'_>,
-        event: &crate::simulation::Event,
+        _event: &crate::simulation::Event,
         events: &mut std::collections::BinaryHeap<crate::simulation::Event>,
-        affected_unit: Unit,
+        _affected_unit: Unit,
         early_end: bool,
     ) {
         // println!("aura.on_end: {:#?} {:#?}", self, event.time_ms);
 
         match self {
-            Aura::Invisibility => {
-                if state.recast_charges.contains(&crate::attack::AttackType::R) {
-                    let r_ability = find_ability(
-                        game_params.abilities,
-                        crate::attack::AttackType::R,
-                        game_params.initial_config,
-                    );
+            Aura::Invisibility if state.recast_charges.contains(&crate::attack::AttackType::R) => {
+                let r_ability = find_ability(
+                    game_params.abilities,
+                    crate::attack::AttackType::R,
+                    game_params.config,
+                );
 
-                    state.add_attacker_aura(
-                        Aura::VoidAssaultDelay,
-                        r_ability.recast_gap_duration,
-                        None,
-                        events,
-                    );
-                }
+                state.add_attacker_aura(
+                    Aura::VoidAssaultDelay,
+                    r_ability.recast_gap_duration,
+                    None,
+                    events,
+                );
             }
             Aura::VoidAssaultDelay => {
                 let r_ability = find_ability(
                     game_params.abilities,
                     crate::attack::AttackType::R,
-                    game_params.initial_config,
+                    game_params.config,
                 );
                 state.add_attacker_aura(
                     Aura::VoidAssaultRecastReady,
@@ -623,6 +762,34 @@ impl Aura {
 
                 Some(offensive_stats)
             }
+            Aura::HailOfBlades => Some(
+                game_params
+                    .runes_data
+                    .hail_of_blades
+                    .offensive_stats(game_params),
+            ),
+            Aura::Conqueror => {
+                let stacks = state.attacker_auras.get(&Aura::Conqueror).unwrap().stacks;
+                Some(
+                    game_params
+                        .runes_data
+                        .conqueror
+                        .offensive_stats(stacks.unwrap(), game_params),
+                )
+            }
+            Aura::WraithStep => {
+                let movement_speed_perc_bonus = match game_params.champion_data.attack_type {
+                    super::champions::AttackType::Melee => 0.20,
+                    super::champions::AttackType::Ranged => 0.15,
+                };
+
+                let offensive_stats = AttackerStats {
+                    movement_speed_perc_bonus,
+                    ..Default::default()
+                };
+
+                Some(offensive_stats)
+            }
             Aura::LightshieldStrike => {
                 if game_params
                     .items
@@ -649,6 +816,7 @@ impl Aura {
 #[derive(Clone, Copy, Debug)]
 pub struct AuraApplication {
     pub aura: Aura,
+    #[allow(dead_code)]
     pub start_ms: u64,
     pub end_ms: Option<u64>,
     pub stacks: Option<u64>,
@@ -657,12 +825,12 @@ pub struct AuraApplication {
 pub trait EffectWithCallbacks {
     fn on_post_damage(
         &self,
-        damage_info: &DamageInfo,
-        attacker_stats: &AttackerStats,
-        state: &mut State<'_>,
-        game_params: &GameParams<'_>,
-        event: &crate::simulation::Event,
-        events: &mut std::collections::BinaryHeap<crate::simulation::Event>,
+        _damage_info: &DamageInfo,
+        _attacker_stats: &AttackerStats,
+        _state: &mut State<'_>,
+        _game_params: &GameParams<'_>,
+        _event: &crate::simulation::Event,
+        _events: &mut std::collections::BinaryHeap<crate::simulation::Event>,
     ) {
     }
 }
@@ -674,6 +842,10 @@ pub fn compute_attacker_stats(game_params: &GameParams, state: &State) -> Attack
     // see https://leagueoflegends.fandom.com/wiki/Champion_statistic
     let mut offensive_stats: AttackerStats = AttackerStats {
         level,
+        q_rank: rank_at_level(game_params, AttackType::Q),
+        w_rank: rank_at_level(game_params, AttackType::W),
+        e_rank: rank_at_level(game_params, AttackType::E),
+        r_rank: rank_at_level(game_params, AttackType::R),
         ability_haste: items
             .iter()
             .fold(0.0, |acc, x| acc + x.offensive_stats.ability_haste),
@@ -685,9 +857,23 @@ pub fn compute_attacker_stats(game_params: &GameParams, state: &State) -> Attack
         armor_penetration_perc: items
             .iter()
             .fold(0.0, |acc, x| acc + x.offensive_stats.armor_penetration_perc),
+        armor_penetration_bonus_perc: items.iter().fold(0.0, |acc, x| {
+            acc + x.offensive_stats.armor_penetration_bonus_perc
+        }),
+        magic_penetration_flat: items
+            .iter()
+            .fold(0.0, |acc, x| acc + x.offensive_stats.magic_penetration_flat),
+        magic_penetration_perc: items
+            .iter()
+            .fold(0.0, |acc, x| acc + x.offensive_stats.magic_penetration_perc),
         crit_chance: items
             .iter()
             .fold(0.0, |acc, x| acc + x.offensive_stats.crit_chance),
+        crit_damage: champion_stats.critical_strike_damage
+            + items
+                .iter()
+                .fold(0.0, |acc, x| acc + x.offensive_stats.crit_damage),
+        crit_damage_modifier: champion_stats.critical_strike_damage_modifier,
         lethality: items
             .iter()
             .fold(0.0, |acc, x| acc + x.offensive_stats.lethality),
@@ -718,6 +904,17 @@ pub fn compute_attacker_stats(game_params: &GameParams, state: &State) -> Attack
         movement_speed_perc_bonus: items.iter().fold(0.0, |acc, x| {
             acc + x.offensive_stats.movement_speed_perc_bonus
         }),
+        mana: max_mana(game_params),
+        life_steal: items
+            .iter()
+            .fold(0.0, |acc, x| acc + x.offensive_stats.life_steal),
+        omnivamp: items
+            .iter()
+            .fold(0.0, |acc, x| acc + x.offensive_stats.omnivamp),
+        // the game data holds the regeneration per 5 seconds
+        mana_regen: (champion_stats.mana_regen_flat
+            + stat_increase(champion_stats.mana_regen_per_level, level as f64))
+            / 5.0,
     };
     offensive_stats += collect_runes_stats(state, game_params);
     offensive_stats += collect_aura_stats(state, game_params);
@@ -734,38 +931,123 @@ pub fn compute_attacker_stats(game_params: &GameParams, state: &State) -> Attack
     offensive_stats
 }
 
+// the health of the attacker does not change during the fight, so that it can be read
+// while computing the stats (e.g. by Absolute Focus)
+pub fn attacker_max_health(game_params: &GameParams) -> f64 {
+    let champion_stats = game_params.champion_stats;
+    let level = game_params.level;
+
+    champion_stats.health_flat
+        + stat_increase(champion_stats.health_per_level, level as f64)
+        + game_params
+            .items
+            .iter()
+            .fold(0.0, |acc, x| acc + x.defensive_stats.health)
+        + game_params
+            .runes
+            .iter()
+            .fold(0.0, |acc, rune| acc + rune.bonus_health(level))
+}
+
+pub fn attacker_health_perc(state: &State, game_params: &GameParams) -> f64 {
+    state.attacker_health / attacker_max_health(game_params) * 100.0
+}
+
+// defenses of the attacker, for the damage it takes in a duel
+pub fn compute_attacker_defensive_stats(game_params: &GameParams, state: &State) -> TargetStats {
+    let champion_stats = game_params.champion_stats;
+    let level = game_params.level as f64;
+    let item_stats = game_params
+        .items
+        .iter()
+        .fold(DefensiveStats::default(), |mut acc, x| {
+            acc.armor += x.defensive_stats.armor;
+            acc.magic_resistance += x.defensive_stats.magic_resistance;
+            acc
+        });
+
+    TargetStats {
+        armor: champion_stats.armor_flat
+            + stat_increase(champion_stats.armor_per_level, level)
+            + item_stats.armor,
+        armor_bonus: item_stats.armor,
+        magic_resistance: champion_stats.magic_resistance_flat
+            + stat_increase(champion_stats.magic_resistance_per_level, level)
+            + item_stats.magic_resistance,
+        magic_resistance_bonus: item_stats.magic_resistance,
+        armor_reduction_flat: 0.0,
+        armor_reduction_perc: 0.0,
+        magic_resistance_reduction_flat: 0.0,
+        magic_resistance_reduction_perc: 0.0,
+        max_health: attacker_max_health(game_params),
+        current_health: state.attacker_health,
+    }
+}
+
+// the mana of the items does not apply to energy
+fn max_mana(game_params: &GameParams) -> f64 {
+    let champion_stats = game_params.champion_stats;
+    let base_mana = champion_stats.mana_flat
+        + stat_increase(champion_stats.mana_per_level, game_params.level as f64);
+
+    match game_params.champion_data.resource {
+        ResourceType::Mana => {
+            base_mana
+                + game_params
+                    .items
+                    .iter()
+                    .fold(0.0, |acc, x| acc + x.offensive_stats.mana)
+        }
+        ResourceType::Energy => base_mana,
+        ResourceType::Other => 0.0,
+    }
+}
+
+// the variations of an ability share the same ranks
+pub(crate) fn rank_at_level(game_params: &GameParams, attack_type: AttackType) -> u64 {
+    game_params
+        .abilities
+        .iter()
+        .find(|ability| ability.attack_type == attack_type)
+        .zip((game_params.level as usize).checked_sub(1))
+        .and_then(|(ability, level_index)| ability.rank_by_level.get(level_index).copied())
+        .unwrap_or_default()
+}
+
 fn collect_aura_stats(state: &State<'_>, game_params: &GameParams<'_>) -> AttackerStats {
     let mut offensive_stats = AttackerStats {
         ..Default::default()
     };
 
-    for (aura, aura_app) in state.attacker_auras.iter() {
-        let aura_stats: Option<AttackerStats> = aura.offensive_stats(state, game_params);
-        if aura_stats.is_some() {
-            offensive_stats += aura_stats.unwrap();
+    for (aura, _aura_app) in state.attacker_auras.iter() {
+        if let Some(aura_stats) = aura.offensive_stats(state, game_params) {
+            offensive_stats += aura_stats;
         }
     }
 
-    return offensive_stats;
+    offensive_stats
 }
 
+// stats of the current target of the attacker
 pub fn compute_target_stats(game_params: &GameParams, state: &State) -> TargetStats {
-    let mut armor = game_params.initial_target_stats.armor;
+    let target_stats = match state.target {
+        Unit::SecondaryTarget(index) => area::secondary_target_stats(index, state, game_params),
+        _ => TargetStats {
+            current_health: game_params.initial_target_stats.current_health - state.total_damage,
+            ..game_params.initial_target_stats.clone()
+        },
+    };
+
+    let mut armor_reduction_perc = target_stats.armor_reduction_perc;
     if let Some(carve_aura_app) = state.target_auras.get(&Aura::Carve) {
         let stacks = carve_aura_app.stacks.unwrap();
-        armor *= 1.0 - (0.06 * stacks as f64);
+        armor_reduction_perc += 0.06 * stacks as f64;
     }
-    // println!(
-    //     "compute_target_stats armor: {:#?}, time: {:#?}",
-    //     armor, state.time_ms
-    // );
 
-    return TargetStats {
-        armor,
-        max_health: game_params.initial_target_stats.max_health,
-        current_health: game_params.initial_target_stats.current_health - state.total_damage,
-        magic_resistance: game_params.initial_target_stats.magic_resistance,
-    };
+    TargetStats {
+        armor_reduction_perc,
+        ..target_stats
+    }
 }
 
 fn collect_passive_effects_stats(state: &State, game_params: &GameParams) -> AttackerStats {
@@ -778,13 +1060,12 @@ fn collect_passive_effects_stats(state: &State, game_params: &GameParams) -> Att
         .iter()
         .flat_map(|&item_data| &item_data.passives)
     {
-        let aura_stats: Option<AttackerStats> = passive_effect.offensive_stats(state, game_params);
-        if aura_stats.is_some() {
-            offensive_stats += aura_stats.unwrap();
+        if let Some(aura_stats) = passive_effect.offensive_stats(state, game_params) {
+            offensive_stats += aura_stats;
         }
     }
 
-    return offensive_stats;
+    offensive_stats
 }
 
 pub fn convert_adaptive(adaptive_force: f64, damage_type: DamageType) -> f64 {
@@ -818,6 +1099,7 @@ fn apply_adaptive_force(offensive_stats: &mut AttackerStats, game_params: &GameP
     offensive_stats.adaptive_force = 0.0;
 }
 
+#[allow(dead_code)]
 pub fn apply_adaptive_damage(
     adaptive_damage: f64,
     offensive_stats: &AttackerStats,
@@ -858,7 +1140,7 @@ pub fn compile_passive_effects(game_params: &mut GameParams<'_>) {
     let mut passive_effects = item_effects
         .chain(rune_effects)
         // .chain(champion_passives)
-        .flat_map(|effects| effects)
+        .flatten()
         .collect_vec();
 
     passive_effects.sort_by(PassiveEffect::execution_order);
@@ -0,0 +1,137 @@
+use schemars::JsonSchema;
+
+// Scenario toggles provided by the user. Static once the simulation is running.
+// Every section is optional: missing fields take their default value and unknown
+// fields are rejected, so that a typo does not silently fall back to a default.
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize, serde::Serialize, JsonSchema)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+pub struct ScenarioConfig {
+    pub champion: ChampionConfig,
+    pub items: ItemsConfig,
+    pub runes: RunesConfig,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize, serde::Serialize, JsonSchema)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+pub struct ChampionConfig {
+    pub khazix: KhazixConfig,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize, serde::Serialize, JsonSchema)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+pub struct KhazixConfig {
+    // allied units of the target left out of the battlefield (e.g. minions) stand near it,
+    // so it is never isolated. Otherwise the battlefield decides: the target is isolated
+    // (Q deals increased damage) when no secondary target stands within 425 units of it
+    // when Q is cast
+    pub allies_nearby: bool,
+    pub q_evolved: bool,
+    // Void Spike fires three spikes in a cone, slowing the enemies hit
+    pub w_evolved: bool,
+    // Leap resets on takedown
+    pub e_evolved: bool,
+    pub r_evolved: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize, serde::Serialize, JsonSchema)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+pub struct ItemsConfig {
+    pub hubris: HubrisConfig,
+    pub opportunity: OpportunityConfig,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize, JsonSchema)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+pub struct HubrisConfig {
+    pub eminence_active: bool,
+    pub eminence_stacks: u64,
+}
+
+impl Default for HubrisConfig {
+    fn default() -> Self {
+        HubrisConfig {
+            eminence_active: true,
+            eminence_stacks: 17,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize, JsonSchema)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+pub struct OpportunityConfig {
+    pub preparation_ready: bool,
+}
+
+impl Default for OpportunityConfig {
+    fn default() -> Self {
+        OpportunityConfig {
+            preparation_ready: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize, serde::Serialize, JsonSchema)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+pub struct RunesConfig {
+    pub dark_harvest: DarkHarvestConfig,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize, serde::Serialize, JsonSchema)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+pub struct DarkHarvestConfig {
+    // souls collected before the fight starts
+    pub stacks: u64,
+}
+
+impl ScenarioConfig {
+    pub fn json_schema() -> serde_json::Value {
+        serde_json::to_value(schemars::schema_for!(ScenarioConfig)).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod scenario_config_tests {
+    use super::*;
+
+    #[test]
+    fn missing_sections_take_their_default_value() {
+        let config: ScenarioConfig =
+            serde_json::from_str(r#"{ "champion": { "khazix": { "qEvolved": true } } }"#).unwrap();
+
+        assert!(config.champion.khazix.q_evolved);
+        assert!(!config.champion.khazix.allies_nearby);
+        assert_eq!(config.items, ItemsConfig::default());
+        assert!(config.items.hubris.eminence_active);
+        assert_eq!(config.items.hubris.eminence_stacks, 17);
+        assert_eq!(config.runes.dark_harvest.stacks, 0);
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        let json = r#"{ "items": { "hubris": { "eminenceStack": 3 } } }"#;
+        let result: Result<ScenarioConfig, _> =
+            serde_path_to_error::deserialize(&mut serde_json::Deserializer::from_str(json));
+        let err = result.unwrap_err();
+
+        assert_eq!(err.path().to_string(), "items.hubris.eminenceStack");
+        assert!(err
+            .inner()
+            .to_string()
+            .contains("unknown field `eminenceStack`"));
+    }
+
+    #[test]
+    fn json_schema_forbids_additional_properties() {
+        let schema = ScenarioConfig::json_schema();
+
+        assert_eq!(schema["additionalProperties"], false);
+        assert_eq!(
+            schema["$defs"]["HubrisConfig"]["additionalProperties"],
+            false
+        );
+        assert_eq!(
+            schema["$defs"]["HubrisConfig"]["properties"]["eminenceStacks"]["default"],
+            17
+        );
+    }
+}
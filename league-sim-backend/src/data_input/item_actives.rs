@@ -0,0 +1,243 @@
+use std::collections::{BinaryHeap, HashMap};
+
+use crate::{
+    area::{self, HitPattern},
+    attack::{compute_mitigated_damage, AttackType},
+    simulation::{self, on_post_damage_events, DamageSource, Event, State},
+};
+
+use super::{
+    abilities::{find_ability, ScriptedEffect, SpellData},
+    common::{compute_target_stats, AttackerStats, Aura, DamageType, GameParams, Unit},
+    items::Item,
+};
+
+// The actives are not part of the item data we pull, their values come from
+// https://wiki.leagueoflegends.com/en-us/Active_item
+pub struct ActiveDamage {
+    item: Item,
+    total_ad_ratio: f64,
+    // slow applied on the target along with the damage
+    slow_duration: Option<u64>,
+}
+
+impl ScriptedEffect for ActiveDamage {
+    fn on_effect(
+        &self,
+        attacker_stats: &AttackerStats,
+        state: &mut State<'_>,
+        game_params: &GameParams<'_>,
+        event: &Event,
+        events: &mut BinaryHeap<Event>,
+    ) {
+        let target_stats = compute_target_stats(game_params, state);
+
+        let unmitigated_damage =
+            self.total_ad_ratio * (attacker_stats.ad_base + attacker_stats.ad_bonus);
+        let mitigated_damage = compute_mitigated_damage(
+            attacker_stats,
+            &target_stats,
+            unmitigated_damage,
+            DamageType::Physical,
+        );
+
+        let damage_info = simulation::on_damage_from_item_active(
+            &mitigated_damage,
+            DamageType::Physical,
+            state,
+            self.item,
+        );
+
+        // the secondary targets in the area of the active take damage as well
+        let ability = find_ability(
+            game_params.abilities,
+            event.attack_type.unwrap(),
+            game_params.config,
+        );
+        let secondary_targets_hit = area::on_area_damage(
+            &ability.hit_pattern,
+            DamageType::Physical,
+            DamageSource::ItemActive,
+            None,
+            Some(self.item),
+            state,
+            game_params,
+            |target_stats| {
+                compute_mitigated_damage(
+                    attacker_stats,
+                    target_stats,
+                    unmitigated_damage,
+                    DamageType::Physical,
+                )
+            },
+        );
+
+        // the slowed targets run away slower (see area::target_movement_speed)
+        if let Some(slow_duration) = self.slow_duration {
+            state.add_target_aura(Aura::Slow, Some(slow_duration), None, events);
+            for index in secondary_targets_hit {
+                area::add_secondary_target_aura(index, Aura::Slow, slow_duration, state);
+            }
+        }
+
+        on_post_damage_events(
+            &damage_info,
+            attacker_stats,
+            state,
+            game_params,
+            event,
+            events,
+        );
+    }
+}
+
+// Youmuu's Ghostblade
+pub struct WraithStep {
+    duration: u64,
+}
+
+impl ScriptedEffect for WraithStep {
+    fn on_effect(
+        &self,
+        _attacker_stats: &AttackerStats,
+        state: &mut State<'_>,
+        _game_params: &GameParams<'_>,
+        _event: &Event,
+        events: &mut BinaryHeap<Event>,
+    ) {
+        state.add_attacker_aura(Aura::WraithStep, Some(self.duration), None, events);
+    }
+}
+
+// Randuin's Omen
+pub struct Humility {
+    slow_duration: u64,
+}
+
+impl ScriptedEffect for Humility {
+    fn on_effect(
+        &self,
+        _attacker_stats: &AttackerStats,
+        state: &mut State<'_>,
+        game_params: &GameParams<'_>,
+        event: &Event,
+        events: &mut BinaryHeap<Event>,
+    ) {
+        state.add_target_aura(Aura::Slow, Some(self.slow_duration), None, events);
+
+        // every enemy around the attacker is slowed
+        let ability = find_ability(
+            game_params.abilities,
+            event.attack_type.unwrap(),
+            game_params.config,
+        );
+        for index in area::secondary_targets_hit(&ability.hit_pattern, state, game_params) {
+            area::add_secondary_target_aura(index, Aura::Slow, self.slow_duration, state);
+        }
+    }
+}
+
+// the actives share the SpellData of abilities, so that the simulation casts them like any
+// other command. They can only be cast when their item is equipped.
+pub fn pull_item_actives() -> Vec<SpellData> {
+    vec![
+        item_active(
+            AttackType::ProfaneHydra,
+            10_000,
+            HitPattern::Circle {
+                radius: 450.0,
+                center: Unit::Attacker,
+            },
+            &ActiveDamage {
+                item: Item::ProfaneHydra,
+                total_ad_ratio: 0.8,
+                slow_duration: None,
+            },
+        ),
+        // the half circle in front of the attacker
+        item_active(
+            AttackType::RavenousHydra,
+            10_000,
+            HitPattern::Cone {
+                range: 450.0,
+                angle_deg: 180.0,
+            },
+            &ActiveDamage {
+                item: Item::RavenousHydra,
+                total_ad_ratio: 0.8,
+                slow_duration: None,
+            },
+        ),
+        item_active(
+            AttackType::YoumuusGhostblade,
+            45_000,
+            HitPattern::Single,
+            &WraithStep { duration: 6_000 },
+        ),
+        item_active(
+            AttackType::Stridebreaker,
+            15_000,
+            HitPattern::Circle {
+                radius: 450.0,
+                center: Unit::Attacker,
+            },
+            &ActiveDamage {
+                item: Item::Stridebreaker,
+                total_ad_ratio: 0.8,
+                slow_duration: Some(2_000),
+            },
+        ),
+        item_active(
+            AttackType::RanduinsOmen,
+            90_000,
+            HitPattern::Circle {
+                radius: 500.0,
+                center: Unit::Attacker,
+            },
+            &Humility {
+                slow_duration: 2_000,
+            },
+        ),
+    ]
+}
+
+fn item_active(
+    attack_type: AttackType,
+    cooldown_ms: u64,
+    hit_pattern: HitPattern,
+    active_effect: &'static dyn ScriptedEffect,
+) -> SpellData {
+    SpellData {
+        key: attack_type.to_string(),
+        attack_type,
+        coefficient_ad: 0.0,
+        coefficient_ap: 0.0,
+        ad_damage: HashMap::new(),
+        ap_damage: HashMap::new(),
+        variation_name: None,
+        selected_when: None,
+        rank_by_level: [1; 18],
+        // the actives are instant
+        cast_time_ms: None,
+        // the actives hitting around the attacker need the target within their area
+        range: match hit_pattern {
+            HitPattern::Circle {
+                radius,
+                center: Unit::Attacker,
+            } => Some(radius),
+            HitPattern::Cone { range, .. } => Some(range),
+            _ => None,
+        },
+        cooldown_ms: Some(HashMap::from([(1, cooldown_ms)])),
+        cost: None,
+        category: None,
+        // the damage (if any) is dealt by the active effect
+        damage_type: None,
+        hit_pattern,
+        active_effect: Some(active_effect),
+        recast_gap_duration: None,
+        recast_charges: None,
+        recast_window: None,
+        charges: None,
+    }
+}
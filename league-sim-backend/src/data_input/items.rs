@@ -1,15 +1,19 @@
-use std::{cmp, collections::HashMap, fs::File, io::BufReader};
-
-use serde_json::Value;
+use std::{
+    cmp,
+    collections::{BinaryHeap, HashMap},
+    fmt,
+};
 
 use crate::{
+    area::{self, HitPattern},
     attack::{compute_mitigated_damage, AttackType},
-    simulation::{self, on_post_damage_events, DamageInfo, DamageSource, State},
+    error::SimError,
+    simulation::{self, on_post_damage_events, DamageInfo, DamageSource, Event, HealSource, State},
 };
 
 use super::common::{
-    compute_attacker_stats, compute_target_stats, AttackerStats, DamageType, GameParams,
-    PassiveEffect,
+    compute_attacker_stats, compute_target_stats, AttackerStats, Aura, DamageType, DefensiveStats,
+    GameParams, PassiveEffect, Unit,
 };
 
 use shared_structs::items_cdragon::*;
@@ -47,6 +51,13 @@ pub enum Item {
     FrozenHeart,
     Stridebreaker,
     BladeofTheRuinedKing,
+    InfinityEdge,
+}
+
+impl fmt::Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
 }
 
 impl Item {
@@ -81,45 +92,48 @@ impl Item {
             "Frozen Heart" => Some(Item::FrozenHeart),
             "Stridebreaker" => Some(Item::Stridebreaker),
             "Blade of the Ruined King" => Some(Item::BladeofTheRuinedKing),
+            "Infinity Edge" => Some(Item::InfinityEdge),
             &_ => None,
         }
     }
 
-    pub fn to_string(self) -> String {
+    pub fn name(self) -> &'static str {
         match self {
-            Item::Unknown => "".to_string(),
-            Item::IonianBootsofLucidity => "Ionian Boots of Lucidity".to_string(),
-            Item::BerserkersGreaves => "Berserker's Greaves".to_string(),
-            Item::YoumuusGhostblade => "Youmuu's Ghostblade".to_string(),
-            Item::Opportunity => "Opportunity".to_string(),
-            Item::EdgeofNight => "Edge of Night".to_string(),
-            Item::SeryldasGrudge => "Serylda's Grudge".to_string(),
-            Item::ProfaneHydra => "Profane Hydra".to_string(),
-            Item::Eclipse => "Eclipse".to_string(),
-            Item::MawofMalmortius => "Maw of Malmortius".to_string(),
-            Item::UmbralGlaive => "Umbral Glaive".to_string(),
-            Item::Hubris => "Hubris".to_string(),
-            Item::DeathsDance => "Death's Dance".to_string(),
-            Item::LordDominiksRegards => "Lord Dominik's Regards".to_string(),
-            Item::MortalReminder => "Mortal Reminder".to_string(),
-            Item::ChempunkChainsword => "Chempunk Chainsword".to_string(),
-            Item::BlackCleaver => "Black Cleaver".to_string(),
-            Item::TheCollector => "The Collector".to_string(),
-            Item::Bloodthirster => "Bloodthirster".to_string(),
-            Item::VoltaicCyclosword => "Voltaic Cyclosword".to_string(),
-            Item::SerpentsFang => "Serpent's Fang".to_string(),
-            Item::GuardianAngel => "Guardian Angel".to_string(),
-            Item::SpearofShojin => "Spear of Shojin".to_string(),
-            Item::AxiomArc => "Axiom Arc".to_string(),
-            Item::SunderedSky => "Sundered Sky".to_string(),
-            Item::RavenousHydra => "Ravenous Hydra".to_string(),
-            Item::RanduinsOmen => "Randuin's Omen".to_string(),
-            Item::FrozenHeart => "Frozen Heart".to_string(),
-            Item::Stridebreaker => "Stridebreaker".to_string(),
-            Item::BladeofTheRuinedKing => "Blade of the Ruined King".to_string(),
+            Item::Unknown => "",
+            Item::IonianBootsofLucidity => "Ionian Boots of Lucidity",
+            Item::BerserkersGreaves => "Berserker's Greaves",
+            Item::YoumuusGhostblade => "Youmuu's Ghostblade",
+            Item::Opportunity => "Opportunity",
+            Item::EdgeofNight => "Edge of Night",
+            Item::SeryldasGrudge => "Serylda's Grudge",
+            Item::ProfaneHydra => "Profane Hydra",
+            Item::Eclipse => "Eclipse",
+            Item::MawofMalmortius => "Maw of Malmortius",
+            Item::UmbralGlaive => "Umbral Glaive",
+            Item::Hubris => "Hubris",
+            Item::DeathsDance => "Death's Dance",
+            Item::LordDominiksRegards => "Lord Dominik's Regards",
+            Item::MortalReminder => "Mortal Reminder",
+            Item::ChempunkChainsword => "Chempunk Chainsword",
+            Item::BlackCleaver => "Black Cleaver",
+            Item::TheCollector => "The Collector",
+            Item::Bloodthirster => "Bloodthirster",
+            Item::VoltaicCyclosword => "Voltaic Cyclosword",
+            Item::SerpentsFang => "Serpent's Fang",
+            Item::GuardianAngel => "Guardian Angel",
+            Item::SpearofShojin => "Spear of Shojin",
+            Item::AxiomArc => "Axiom Arc",
+            Item::SunderedSky => "Sundered Sky",
+            Item::RavenousHydra => "Ravenous Hydra",
+            Item::RanduinsOmen => "Randuin's Omen",
+            Item::FrozenHeart => "Frozen Heart",
+            Item::Stridebreaker => "Stridebreaker",
+            Item::BladeofTheRuinedKing => "Blade of the Ruined King",
+            Item::InfinityEdge => "Infinity Edge",
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn handle_on_pre_damage(
         &self,
         passive_effect: &PassiveEffect,
@@ -256,6 +270,7 @@ impl Item {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn handle_on_post_damage(
         &self,
         passive_effect: &PassiveEffect,
@@ -305,7 +320,7 @@ impl Item {
                         return;
                     }
 
-                    if let Some(aura_app) =
+                    if let Some(_aura_app) =
                         state.target_auras.get(&super::common::Aura::EverRisingMoon)
                     {
                         state.end_early_target_aura(
@@ -342,9 +357,26 @@ impl Item {
                             Item::Eclipse,
                         );
 
+                        let shield = match game_params.champion_data.attack_type {
+                            super::champions::AttackType::Melee => {
+                                160.0 + 0.4 * attacker_stats.ad_bonus
+                            }
+                            super::champions::AttackType::Ranged => {
+                                80.0 + 0.2 * attacker_stats.ad_bonus
+                            }
+                        };
+                        simulation::on_shield(
+                            shield,
+                            super::common::Aura::EverRisingMoonShield,
+                            Some(2_000),
+                            Item::Eclipse,
+                            state,
+                            events,
+                        );
+
                         on_post_damage_events(
                             &damage_info,
-                            &attacker_stats,
+                            attacker_stats,
                             state,
                             game_params,
                             event,
@@ -364,7 +396,7 @@ impl Item {
             Item::SpearofShojin => match passive_effect {
                 PassiveEffect::FocusedWill => {
                     if damage_info.source_ability.is_some_and(|attack_type| {
-                        vec![AttackType::Q, AttackType::W, AttackType::E].contains(&attack_type)
+                        [AttackType::Q, AttackType::W, AttackType::E].contains(&attack_type)
                     }) {
                         let stack = if let Some(aura_app) =
                             state.attacker_auras.get(&super::common::Aura::FocusedWill)
@@ -397,9 +429,9 @@ impl Item {
             },
             Item::TheCollector => match passive_effect {
                 PassiveEffect::Death => {
-                    let current_health =
-                        game_params.initial_target_stats.current_health - state.total_damage;
-                    let hp_prc = current_health / game_params.initial_target_stats.max_health;
+                    let target_stats = compute_target_stats(game_params, state);
+                    let current_health = target_stats.current_health;
+                    let hp_prc = current_health / target_stats.max_health;
                     if hp_prc < 5.0 {
                         simulation::on_damage_from_item(
                             &current_health,
@@ -421,16 +453,16 @@ impl Item {
         duration: u64,
         state: &mut State<'_>,
         game_params: &GameParams<'_>,
-        event: &crate::simulation::Event,
+        _event: &crate::simulation::Event,
         events: &mut std::collections::BinaryHeap<crate::simulation::Event>,
     ) {
         match &self {
             Item::VoltaicCyclosword => match passive_effect {
                 PassiveEffect::Energized => {
                     let attacker_stats = compute_attacker_stats(game_params, state);
-                    let move_speed = (attacker_stats.movement_speed_base
-                        + attacker_stats.movement_speed_flat_bonus)
-                        * (1.0 + attacker_stats.movement_speed_perc_bonus);
+                    // the attacker is assumed to keep moving around its target, even when
+                    // it stands within range
+                    let move_speed = area::attacker_movement_speed(&attacker_stats);
 
                     let distance_traveled = move_speed * duration as f64 / 1000.0;
                     let generated_stacks = (distance_traveled / 24.0) as u64;
@@ -462,17 +494,117 @@ impl Item {
     }
 }
 
+// Bloodthirster: the healing from life steal in excess of the maximum health is converted
+// into a shield, which lasts until destroyed
+pub fn ichorshield(
+    overheal: f64,
+    state: &mut State<'_>,
+    game_params: &GameParams<'_>,
+    events: &mut BinaryHeap<Event>,
+) {
+    let max_shield = 165.0 + 15.0 * game_params.level.saturating_sub(8) as f64;
+    let current_shield = state
+        .attacker_auras
+        .get(&Aura::Ichorshield)
+        .and_then(|aura_app| aura_app.stacks)
+        .unwrap_or_default() as f64;
+
+    let gained_shield = f64::min(overheal, max_shield - current_shield);
+    if gained_shield > 0.0 {
+        simulation::on_shield(
+            gained_shield,
+            Aura::Ichorshield,
+            None,
+            Item::Bloodthirster,
+            state,
+            events,
+        );
+    }
+}
+
+// Profane and Ravenous Hydra: the basic attacks deal a part of the attack damage to the
+// other enemies around the primary target
+pub fn cleave(
+    damage_info: &DamageInfo,
+    attacker_stats: &AttackerStats,
+    state: &mut State<'_>,
+    game_params: &GameParams<'_>,
+) {
+    if damage_info.source_ability != Some(AttackType::AA) || damage_info.target_unit != state.target
+    {
+        return;
+    }
+
+    let ad_ratio = match game_params.champion_data.attack_type {
+        super::champions::AttackType::Melee => 0.4,
+        super::champions::AttackType::Ranged => 0.2,
+    };
+    let unmitigated_damage = ad_ratio * (attacker_stats.ad_base + attacker_stats.ad_bonus);
+    let item = game_params
+        .items
+        .iter()
+        .find(|item_data| item_data.passives.contains(&PassiveEffect::Cleave))
+        .map(|item_data| item_data.item);
+
+    area::on_area_damage(
+        &HitPattern::Circle {
+            radius: 350.0,
+            center: Unit::Target,
+        },
+        DamageType::Physical,
+        DamageSource::ItemPassive,
+        None,
+        item,
+        state,
+        game_params,
+        |target_stats| {
+            compute_mitigated_damage(
+                attacker_stats,
+                target_stats,
+                unmitigated_damage,
+                DamageType::Physical,
+            )
+        },
+    );
+}
+
+// Death's Dance: the takedown heals for 75% of the bonus AD over 2 seconds. The ticks
+// left when the fight ends are not simulated
+const DEFY_DURATION_MS: u64 = 2_000;
+const DEFY_TICK_INTERVAL_MS: u64 = 250;
+
+pub fn defy(state: &mut State<'_>, events: &mut BinaryHeap<Event>) {
+    for tick in 1..=DEFY_DURATION_MS / DEFY_TICK_INTERVAL_MS {
+        simulation::insert_defy_tick_event(events, state.time_ms + tick * DEFY_TICK_INTERVAL_MS);
+    }
+}
+
+// the heal is split evenly between the ticks
+pub fn on_defy_tick(state: &mut State<'_>, game_params: &GameParams<'_>) {
+    let attacker_stats = compute_attacker_stats(game_params, state);
+    simulation::on_heal(
+        0.75 * attacker_stats.ad_bonus * DEFY_TICK_INTERVAL_MS as f64 / DEFY_DURATION_MS as f64,
+        HealSource::ItemPassive,
+        None,
+        Some(Item::DeathsDance),
+        state,
+        game_params,
+    );
+}
+
 #[derive(Debug)]
 pub struct ItemData {
     pub item: Item,
     pub id: u64,
     pub total_cost: u64,
     pub offensive_stats: AttackerStats,
+    // only used when the item is held by the target
+    pub defensive_stats: DefensiveStats,
     pub item_groups: Vec<String>,
     pub passives: Vec<PassiveEffect>,
 }
 
-pub fn pull_items_data(item_ids: &[u64]) -> HashMap<u64, ItemData> {
+pub fn pull_items_data(item_ids: &[u64]) -> Result<HashMap<u64, ItemData>, SimError> {
     let item_map: HashMap<String, ItemDataCdragon> = include!("items_gen/items_cdragon_gen.rs");
     let item_map_meraki: HashMap<String, ItemDataMeraki> =
         include!("items_gen/items_meraki_gen.rs");
@@ -480,13 +612,18 @@ pub fn pull_items_data(item_ids: &[u64]) -> HashMap<u64, ItemData> {
     let mut map = HashMap::new();
     let mut sanity_checker: Vec<String> = Vec::new();
     for (_key, item_data_meraki) in item_map_meraki.iter() {
-        let item_id = item_data_meraki.id as u64;
-        let item_data = item_map.get(&format!("Items/{item_id}")).unwrap();
-
+        let item_id = item_data_meraki.id;
         if !item_ids.contains(&item_id) {
             continue;
         }
 
+        let item_data =
+            item_map
+                .get(&format!("Items/{item_id}"))
+                .ok_or_else(|| SimError::InvalidGameData {
+                    reason: format!("item {} is missing from the cdragon data", item_id),
+                })?;
+
         let stats = AttackerStats {
             ability_haste: item_data_meraki
                 .clone()
@@ -511,6 +648,29 @@ pub fn pull_items_data(item_ids: &[u64]) -> HashMap<u64, ItemData> {
                 .unwrap_or_default()
                 .percent
                 / 100.0,
+            armor_penetration_bonus_perc: item_data_meraki
+                .clone()
+                .stats
+                .unwrap_or_default()
+                .armor_penetration
+                .unwrap_or_default()
+                .percent_bonus
+                / 100.0,
+            magic_penetration_flat: item_data_meraki
+                .clone()
+                .stats
+                .unwrap_or_default()
+                .magic_penetration
+                .unwrap_or_default()
+                .flat,
+            magic_penetration_perc: item_data_meraki
+                .clone()
+                .stats
+                .unwrap_or_default()
+                .magic_penetration
+                .unwrap_or_default()
+                .percent
+                / 100.0,
             crit_chance: item_data_meraki
                 .clone()
                 .stats
@@ -519,6 +679,14 @@ pub fn pull_items_data(item_ids: &[u64]) -> HashMap<u64, ItemData> {
                 .unwrap_or_default()
                 .percent
                 / 100.0,
+            crit_damage: item_data_meraki
+                .clone()
+                .stats
+                .unwrap_or_default()
+                .critical_strike_damage
+                .unwrap_or_default()
+                .percent
+                / 100.0,
             lethality: item_data_meraki
                 .clone()
                 .stats
@@ -550,9 +718,39 @@ pub fn pull_items_data(item_ids: &[u64]) -> HashMap<u64, ItemData> {
                 .unwrap_or_default()
                 .percent
                 / 100.0,
+            mana: item_data_meraki
+                .clone()
+                .stats
+                .unwrap_or_default()
+                .mana
+                .unwrap_or_default()
+                .flat,
+            life_steal: item_data_meraki
+                .clone()
+                .stats
+                .unwrap_or_default()
+                .lifesteal
+                .unwrap_or_default()
+                .percent
+                / 100.0,
+            omnivamp: item_data_meraki
+                .clone()
+                .stats
+                .unwrap_or_default()
+                .omnivamp
+                .unwrap_or_default()
+                .percent
+                / 100.0,
             ..Default::default()
         };
 
+        let meraki_stats = item_data_meraki.stats.clone().unwrap_or_default();
+        let defensive_stats = DefensiveStats {
+            health: meraki_stats.health.unwrap_or_default().flat,
+            armor: meraki_stats.armor.unwrap_or_default().flat,
+            magic_resistance: meraki_stats.magic_resistance.unwrap_or_default().flat,
+        };
+
         let mut item_groups = Vec::new();
 
         let item_groups_source = item_data.m_item_groups.clone();
@@ -589,10 +787,11 @@ pub fn pull_items_data(item_ids: &[u64]) -> HashMap<u64, ItemData> {
                 .prices
                 .clone()
                 .unwrap_or_default()
-                .total as u64,
+                .total,
             offensive_stats: stats,
+            defensive_stats,
             item_groups,
-            passives: passives,
+            passives,
         };
 
         map.insert(item.id, item);
@@ -604,11 +803,17 @@ pub fn pull_items_data(item_ids: &[u64]) -> HashMap<u64, ItemData> {
     sanity_checker.dedup();
     let length_after_dedup = sanity_checker.len();
     if length_before_dedup != length_after_dedup {
-        println!("{:#?}", sanity_checker);
-        panic!();
+        return Err(SimError::InvalidGameData {
+            reason: format!("ambiguous item groups: {:?}", sanity_checker),
+        });
+    }
+
+    // every requested item must be known
+    if let Some(item_id) = item_ids.iter().find(|item_id| !map.contains_key(item_id)) {
+        return Err(SimError::UnknownItem { item_id: *item_id });
     }
 
-    map
+    Ok(map)
 }
 
 pub fn has_item_group_duplicates(selected_items: &[&ItemData]) -> bool {
@@ -1 +1 @@
-vec![("Items/6696".into(),ItemDataCdragon {item_id: 6696i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{bd69c87a}".into()].into_iter().collect()}),("Items/3814".into(),ItemDataCdragon {item_id: 3814i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{c6c2af47}".into(),"{3fcdbdac}".into()].into_iter().collect()}),("Items/6697".into(),ItemDataCdragon {item_id: 6697i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{be69ca0d}".into()].into_iter().collect()}),("Items/3072".into(),ItemDataCdragon {item_id: 3072i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{23adb57f}".into()].into_iter().collect()}),("Items/6609".into(),ItemDataCdragon {item_id: 6609i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{b458712e}".into()].into_iter().collect()}),("Items/3071".into(),ItemDataCdragon {item_id: 3071i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{24adb712}".into(),"Items/ItemGroups/LastWhisper".into()].into_iter().collect()}),("Items/3158".into(),ItemDataCdragon {item_id: 3158i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"Items/ItemGroups/Boots".into(),"Items/ItemGroups/BootsWithoutActives".into()].into_iter().collect()}),("Items/3026".into(),ItemDataCdragon {item_id: 3026i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{2da0f94a}".into()].into_iter().collect()}),("Items/6695".into(),ItemDataCdragon {item_id: 6695i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{bc69c6e7}".into()].into_iter().collect()}),("Items/3110".into(),ItemDataCdragon {item_id: 3110i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{0c3b352c}".into()].into_iter().collect()}),("Items/3179".into(),ItemDataCdragon {item_id: 3179i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{993602f5}".into()].into_iter().collect()}),("Items/6676".into(),ItemDataCdragon {item_id: 6676i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{ad65321c}".into()].into_iter().collect()}),("Items/6699".into(),ItemDataCdragon {item_id: 6699i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{b069b403}".into()].into_iter().collect()}),("Items/3036".into(),ItemDataCdragon {item_id: 3036i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"Items/ItemGroups/LastWhisper".into()].into_iter().collect()}),("Items/3033".into(),ItemDataCdragon {item_id: 3033i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{aaa3fca8}".into(),"Items/ItemGroups/LastWhisper".into()].into_iter().collect()}),("Items/6333".into(),ItemDataCdragon {item_id: 6333i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{001c1988}".into()].into_iter().collect()}),("Items/3156".into(),ItemDataCdragon {item_id: 3156i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"Items/ItemGroups/LifelineItems".into()].into_iter().collect()}),("Items/3153".into(),ItemDataCdragon {item_id: 3153i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{973182a1}".into()].into_iter().collect()}),("Items/6694".into(),ItemDataCdragon {item_id: 6694i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"Items/ItemGroups/LastWhisper".into()].into_iter().collect()}),("Items/3074".into(),ItemDataCdragon {item_id: 3074i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"Items/ItemGroups/LegendaryClearingItems".into()].into_iter().collect()}),("Items/6698".into(),ItemDataCdragon {item_id: 6698i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"Items/ItemGroups/LegendaryClearingItems".into()].into_iter().collect()}),("Items/3143".into(),ItemDataCdragon {item_id: 3143i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{9733c138}".into()].into_iter().collect()}),("Items/6631".into(),ItemDataCdragon {item_id: 6631i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{b45aafc5}".into(),"Items/ItemGroups/LegendaryClearingItems".into()].into_iter().collect()}),("Items/6692".into(),ItemDataCdragon {item_id: 6692i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{b969c22e}".into()].into_iter().collect()}),("Items/3006".into(),ItemDataCdragon {item_id: 3006i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"Items/ItemGroups/Boots".into(),"Items/ItemGroups/BootsWithoutActives".into()].into_iter().collect()}),("Items/3161".into(),ItemDataCdragon {item_id: 3161i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{0538eb90}".into()].into_iter().collect()}),("Items/6701".into(),ItemDataCdragon {item_id: 6701i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{ced2e7d1}".into()].into_iter().collect()}),("Items/3142".into(),ItemDataCdragon {item_id: 3142i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{9833c2cb}".into()].into_iter().collect()}),("Items/6610".into(),ItemDataCdragon {item_id: 6610i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{a7561e20}".into()].into_iter().collect()})].into_iter().collect()
\ No newline at end of file
+vec![("Items/6696".into(),ItemDataCdragon {item_id: 6696i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{bd69c87a}".into()].into_iter().collect()}),("Items/3814".into(),ItemDataCdragon {item_id: 3814i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{c6c2af47}".into(),"{3fcdbdac}".into()].into_iter().collect()}),("Items/6697".into(),ItemDataCdragon {item_id: 6697i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{be69ca0d}".into()].into_iter().collect()}),("Items/3072".into(),ItemDataCdragon {item_id: 3072i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{23adb57f}".into()].into_iter().collect()}),("Items/6609".into(),ItemDataCdragon {item_id: 6609i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{b458712e}".into()].into_iter().collect()}),("Items/3071".into(),ItemDataCdragon {item_id: 3071i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{24adb712}".into(),"Items/ItemGroups/LastWhisper".into()].into_iter().collect()}),("Items/3158".into(),ItemDataCdragon {item_id: 3158i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"Items/ItemGroups/Boots".into(),"Items/ItemGroups/BootsWithoutActives".into()].into_iter().collect()}),("Items/3026".into(),ItemDataCdragon {item_id: 3026i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{2da0f94a}".into()].into_iter().collect()}),("Items/6695".into(),ItemDataCdragon {item_id: 6695i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{bc69c6e7}".into()].into_iter().collect()}),("Items/3110".into(),ItemDataCdragon {item_id: 3110i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{0c3b352c}".into()].into_iter().collect()}),("Items/3179".into(),ItemDataCdragon {item_id: 3179i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{993602f5}".into()].into_iter().collect()}),("Items/6676".into(),ItemDataCdragon {item_id: 6676i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{ad65321c}".into()].into_iter().collect()}),("Items/6699".into(),ItemDataCdragon {item_id: 6699i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{b069b403}".into()].into_iter().collect()}),("Items/3036".into(),ItemDataCdragon {item_id: 3036i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"Items/ItemGroups/LastWhisper".into()].into_iter().collect()}),("Items/3033".into(),ItemDataCdragon {item_id: 3033i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{aaa3fca8}".into(),"Items/ItemGroups/LastWhisper".into()].into_iter().collect()}),("Items/6333".into(),ItemDataCdragon {item_id: 6333i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{001c1988}".into()].into_iter().collect()}),("Items/3156".into(),ItemDataCdragon {item_id: 3156i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"Items/ItemGroups/LifelineItems".into()].into_iter().collect()}),("Items/3153".into(),ItemDataCdragon {item_id: 3153i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{973182a1}".into()].into_iter().collect()}),("Items/6694".into(),ItemDataCdragon {item_id: 6694i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"Items/ItemGroups/LastWhisper".into()].into_iter().collect()}),("Items/3074".into(),ItemDataCdragon {item_id: 3074i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"Items/ItemGroups/LegendaryClearingItems".into()].into_iter().collect()}),("Items/6698".into(),ItemDataCdragon {item_id: 6698i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"Items/ItemGroups/LegendaryClearingItems".into()].into_iter().collect()}),("Items/3143".into(),ItemDataCdragon {item_id: 3143i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{9733c138}".into()].into_iter().collect()}),("Items/6631".into(),ItemDataCdragon {item_id: 6631i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{b45aafc5}".into(),"Items/ItemGroups/LegendaryClearingItems".into()].into_iter().collect()}),("Items/6692".into(),ItemDataCdragon {item_id: 6692i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{b969c22e}".into()].into_iter().collect()}),("Items/3006".into(),ItemDataCdragon {item_id: 3006i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"Items/ItemGroups/Boots".into(),"Items/ItemGroups/BootsWithoutActives".into()].into_iter().collect()}),("Items/3161".into(),ItemDataCdragon {item_id: 3161i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{0538eb90}".into()].into_iter().collect()}),("Items/6701".into(),ItemDataCdragon {item_id: 6701i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{ced2e7d1}".into()].into_iter().collect()}),("Items/3142".into(),ItemDataCdragon {item_id: 3142i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{9833c2cb}".into()].into_iter().collect()}),("Items/6610".into(),ItemDataCdragon {item_id: 6610i64,m_item_groups: vec!["Items/ItemGroups/Default".into(),"{a7561e20}".into()].into_iter().collect()}),("Items/3031".into(),ItemDataCdragon {item_id: 3031i64,m_item_groups: vec!["Items/ItemGroups/Default".into()].into_iter().collect()})].into_iter().collect()
\ No newline at end of file
@@ -1 +1 @@
-vec![("3026".into(),ItemDataMeraki {name: "Guardian Angel".into(),id: 3026u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3026_fighter_t3_guardianangel.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Rebirth".into()),effects: "Upon taking [[death|lethal damage]], enter {{tip|resurrection}} for 4 seconds, during which you are {{tip|invulnerable}}, {{tip|untargetable}}, and unable to act, and afterwards {{tip|heal}} for {{as|50% of \'\'\'base\'\'\' health}} and restore {{as|100% of \'\'\'maximum\'\'\' mana}} (300 second cooldown, starts after resurrection ends).".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: Some(StatDetails {flat: 45f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),armor_penetration: None,attack_damage: Some(StatDetails {flat: 55f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: None,omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3200u64}),purchasable: true}}),("3814".into(),ItemDataMeraki {name: "Edge of Night".into(),id: 3814u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3814_assassin_t3_edgeofnight.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Annul".into()),effects: "Grants a {{tip|spell shield}} that blocks the next hostile ability (40 second cooldown, timer restarts upon taking damage from champions).".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 50f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,gold_per10: None,heal_and_shield_power: None,health: Some(StatDetails {flat: 250f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),health_regen: None,lethality: Some(StatDetails {flat: 15f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: None,omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3000u64}),purchasable: true}}),("3033".into(),ItemDataMeraki {name: "Mortal Reminder".into(),id: 3033u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3033_marksman_t3_mortalreminder.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Grievous Wounds".into()),effects: "Dealing {{as|physical damage}} to enemy champions inflicts them with {{tip|Grievous Wounds}} for 3 seconds.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: Some(StatDetails {flat: 0f64,percent: 35f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_damage: Some(StatDetails {flat: 35f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: Some(StatDetails {flat: 0f64,percent: 25f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: None,omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3300u64}),purchasable: true}}),("6697".into(),ItemDataMeraki {name: "Hubris".into(),id: 6697u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/6697_hubris.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Eminence".into()),effects: "Scoring a {{tip|takedown}} against an enemy champion within 3 seconds of damaging them generates a permanent stack and grants you {{as|15|ad}} (+ 2 per stack) {{as|\'\'\'bonus\'\'\' attack damage}} for 90 seconds.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 60f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: Some(StatDetails {flat: 18f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: Some(StatDetails {flat: 10f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3000u64}),purchasable: true}}),("3161".into(),ItemDataMeraki {name: "Spear of Shojin".into(),id: 3161u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3161_fighter_t3_spearofshojin.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Dragonforce".into()),effects: "Gain 25 [[Haste#Basic ability haste|basic ability haste]].".into(),range: None,cooldown: None,stats: None},Passive {unique: true,mythic: false,name: Some("Focused Will".into()),effects: "Dealing [[ability damage]] with a non-innate [[champion ability|ability]] grants a stack for 6 seconds, stacking up to 4 times and up to once per {{tip|cast instance}} per second and every second for [[damage over time]] abilities. For each stack, your non-{{tip|basic damage}} dealt by non-[[item|items]] gain 3% increased damage, for a total increase of 12% at \'\'\'maximum\'\'\' stacks.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 45f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,gold_per10: None,heal_and_shield_power: None,health: Some(StatDetails {flat: 450f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: None,omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3100u64}),purchasable: true}}),("6695".into(),ItemDataMeraki {name: "Serpent\'s Fang".into(),id: 6695u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/6695_assassin_t3_serpentsfang.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Shield Reaver".into()),effects: "Dealing damage to an enemy champion inflicts them with venom for 3 seconds, reducing any {{tip|shield|shields}} they gain within the duration by {{rd|50%|35%}}, and if the target was not already afflicted by the venom, reducing all of their active shields by the same amount.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 55f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: Some(StatDetails {flat: 15f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: None,omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 2500u64}),purchasable: true}}),("6696".into(),ItemDataMeraki {name: "Axiom Arc".into(),id: 6696u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/6696_axiomarc.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Flux".into()),effects: "Scoring a {{tip|takedown}} against an enemy champion within 3 seconds of damaging them refunds 15% {{as|(+ {{fd|0.15}}% per 1 Lethality)}} of your ultimate ability\'s \'\'\'total\'\'\' cooldown.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 55f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: Some(StatDetails {flat: 18f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: Some(StatDetails {flat: 20f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3000u64}),purchasable: true}}),("6609".into(),ItemDataMeraki {name: "Chempunk Chainsword".into(),id: 6609u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/6609_fighter_t3_chempunkchainsword.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Hackshorn".into()),effects: "Dealing {{as|physical damage}} to enemy champions inflicts them with {{tip|Grievous Wounds}} for 3 seconds.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 45f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,gold_per10: None,heal_and_shield_power: None,health: Some(StatDetails {flat: 450f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: Some(StatDetails {flat: 15f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3100u64}),purchasable: true}}),("3072".into(),ItemDataMeraki {name: "Bloodthirster".into(),id: 3072u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3072_fighter_t3_bloodthirster.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Ichorshield".into()),effects: "Convert the {{tip|healing}} received from {{sti|life steal}} in excess of {{as|\'\'\'maximum\'\'\' health}} into a {{tip|shield}} for up to {{pp|165 to 315 for 11|1;9 to 18|formula=165 base, then +15 per level starting from level 9.}}, which lasts until destroyed.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 80f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: Some(StatDetails {flat: 0f64,percent: 15f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: None,omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3400u64}),purchasable: true}}),("6333".into(),ItemDataMeraki {name: "Death\'s Dance".into(),id: 6333u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/6333_fighter_t3_deathsdance.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Ignore Pain".into()),effects: "Reduces {{rd|30%|10%}} of all {{tt|post-mitigation|Damage calculated after modifiers}} {{as|physical|physical damage}} and {{as|magic|magic damage}} damage received and instead stores the damage to successively take it as {{as|true damage}} over 3 seconds, dealing a third of the stored damage each second.".into(),range: None,cooldown: None,stats: None},Passive {unique: true,mythic: false,name: Some("Defy".into()),effects: "If an enemy champion dies within 3 seconds of you damaging them, removes \'\'Ignore Pain\'s\'\' remaining stored damage and {{tip|heals}} you for {{as|75% \'\'\'bonus\'\'\' AD}} over 2 seconds.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: Some(StatDetails {flat: 50f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),armor_penetration: None,attack_damage: Some(StatDetails {flat: 60f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: Some(StatDetails {flat: 15f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3300u64}),purchasable: true}}),("3110".into(),ItemDataMeraki {name: "Frozen Heart".into(),id: 3110u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3110_tank_t3_frozenheart.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Winter\'s Caress".into()),effects: "{{tip|Cripple|Cripples}} the [[attack speed]] of enemy champions within {{tt|700 units|center to edge}} by 20%.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: Some(StatDetails {flat: 75f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),armor_penetration: None,attack_damage: None,attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: Some(StatDetails {flat: 400f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),mana_regen: None,movespeed: None,ability_haste: Some(StatDetails {flat: 20f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 2500u64}),purchasable: true}}),("6699".into(),ItemDataMeraki {name: "Voltaic Cyclosword".into(),id: 6699u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/6699_voltaiccyclosword.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Energized".into()),effects: "Moving and basic attacking generates \'\'Energize\'\' stacks, up to 100.".into(),range: None,cooldown: None,stats: None},Passive {unique: true,mythic: false,name: Some("Galvanize".into()),effects: "{{tip|Energized}} stack generation is increased by 75% for distance travelled from {{tip|dash|dashes}}, {{tip|lunge|lunges}} and {{tip|blink|blinks}}, as well as while moving in {{tip|stealth}}.".into(),range: None,cooldown: None,stats: None},Passive {unique: true,mythic: false,name: Some("Firmament".into()),effects: "When fully {{tip|Energized}}, your next basic attack deals {{as|100 \'\'\'bonus\'\'\' physical damage}} [[on-hit]] and {{tip|slow|slows}} the target by {{rd|99%|20%}} for {{fd|0.75}} seconds.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 55f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: Some(StatDetails {flat: 18f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: Some(StatDetails {flat: 10f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3000u64}),purchasable: true}}),("3142".into(),ItemDataMeraki {name: "Youmuu\'s Ghostblade".into(),id: 3142u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3142_assassin_t3_youmuusghostblade.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Haunt".into()),effects: "Gain {{as|{{rd|20|10}} \'\'\'bonus\'\'\' movement speed}} while out-of-combat with enemy champions for 3 seconds.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![Active {unique: true,name: Some("Wraith Step".into()),effects: "Gain {{as|{{rd|20%|15%}} \'\'\'bonus\'\'\' movement speed}} and {{tip|ghosted|ghosting}} for {{rd|6|4}} seconds.".into(),range: None,cooldown: None}].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 55f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: Some(StatDetails {flat: 18f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: Some(StatDetails {flat: 0f64,percent: 4f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),ability_haste: None,omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 2800u64}),purchasable: true}}),("6676".into(),ItemDataMeraki {name: "The Collector".into(),id: 6676u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/6676_marksman_t3_thecollector.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Death".into()),effects: "If you deal {{tt|post-mitigation|Damage calculated after modifiers}} damage that would leave a champion below {{as|5% of their \'\'\'maximum\'\'\' health}}, {{tip|execute}} them.".into(),range: None,cooldown: None,stats: None},Passive {unique: true,mythic: false,name: Some("Taxes".into()),effects: "Killing a champion grants you an additional {{g|25}}.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 50f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: Some(StatDetails {flat: 0f64,percent: 25f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: Some(StatDetails {flat: 10f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: None,omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3000u64}),purchasable: true}}),("3071".into(),ItemDataMeraki {name: "Black Cleaver".into(),id: 3071u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3071_fighter_t3_blackcleaver.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Carve".into()),effects: "Dealing {{as|physical damage}} to an enemy champion applies a stack of \'\'Carve\'\' for 6 seconds, stacking up to 5 times. Each stack inflicts {{as|6% armor reduction}}, up to {{as|30%|armor}} at 5 stacks.".into(),range: None,cooldown: None,stats: None},Passive {unique: true,mythic: false,name: Some("Fervor".into()),effects: "Dealing {{as|physical damage}} grants you {{as|20 \'\'\'bonus\'\'\' movement speed}} for 2 seconds.".into(),range: None,cooldown: None,stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: None,attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: Some(StatDetails {flat: 20f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),ability_haste: None,omnivamp: None,tenacity: None})}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 40f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,gold_per10: None,heal_and_shield_power: None,health: Some(StatDetails {flat: 400f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: Some(StatDetails {flat: 20f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3000u64}),purchasable: true}}),("3156".into(),ItemDataMeraki {name: "Maw of Malmortius".into(),id: 3156u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3156_fighter_t3_mawofmalmortius.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Lifeline".into()),effects: "If you would take {{as|magic damage}} that would reduce you below {{as|30% of your \'\'\'maximum\'\'\' health}}, you first gain a {{tip|shield}} that absorbs {{as|{{rd|200|150}}|magic damage}} {{as|(+ {{rd|150%|{{fd|112.5}}%}} \'\'\'bonus\'\'\' AD)}} {{as|magic damage}} for 3 seconds. Additionally, triggering this effect grants you {{as|10% omnivamp}} until the end of [[combat status|combat]].".into(),range: None,cooldown: Some("90".into()),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: None,attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: None,omnivamp: Some(StatDetails {flat: 0f64,percent: 30f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),tenacity: None})}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 60f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: Some(StatDetails {flat: 40f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),mana: None,mana_regen: None,movespeed: None,ability_haste: Some(StatDetails {flat: 15f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3100u64}),purchasable: true}}),("3143".into(),ItemDataMeraki {name: "Randuin\'s Omen".into(),id: 3143u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3143_tank_t3_randuinsomen.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Resilience".into()),effects: "Reduces incoming damage from {{tip|critical strike|critical strikes}} by 30%.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![Active {unique: true,name: Some("Humility".into()),effects: "Unleash a shockwave around you that {{tip|slow|slows}} nearby enemies by 70% for 2 seconds.".into(),range: Some(0u64),cooldown: None}].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: Some(StatDetails {flat: 75f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),armor_penetration: None,attack_damage: None,attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,gold_per10: None,heal_and_shield_power: None,health: Some(StatDetails {flat: 350f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: None,omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 2700u64}),purchasable: true}}),("6698".into(),ItemDataMeraki {name: "Profane Hydra".into(),id: 6698u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/6698_profanehydra.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Cleave".into()),effects: "Damaging basic attacks deal {{as|{{rd|40% AD|20% AD}}|ad}} {{as|physical damage}} to other enemies in a {{tip|cr|icononly=true}} 350 radius centered around the target.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![Active {unique: true,name: Some("Heretical Cleave".into()),effects: "Deal {{as|80% AD}} {{as|physical damage}} to enemies in a {{tip|cr|icononly = true}} 450 radius.".into(),range: None,cooldown: None}].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 60f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: Some(StatDetails {flat: 18f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: Some(StatDetails {flat: 10f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3200u64}),purchasable: true}}),("3153".into(),ItemDataMeraki {name: "Blade of the Ruined King".into(),id: 3153u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3153_fighter_t3_bladeoftheruinedking.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Mist\'s Edge".into()),effects: "Basic attacks deal {{as|\'\'\'bonus\'\'\' physical damage}} [[on-hit]] equal to {{as|{{rd|8%|5%}} of the target\'s \'\'\'current\'\'\' health}}, with a \'\'\'maximum\'\'\' of 100 against {{tip|minions}} and {{tip|monsters}}.".into(),range: None,cooldown: None,stats: None},Passive {unique: true,mythic: false,name: Some("Clawing Shadows".into()),effects: "Basic attacks on-hit against enemy champions apply a stack for 6 seconds, stacking up to 3 times. The third stack consumes them all to {{tip|slow}} the target by 30% for 1 second.".into(),range: None,cooldown: Some("15".into()),stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 40f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: Some(StatDetails {flat: 25f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),cooldown_reduction: None,critical_strike_chance: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: Some(StatDetails {flat: 0f64,percent: 10f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: None,omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3200u64}),purchasable: true}}),("3036".into(),ItemDataMeraki {name: "Lord Dominik\'s Regards".into(),id: 3036u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3036_marksman_t3_dominikregards.png".into(),passives: vec![].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: Some(StatDetails {flat: 0f64,percent: 40f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_damage: Some(StatDetails {flat: 35f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: Some(StatDetails {flat: 0f64,percent: 25f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: None,omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3100u64}),purchasable: true}}),("6631".into(),ItemDataMeraki {name: "Stridebreaker".into(),id: 6631u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/6631_fighter_t4_stridebreaker.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Cleave".into()),effects: "Basic attacks [[on-hit]] deal {{as|{{rd|40% AD|20% AD}}|ad}} {{as|physical damage}} to other enemies in a {{tip|cr|icononly = true}} 350 radius centered around the target.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![Active {unique: true,name: Some("Breaking Shockwave".into()),effects: "Deal {{as|80% AD}} {{as|physical damage}} to enemies in a {{tip|cr|icononly = true}} 450 radius centered around you and {{tip|slow}} them by 35% for 3 seconds. For each champion hit, gain {{as|35% \'\'\'bonus\'\'\' movement speed}} decaying over 3 seconds. Can move while casting.".into(),range: None,cooldown: None}].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 40f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: Some(StatDetails {flat: 25f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),cooldown_reduction: None,critical_strike_chance: None,gold_per10: None,heal_and_shield_power: None,health: Some(StatDetails {flat: 450f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: None,omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3300u64}),purchasable: true}}),("3074".into(),ItemDataMeraki {name: "Ravenous Hydra".into(),id: 3074u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3074_fighter_t3_ravenoushydra.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Cleave".into()),effects: "Basic attacks [[on-hit]] deal {{as|{{rd|40% AD|20% AD}}|ad}} {{as|physical damage}} to other enemies in a {{tip|cr|icononly = true}} 350 radius centered around the target.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![Active {unique: true,name: Some("Ravenous Crescent".into()),effects: "Deal {{as|80% AD}} {{as|physical damage}} to enemies within a {{tip|cr|icononly = true}} 450 radius in front of you.".into(),range: None,cooldown: None}].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 65f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: Some(StatDetails {flat: 0f64,percent: 12f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: Some(StatDetails {flat: 15f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3300u64}),purchasable: true}}),("6610".into(),ItemDataMeraki {name: "Sundered Sky".into(),id: 6610u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/6610_sunderedsky.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Lightshield Strike".into()),effects: "Your next basic attack against a champion is empowered to {{tip|critical strike|critically strike}} for {{tt|175% damage|Affected by critical damage modifiers}} and {{tip|heal}} you for {{as|{{rd|100%|50%}} \'\'\'base\'\'\' AD}} {{as|(+ 6% of your \'\'\'missing\'\'\' health)}} (8 second cooldown per target). Excess healing beyond {{as|\'\'\'maximum\'\'\' health}} is converted to {{as|\'\'\'bonus\'\'\' health}} for 8 seconds.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 40f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,gold_per10: None,heal_and_shield_power: None,health: Some(StatDetails {flat: 400f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: Some(StatDetails {flat: 10f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3100u64}),purchasable: true}}),("6701".into(),ItemDataMeraki {name: "Opportunity".into(),id: 6701u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/6701_opportunity.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Preparation".into()),effects: "After being [[Combat status|out-of-combat]] with enemy champions for 8 seconds, gain {{as|{{rd|11|7}} lethality}}. This bonus remains for 3 seconds after dealing damage to an enemy champion.".into(),range: None,cooldown: None,stats: None},Passive {unique: true,mythic: false,name: Some("Extraction".into()),effects: "Scoring a {{tip|takedown}} against an enemy champion within 3 seconds of damaging them grants you {{as|200 \'\'\'bonus\'\'\' movement speed}} that decays over {{fd|1.5}} seconds.".into(),range: None,cooldown: None,stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: None,attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: Some(StatDetails {flat: 200f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),ability_haste: None,omnivamp: None,tenacity: None})}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 55f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: Some(StatDetails {flat: 15f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: None,omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 2700u64}),purchasable: true}}),("6692".into(),ItemDataMeraki {name: "Eclipse".into(),id: 6692u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/6692_assassin_t4_eclipse.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Ever Rising Moon".into()),effects: "Damaging [[basic attack]]s, [[champion ability|abilities]], [[Named item effect|item effects]], and [[summoner spell]]s, as well as the application of {{tip|crowd control}} and [[damage over time]] effects, generate [[stack]]s against enemy champions, up to one per attack or cast per champion. Applying 2 stacks to a champion within a 2 second period deals {{as|\'\'\'bonus\'\'\' physical damage}} to them equal to {{as|{{rd|6%|4%}} of target\'s \'\'\'maximum\'\'\' health}} and grants you a {{tip|shield}} for {{rd|160|80}} {{as|(+ {{rd|40%|20%}} \'\'\'bonus\'\'\' AD)}} for 2 seconds.".into(),range: None,cooldown: Some("6".into()),stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 60f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: Some(StatDetails {flat: 15f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 2900u64}),purchasable: true}}),("6694".into(),ItemDataMeraki {name: "Serylda\'s Grudge".into(),id: 6694u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/6694_assasin_t3_seryldasgrudge.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Bitter Cold".into()),effects: "Dealing [[ability damage]] to an enemy that is at or below {{as|50% of their \'\'\'maximum\'\'\' health}} {{tip|slow|slows}} them by 30% for 1 second.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: Some(StatDetails {flat: 0f64,percent: 35f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_damage: Some(StatDetails {flat: 45f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: Some(StatDetails {flat: 15f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3000u64}),purchasable: true}}),("3179".into(),ItemDataMeraki {name: "Umbral Glaive".into(),id: 3179u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3179_assassin_t3_umbralglaive.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Blackout".into()),effects: "When near an enemy [[Stealth#Stealthed_traps_and_wards|stealthed]] {{tip|ward}} or {{tip|stealthed trap|trap}}, gain \'\'Blackout\'\' for 8 seconds.".into(),range: Some(400u64),cooldown: Some("50".into()),stats: None},Passive {unique: false,mythic: false,name: Some("Blackout".into()),effects: "You {{tip|disabled ward|disable}} surrounding stealthed wards, as well as {{tip|expose}} and {{tip|true sight|reveal}} nearby stealthed wards and traps while \'\'Blackout\'\' is active.".into(),range: None,cooldown: None,stats: None},Passive {unique: true,mythic: false,name: Some("Extinguish".into()),effects: "Your [[basic attack]]s deal {{as|{{rd|2|1}} \'\'\'bonus\'\'\' true damage}} to wards.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 50f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: Some(StatDetails {flat: 15f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: Some(StatDetails {flat: 10f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 2600u64}),purchasable: true}}),("3006".into(),ItemDataMeraki {name: "Berserker\'s Greaves".into(),id: 3006u64,tier: 2u64,rank: vec!["BOOTS".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3006_class_t2_berserkersgreaves.png".into(),passives: vec![].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: None,attack_speed: Some(StatDetails {flat: 25f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),cooldown_reduction: None,critical_strike_chance: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: Some(StatDetails {flat: 45f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),ability_haste: None,omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 1100u64}),purchasable: true}}),("3158".into(),ItemDataMeraki {name: "Ionian Boots of Lucidity".into(),id: 3158u64,tier: 2u64,rank: vec!["BOOTS".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3158_class_t2_ionianbootsoflucidity.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Ionian Insight".into()),effects: "Gain 10 [[Haste#Summoner spell haste|summoner spell haste]].".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: None,attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: Some(StatDetails {flat: 45f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),ability_haste: Some(StatDetails {flat: 10f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 900u64}),purchasable: true}})].into_iter().collect()
\ No newline at end of file
+vec![("3026".into(),ItemDataMeraki {name: "Guardian Angel".into(),id: 3026u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3026_fighter_t3_guardianangel.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Rebirth".into()),effects: "Upon taking [[death|lethal damage]], enter {{tip|resurrection}} for 4 seconds, during which you are {{tip|invulnerable}}, {{tip|untargetable}}, and unable to act, and afterwards {{tip|heal}} for {{as|50% of \'\'\'base\'\'\' health}} and restore {{as|100% of \'\'\'maximum\'\'\' mana}} (300 second cooldown, starts after resurrection ends).".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: Some(StatDetails {flat: 45f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),armor_penetration: None,attack_damage: Some(StatDetails {flat: 55f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: None,omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3200u64}),purchasable: true}}),("3814".into(),ItemDataMeraki {name: "Edge of Night".into(),id: 3814u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3814_assassin_t3_edgeofnight.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Annul".into()),effects: "Grants a {{tip|spell shield}} that blocks the next hostile ability (40 second cooldown, timer restarts upon taking damage from champions).".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 50f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: Some(StatDetails {flat: 250f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),health_regen: None,lethality: Some(StatDetails {flat: 15f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: None,omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3000u64}),purchasable: true}}),("3033".into(),ItemDataMeraki {name: "Mortal Reminder".into(),id: 3033u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3033_marksman_t3_mortalreminder.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Grievous Wounds".into()),effects: "Dealing {{as|physical damage}} to enemy champions inflicts them with {{tip|Grievous Wounds}} for 3 seconds.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: Some(StatDetails {flat: 0f64,percent: 35f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_damage: Some(StatDetails {flat: 35f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: Some(StatDetails {flat: 0f64,percent: 25f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: None,omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3300u64}),purchasable: true}}),("6697".into(),ItemDataMeraki {name: "Hubris".into(),id: 6697u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/6697_hubris.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Eminence".into()),effects: "Scoring a {{tip|takedown}} against an enemy champion within 3 seconds of damaging them generates a permanent stack and grants you {{as|15|ad}} (+ 2 per stack) {{as|\'\'\'bonus\'\'\' attack damage}} for 90 seconds.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 60f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: Some(StatDetails {flat: 18f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: Some(StatDetails {flat: 10f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3000u64}),purchasable: true}}),("3161".into(),ItemDataMeraki {name: "Spear of Shojin".into(),id: 3161u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3161_fighter_t3_spearofshojin.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Dragonforce".into()),effects: "Gain 25 [[Haste#Basic ability haste|basic ability haste]].".into(),range: None,cooldown: None,stats: None},Passive {unique: true,mythic: false,name: Some("Focused Will".into()),effects: "Dealing [[ability damage]] with a non-innate [[champion ability|ability]] grants a stack for 6 seconds, stacking up to 4 times and up to once per {{tip|cast instance}} per second and every second for [[damage over time]] abilities. For each stack, your non-{{tip|basic damage}} dealt by non-[[item|items]] gain 3% increased damage, for a total increase of 12% at \'\'\'maximum\'\'\' stacks.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 45f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: Some(StatDetails {flat: 450f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: None,omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3100u64}),purchasable: true}}),("6695".into(),ItemDataMeraki {name: "Serpent\'s Fang".into(),id: 6695u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/6695_assassin_t3_serpentsfang.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Shield Reaver".into()),effects: "Dealing damage to an enemy champion inflicts them with venom for 3 seconds, reducing any {{tip|shield|shields}} they gain within the duration by {{rd|50%|35%}}, and if the target was not already afflicted by the venom, reducing all of their active shields by the same amount.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 55f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: Some(StatDetails {flat: 15f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: None,omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 2500u64}),purchasable: true}}),("6696".into(),ItemDataMeraki {name: "Axiom Arc".into(),id: 6696u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/6696_axiomarc.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Flux".into()),effects: "Scoring a {{tip|takedown}} against an enemy champion within 3 seconds of damaging them refunds 15% {{as|(+ {{fd|0.15}}% per 1 Lethality)}} of your ultimate ability\'s \'\'\'total\'\'\' cooldown.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 55f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: Some(StatDetails {flat: 18f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: Some(StatDetails {flat: 20f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3000u64}),purchasable: true}}),("6609".into(),ItemDataMeraki {name: "Chempunk Chainsword".into(),id: 6609u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/6609_fighter_t3_chempunkchainsword.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Hackshorn".into()),effects: "Dealing {{as|physical damage}} to enemy champions inflicts them with {{tip|Grievous Wounds}} for 3 seconds.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 45f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: Some(StatDetails {flat: 450f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: Some(StatDetails {flat: 15f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3100u64}),purchasable: true}}),("3072".into(),ItemDataMeraki {name: "Bloodthirster".into(),id: 3072u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3072_fighter_t3_bloodthirster.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Ichorshield".into()),effects: "Convert the {{tip|healing}} received from {{sti|life steal}} in excess of {{as|\'\'\'maximum\'\'\' health}} into a {{tip|shield}} for up to {{pp|165 to 315 for 11|1;9 to 18|formula=165 base, then +15 per level starting from level 9.}}, which lasts until destroyed.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 80f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: Some(StatDetails {flat: 0f64,percent: 15f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: None,omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3400u64}),purchasable: true}}),("6333".into(),ItemDataMeraki {name: "Death\'s Dance".into(),id: 6333u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/6333_fighter_t3_deathsdance.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Ignore Pain".into()),effects: "Reduces {{rd|30%|10%}} of all {{tt|post-mitigation|Damage calculated after modifiers}} {{as|physical|physical damage}} and {{as|magic|magic damage}} damage received and instead stores the damage to successively take it as {{as|true damage}} over 3 seconds, dealing a third of the stored damage each second.".into(),range: None,cooldown: None,stats: None},Passive {unique: true,mythic: false,name: Some("Defy".into()),effects: "If an enemy champion dies within 3 seconds of you damaging them, removes \'\'Ignore Pain\'s\'\' remaining stored damage and {{tip|heals}} you for {{as|75% \'\'\'bonus\'\'\' AD}} over 2 seconds.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: Some(StatDetails {flat: 50f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),armor_penetration: None,attack_damage: Some(StatDetails {flat: 60f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: Some(StatDetails {flat: 15f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3300u64}),purchasable: true}}),("3110".into(),ItemDataMeraki {name: "Frozen Heart".into(),id: 3110u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3110_tank_t3_frozenheart.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Winter\'s Caress".into()),effects: "{{tip|Cripple|Cripples}} the [[attack speed]] of enemy champions within {{tt|700 units|center to edge}} by 20%.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: Some(StatDetails {flat: 75f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),armor_penetration: None,attack_damage: None,attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: Some(StatDetails {flat: 400f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),mana_regen: None,movespeed: None,ability_haste: Some(StatDetails {flat: 20f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 2500u64}),purchasable: true}}),("6699".into(),ItemDataMeraki {name: "Voltaic Cyclosword".into(),id: 6699u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/6699_voltaiccyclosword.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Energized".into()),effects: "Moving and basic attacking generates \'\'Energize\'\' stacks, up to 100.".into(),range: None,cooldown: None,stats: None},Passive {unique: true,mythic: false,name: Some("Galvanize".into()),effects: "{{tip|Energized}} stack generation is increased by 75% for distance travelled from {{tip|dash|dashes}}, {{tip|lunge|lunges}} and {{tip|blink|blinks}}, as well as while moving in {{tip|stealth}}.".into(),range: None,cooldown: None,stats: None},Passive {unique: true,mythic: false,name: Some("Firmament".into()),effects: "When fully {{tip|Energized}}, your next basic attack deals {{as|100 \'\'\'bonus\'\'\' physical damage}} [[on-hit]] and {{tip|slow|slows}} the target by {{rd|99%|20%}} for {{fd|0.75}} seconds.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 55f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: Some(StatDetails {flat: 18f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: Some(StatDetails {flat: 10f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3000u64}),purchasable: true}}),("3142".into(),ItemDataMeraki {name: "Youmuu\'s Ghostblade".into(),id: 3142u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3142_assassin_t3_youmuusghostblade.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Haunt".into()),effects: "Gain {{as|{{rd|20|10}} \'\'\'bonus\'\'\' movement speed}} while out-of-combat with enemy champions for 3 seconds.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![Active {unique: true,name: Some("Wraith Step".into()),effects: "Gain {{as|{{rd|20%|15%}} \'\'\'bonus\'\'\' movement speed}} and {{tip|ghosted|ghosting}} for {{rd|6|4}} seconds.".into(),range: None,cooldown: None}].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 55f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: Some(StatDetails {flat: 18f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: Some(StatDetails {flat: 0f64,percent: 4f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),ability_haste: None,omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 2800u64}),purchasable: true}}),("6676".into(),ItemDataMeraki {name: "The Collector".into(),id: 6676u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/6676_marksman_t3_thecollector.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Death".into()),effects: "If you deal {{tt|post-mitigation|Damage calculated after modifiers}} damage that would leave a champion below {{as|5% of their \'\'\'maximum\'\'\' health}}, {{tip|execute}} them.".into(),range: None,cooldown: None,stats: None},Passive {unique: true,mythic: false,name: Some("Taxes".into()),effects: "Killing a champion grants you an additional {{g|25}}.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 50f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: Some(StatDetails {flat: 0f64,percent: 25f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: Some(StatDetails {flat: 10f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: None,omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3000u64}),purchasable: true}}),("3071".into(),ItemDataMeraki {name: "Black Cleaver".into(),id: 3071u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3071_fighter_t3_blackcleaver.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Carve".into()),effects: "Dealing {{as|physical damage}} to an enemy champion applies a stack of \'\'Carve\'\' for 6 seconds, stacking up to 5 times. Each stack inflicts {{as|6% armor reduction}}, up to {{as|30%|armor}} at 5 stacks.".into(),range: None,cooldown: None,stats: None},Passive {unique: true,mythic: false,name: Some("Fervor".into()),effects: "Dealing {{as|physical damage}} grants you {{as|20 \'\'\'bonus\'\'\' movement speed}} for 2 seconds.".into(),range: None,cooldown: None,stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: None,attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: Some(StatDetails {flat: 20f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),ability_haste: None,omnivamp: None,tenacity: None})}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 40f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: Some(StatDetails {flat: 400f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: Some(StatDetails {flat: 20f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3000u64}),purchasable: true}}),("3156".into(),ItemDataMeraki {name: "Maw of Malmortius".into(),id: 3156u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3156_fighter_t3_mawofmalmortius.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Lifeline".into()),effects: "If you would take {{as|magic damage}} that would reduce you below {{as|30% of your \'\'\'maximum\'\'\' health}}, you first gain a {{tip|shield}} that absorbs {{as|{{rd|200|150}}|magic damage}} {{as|(+ {{rd|150%|{{fd|112.5}}%}} \'\'\'bonus\'\'\' AD)}} {{as|magic damage}} for 3 seconds. Additionally, triggering this effect grants you {{as|10% omnivamp}} until the end of [[combat status|combat]].".into(),range: None,cooldown: Some("90".into()),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: None,attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: None,omnivamp: Some(StatDetails {flat: 0f64,percent: 30f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),tenacity: None})}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 60f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: Some(StatDetails {flat: 40f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),mana: None,mana_regen: None,movespeed: None,ability_haste: Some(StatDetails {flat: 15f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3100u64}),purchasable: true}}),("3143".into(),ItemDataMeraki {name: "Randuin\'s Omen".into(),id: 3143u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3143_tank_t3_randuinsomen.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Resilience".into()),effects: "Reduces incoming damage from {{tip|critical strike|critical strikes}} by 30%.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![Active {unique: true,name: Some("Humility".into()),effects: "Unleash a shockwave around you that {{tip|slow|slows}} nearby enemies by 70% for 2 seconds.".into(),range: Some(0u64),cooldown: None}].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: Some(StatDetails {flat: 75f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),armor_penetration: None,attack_damage: None,attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: Some(StatDetails {flat: 350f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: None,omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 2700u64}),purchasable: true}}),("6698".into(),ItemDataMeraki {name: "Profane Hydra".into(),id: 6698u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/6698_profanehydra.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Cleave".into()),effects: "Damaging basic attacks deal {{as|{{rd|40% AD|20% AD}}|ad}} {{as|physical damage}} to other enemies in a {{tip|cr|icononly=true}} 350 radius centered around the target.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![Active {unique: true,name: Some("Heretical Cleave".into()),effects: "Deal {{as|80% AD}} {{as|physical damage}} to enemies in a {{tip|cr|icononly = true}} 450 radius.".into(),range: None,cooldown: None}].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 60f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: Some(StatDetails {flat: 18f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: Some(StatDetails {flat: 10f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3200u64}),purchasable: true}}),("3153".into(),ItemDataMeraki {name: "Blade of the Ruined King".into(),id: 3153u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3153_fighter_t3_bladeoftheruinedking.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Mist\'s Edge".into()),effects: "Basic attacks deal {{as|\'\'\'bonus\'\'\' physical damage}} [[on-hit]] equal to {{as|{{rd|8%|5%}} of the target\'s \'\'\'current\'\'\' health}}, with a \'\'\'maximum\'\'\' of 100 against {{tip|minions}} and {{tip|monsters}}.".into(),range: None,cooldown: None,stats: None},Passive {unique: true,mythic: false,name: Some("Clawing Shadows".into()),effects: "Basic attacks on-hit against enemy champions apply a stack for 6 seconds, stacking up to 3 times. The third stack consumes them all to {{tip|slow}} the target by 30% for 1 second.".into(),range: None,cooldown: Some("15".into()),stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 40f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: Some(StatDetails {flat: 25f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),cooldown_reduction: None,critical_strike_chance: None,critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: Some(StatDetails {flat: 0f64,percent: 10f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: None,omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3200u64}),purchasable: true}}),("3036".into(),ItemDataMeraki {name: "Lord Dominik\'s Regards".into(),id: 3036u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3036_marksman_t3_dominikregards.png".into(),passives: vec![].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: Some(StatDetails {flat: 0f64,percent: 40f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_damage: Some(StatDetails {flat: 35f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: Some(StatDetails {flat: 0f64,percent: 25f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: None,omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3100u64}),purchasable: true}}),("6631".into(),ItemDataMeraki {name: "Stridebreaker".into(),id: 6631u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/6631_fighter_t4_stridebreaker.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Cleave".into()),effects: "Basic attacks [[on-hit]] deal {{as|{{rd|40% AD|20% AD}}|ad}} {{as|physical damage}} to other enemies in a {{tip|cr|icononly = true}} 350 radius centered around the target.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![Active {unique: true,name: Some("Breaking Shockwave".into()),effects: "Deal {{as|80% AD}} {{as|physical damage}} to enemies in a {{tip|cr|icononly = true}} 450 radius centered around you and {{tip|slow}} them by 35% for 3 seconds. For each champion hit, gain {{as|35% \'\'\'bonus\'\'\' movement speed}} decaying over 3 seconds. Can move while casting.".into(),range: None,cooldown: None}].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 40f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: Some(StatDetails {flat: 25f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),cooldown_reduction: None,critical_strike_chance: None,critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: Some(StatDetails {flat: 450f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: None,omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3300u64}),purchasable: true}}),("3074".into(),ItemDataMeraki {name: "Ravenous Hydra".into(),id: 3074u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3074_fighter_t3_ravenoushydra.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Cleave".into()),effects: "Basic attacks [[on-hit]] deal {{as|{{rd|40% AD|20% AD}}|ad}} {{as|physical damage}} to other enemies in a {{tip|cr|icononly = true}} 350 radius centered around the target.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![Active {unique: true,name: Some("Ravenous Crescent".into()),effects: "Deal {{as|80% AD}} {{as|physical damage}} to enemies within a {{tip|cr|icononly = true}} 450 radius in front of you.".into(),range: None,cooldown: None}].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 65f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: Some(StatDetails {flat: 0f64,percent: 12f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: Some(StatDetails {flat: 15f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3300u64}),purchasable: true}}),("6610".into(),ItemDataMeraki {name: "Sundered Sky".into(),id: 6610u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/6610_sunderedsky.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Lightshield Strike".into()),effects: "Your next basic attack against a champion is empowered to {{tip|critical strike|critically strike}} for {{tt|175% damage|Affected by critical damage modifiers}} and {{tip|heal}} you for {{as|{{rd|100%|50%}} \'\'\'base\'\'\' AD}} {{as|(+ 6% of your \'\'\'missing\'\'\' health)}} (8 second cooldown per target). Excess healing beyond {{as|\'\'\'maximum\'\'\' health}} is converted to {{as|\'\'\'bonus\'\'\' health}} for 8 seconds.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 40f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: Some(StatDetails {flat: 400f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: Some(StatDetails {flat: 10f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3100u64}),purchasable: true}}),("6701".into(),ItemDataMeraki {name: "Opportunity".into(),id: 6701u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/6701_opportunity.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Preparation".into()),effects: "After being [[Combat status|out-of-combat]] with enemy champions for 8 seconds, gain {{as|{{rd|11|7}} lethality}}. This bonus remains for 3 seconds after dealing damage to an enemy champion.".into(),range: None,cooldown: None,stats: None},Passive {unique: true,mythic: false,name: Some("Extraction".into()),effects: "Scoring a {{tip|takedown}} against an enemy champion within 3 seconds of damaging them grants you {{as|200 \'\'\'bonus\'\'\' movement speed}} that decays over {{fd|1.5}} seconds.".into(),range: None,cooldown: None,stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: None,attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: Some(StatDetails {flat: 200f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),ability_haste: None,omnivamp: None,tenacity: None})}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 55f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: Some(StatDetails {flat: 15f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: None,omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 2700u64}),purchasable: true}}),("6692".into(),ItemDataMeraki {name: "Eclipse".into(),id: 6692u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/6692_assassin_t4_eclipse.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Ever Rising Moon".into()),effects: "Damaging [[basic attack]]s, [[champion ability|abilities]], [[Named item effect|item effects]], and [[summoner spell]]s, as well as the application of {{tip|crowd control}} and [[damage over time]] effects, generate [[stack]]s against enemy champions, up to one per attack or cast per champion. Applying 2 stacks to a champion within a 2 second period deals {{as|\'\'\'bonus\'\'\' physical damage}} to them equal to {{as|{{rd|6%|4%}} of target\'s \'\'\'maximum\'\'\' health}} and grants you a {{tip|shield}} for {{rd|160|80}} {{as|(+ {{rd|40%|20%}} \'\'\'bonus\'\'\' AD)}} for 2 seconds.".into(),range: None,cooldown: Some("6".into()),stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 60f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: Some(StatDetails {flat: 15f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 2900u64}),purchasable: true}}),("6694".into(),ItemDataMeraki {name: "Serylda\'s Grudge".into(),id: 6694u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/6694_assasin_t3_seryldasgrudge.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Bitter Cold".into()),effects: "Dealing [[ability damage]] to an enemy that is at or below {{as|50% of their \'\'\'maximum\'\'\' health}} {{tip|slow|slows}} them by 30% for 1 second.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: Some(StatDetails {flat: 0f64,percent: 35f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_damage: Some(StatDetails {flat: 45f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: Some(StatDetails {flat: 15f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3000u64}),purchasable: true}}),("3179".into(),ItemDataMeraki {name: "Umbral Glaive".into(),id: 3179u64,tier: 3u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3179_assassin_t3_umbralglaive.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Blackout".into()),effects: "When near an enemy [[Stealth#Stealthed_traps_and_wards|stealthed]] {{tip|ward}} or {{tip|stealthed trap|trap}}, gain \'\'Blackout\'\' for 8 seconds.".into(),range: Some(400u64),cooldown: Some("50".into()),stats: None},Passive {unique: false,mythic: false,name: Some("Blackout".into()),effects: "You {{tip|disabled ward|disable}} surrounding stealthed wards, as well as {{tip|expose}} and {{tip|true sight|reveal}} nearby stealthed wards and traps while \'\'Blackout\'\' is active.".into(),range: None,cooldown: None,stats: None},Passive {unique: true,mythic: false,name: Some("Extinguish".into()),effects: "Your [[basic attack]]s deal {{as|{{rd|2|1}} \'\'\'bonus\'\'\' true damage}} to wards.".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 50f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: Some(StatDetails {flat: 15f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: Some(StatDetails {flat: 10f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 2600u64}),purchasable: true}}),("3006".into(),ItemDataMeraki {name: "Berserker\'s Greaves".into(),id: 3006u64,tier: 2u64,rank: vec!["BOOTS".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3006_class_t2_berserkersgreaves.png".into(),passives: vec![].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: None,attack_speed: Some(StatDetails {flat: 25f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),cooldown_reduction: None,critical_strike_chance: None,critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: Some(StatDetails {flat: 45f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),ability_haste: None,omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 1100u64}),purchasable: true}}),("3158".into(),ItemDataMeraki {name: "Ionian Boots of Lucidity".into(),id: 3158u64,tier: 2u64,rank: vec!["BOOTS".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3158_class_t2_ionianbootsoflucidity.png".into(),passives: vec![Passive {unique: true,mythic: false,name: Some("Ionian Insight".into()),effects: "Gain 10 [[Haste#Summoner spell haste|summoner spell haste]].".into(),range: None,cooldown: None,stats: None}].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: None,attack_speed: None,cooldown_reduction: None,critical_strike_chance: None,critical_strike_damage: None,gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: Some(StatDetails {flat: 45f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),ability_haste: Some(StatDetails {flat: 10f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 900u64}),purchasable: true}}),("3031".into(),ItemDataMeraki {name: "Infinity Edge".into(),id: 3031u64,tier: 2u64,rank: vec!["LEGENDARY".into()].into_iter().collect(),removed: false,icon: "https://raw.communitydragon.org/15.6/plugins/rcp-be-lol-game-data/global/default/assets/items/icons2d/3031_marksman_t3_infinityedge.png".into(),passives: vec![].into_iter().collect(),active: vec![].into_iter().collect(),stats: Some(Stats {ability_power: None,armor: None,armor_penetration: None,attack_damage: Some(StatDetails {flat: 65f64,percent: 0f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),attack_speed: None,cooldown_reduction: None,critical_strike_chance: Some(StatDetails {flat: 0f64,percent: 25f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),critical_strike_damage: Some(StatDetails {flat: 0f64,percent: 40f64,per_level: 0f64,percent_per_level: 0f64,percent_base: 0f64,percent_bonus: 0f64}),gold_per10: None,heal_and_shield_power: None,health: None,health_regen: None,lethality: None,lifesteal: None,magic_penetration: None,magic_resistance: None,mana: None,mana_regen: None,movespeed: None,ability_haste: None,omnivamp: None,tenacity: None}),shop: Shop {prices: Some(Prices {total: 3450u64}),purchasable: true}})].into_iter().collect()
\ No newline at end of file
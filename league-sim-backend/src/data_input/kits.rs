@@ -0,0 +1,293 @@
+use shared_structs::champions as game_data;
+
+use crate::{
+    area::HitPattern,
+    attack::{AttackType, SpellCategory},
+};
+
+use super::{
+    abilities::{
+        AbilitiesExtraData, CastContext, KhazixE, KhazixR, KhazixW, ScriptedEffect, SpellData,
+        UnseenThreat,
+    },
+    common::{Champion, DamageType, Unit},
+    config::ScenarioConfig,
+};
+
+// Description of a champion's abilities. The numbers (damage per rank, ratios, cooldowns
+// and cast times) are read from the generated game data: the kit only tells where to find
+// them and adds what the game data does not hold (ranks, categories, scripted effects).
+// Adding a champion means adding its generated data and writing its kit.
+pub struct ChampionKit {
+    pub abilities: Vec<AbilityDefinition>,
+    pub extra_data: AbilitiesExtraData,
+}
+
+pub struct AbilityDefinition {
+    pub attack_type: AttackType,
+    // position of the ability in the game data, for keys holding several abilities
+    pub index: usize,
+    // None for abilities dealing no damage
+    pub damage: Option<DamageTable>,
+    // when set, the ability is one variation amongst several sharing the same key.
+    // find_ability_for_cast picks the first variation selected by the scenario config and
+    // the cast context.
+    pub selected_when: Option<fn(&ScenarioConfig, &CastContext) -> bool>,
+    // rank of the ability for each champion level (index 0 is level 1). 0 means not learned yet
+    pub rank_by_level: [u64; 18],
+    pub category: Option<SpellCategory>,
+    // secondary targets hit along with the primary target
+    pub hit_pattern: HitPattern,
+    pub active_effect: Option<&'static dyn ScriptedEffect>,
+    pub recast: Option<Recast>,
+    // config dependent tweaks, applied once the ability has been read from the game data
+    pub customize: Option<fn(&mut SpellData, &ScenarioConfig)>,
+}
+
+// position of the damage values in the game data: abilities.<key>[index].effects[effect].leveling[leveling]
+pub struct DamageTable {
+    pub effect: usize,
+    pub leveling: usize,
+    pub damage_type: DamageType,
+}
+
+pub struct Recast {
+    pub gap_duration_ms: u64,
+    pub charges: u64,
+    pub window_ms: u64,
+}
+
+// the game data of the abilities is only generated for champions having a kit.
+// None for the champions whose abilities are not supported yet.
+pub fn champion_kit(champion: Champion) -> Option<(game_data::Champion, ChampionKit)> {
+    match champion {
+        Champion::Khazix => {
+            // the generated data refers to the game data types by name
+            use game_data::*;
+            Some((include!("champions_gen/khazix.rs"), khazix_kit()))
+        }
+        #[allow(unreachable_patterns)]
+        _ => None,
+    }
+}
+
+// standard skill order: R first, then Q > W > E
+const KHAZIX_Q_RANKS: [u64; 18] = [1, 1, 1, 2, 3, 3, 4, 4, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5];
+const KHAZIX_W_RANKS: [u64; 18] = [0, 1, 1, 1, 1, 1, 1, 2, 2, 3, 3, 4, 5, 5, 5, 5, 5, 5];
+const KHAZIX_E_RANKS: [u64; 18] = [0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 3, 3, 4, 5];
+const KHAZIX_R_RANKS: [u64; 18] = [0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 3, 3, 3];
+
+const KHAZIX_W_EXPLOSION_RADIUS: f64 = 275.0;
+
+fn khazix_kit() -> ChampionKit {
+    ChampionKit {
+        abilities: vec![
+            AbilityDefinition {
+                selected_when: Some(|_, cast_context| !cast_context.isolated_target),
+                ..khazix_q(0)
+            },
+            AbilityDefinition {
+                selected_when: Some(|_, cast_context| cast_context.isolated_target),
+                customize: Some(|q, config| {
+                    khazix_evolved_q_range(q, config);
+                    // the evolved Q has a reduced cooldown against isolated targets
+                    if config.champion.khazix.q_evolved {
+                        for cooldown in q
+                            .cooldown_ms
+                            .iter_mut()
+                            .flat_map(|cooldowns| cooldowns.values_mut())
+                        {
+                            *cooldown = (*cooldown as f64 * (1.0 - 0.45)) as u64;
+                        }
+                    }
+                }),
+                ..khazix_q(1)
+            },
+            AbilityDefinition {
+                attack_type: AttackType::W,
+                index: 0,
+                damage: Some(DamageTable {
+                    effect: 0,
+                    leveling: 0,
+                    damage_type: DamageType::Physical,
+                }),
+                selected_when: None,
+                rank_by_level: KHAZIX_W_RANKS,
+                category: None,
+                // the spike explodes on the first enemy hit
+                hit_pattern: HitPattern::Circle {
+                    radius: KHAZIX_W_EXPLOSION_RADIUS,
+                    center: Unit::Target,
+                },
+                // the heal is not part of the damage table
+                active_effect: Some(&KhazixW {
+                    heal_by_rank: [55.0, 75.0, 95.0, 115.0, 135.0],
+                    ap_ratio: 0.5,
+                    explosion_radius: KHAZIX_W_EXPLOSION_RADIUS,
+                    evolved_slow_duration: 2000,
+                    evolved_slow_perc: 0.4,
+                    evolved_isolated_slow_perc: 0.6,
+                }),
+                recast: None,
+                customize: Some(|w, config| {
+                    // the three evolved spikes spread out in a cone. An enemy hit by several
+                    // spikes only takes damage once
+                    if config.champion.khazix.w_evolved {
+                        w.hit_pattern = HitPattern::Cone {
+                            range: 1025.0,
+                            angle_deg: 60.0,
+                        };
+                    }
+                }),
+            },
+            AbilityDefinition {
+                attack_type: AttackType::E,
+                index: 0,
+                damage: Some(DamageTable {
+                    effect: 0,
+                    leveling: 0,
+                    damage_type: DamageType::Physical,
+                }),
+                selected_when: None,
+                rank_by_level: KHAZIX_E_RANKS,
+                category: Some(SpellCategory::Dash),
+                // the landing area, the attacker leaps onto the target
+                hit_pattern: HitPattern::Circle {
+                    radius: 300.0,
+                    center: Unit::Target,
+                },
+                active_effect: Some(&KhazixE),
+                recast: None,
+                customize: Some(|e, config| {
+                    // the evolved E leaps further
+                    if config.champion.khazix.e_evolved {
+                        e.range = Some(900.0);
+                    }
+                }),
+            },
+            AbilityDefinition {
+                attack_type: AttackType::R,
+                index: 0,
+                damage: None,
+                selected_when: None,
+                rank_by_level: KHAZIX_R_RANKS,
+                category: Some(SpellCategory::Stealth),
+                hit_pattern: HitPattern::Single,
+                active_effect: Some(&KhazixR {
+                    base_duration: 1250,
+                    evolved_duration: 2000,
+                }),
+                recast: Some(Recast {
+                    gap_duration_ms: 2000,
+                    charges: 1,
+                    window_ms: 12_000,
+                }),
+                customize: Some(|r, config| {
+                    // the evolved R can be recast twice
+                    if config.champion.khazix.r_evolved {
+                        r.recast_charges = Some(2);
+                    }
+                }),
+            },
+        ],
+        extra_data: AbilitiesExtraData {
+            unseen_threat: UnseenThreat {
+                base_damage: 10.0,
+                per_level_bonus: 7.0,
+                bonus_ad_ratio: 0.5,
+            },
+        },
+    }
+}
+
+// the two variations of Q only differ by their damage ("Physical Damage" and
+// "Increased Damage" against isolated targets)
+fn khazix_q(leveling: usize) -> AbilityDefinition {
+    AbilityDefinition {
+        attack_type: AttackType::Q,
+        index: 0,
+        damage: Some(DamageTable {
+            effect: 1,
+            leveling,
+            damage_type: DamageType::Physical,
+        }),
+        selected_when: None,
+        rank_by_level: KHAZIX_Q_RANKS,
+        category: None,
+        hit_pattern: HitPattern::Single,
+        active_effect: None,
+        recast: None,
+        customize: Some(khazix_evolved_q_range),
+    }
+}
+
+// the evolved Q reaches further
+fn khazix_evolved_q_range(q: &mut SpellData, config: &ScenarioConfig) {
+    if config.champion.khazix.q_evolved {
+        q.range = Some(375.0);
+    }
+}
+
+#[cfg(test)]
+mod kits_tests {
+    use super::*;
+    use crate::data_input::abilities::{find_ability, find_ability_for_cast, pull_abilities_data};
+
+    #[test]
+    fn khazix_abilities_are_read_from_the_game_data() {
+        let mut config = ScenarioConfig::default();
+        let (abilities, _) = pull_abilities_data(Champion::Khazix, &config).unwrap();
+
+        let q = find_ability(&abilities, AttackType::Q, &config);
+        assert_eq!(q.variation_name.as_deref(), Some("Physical Damage"));
+        assert_eq!(q.ad_damage.get(&5), Some(&180.0));
+        assert!((q.coefficient_ad - 1.1).abs() < 1e-9);
+        assert_eq!(q.cast_time_ms, Some(250));
+        assert_eq!(q.cooldown_ms.as_ref().unwrap().get(&1), Some(&4000));
+        assert_eq!(q.range, Some(325.0));
+        assert_eq!(
+            find_ability(&abilities, AttackType::E, &config).range,
+            Some(700.0)
+        );
+
+        let r = find_ability(&abilities, AttackType::R, &config);
+        assert_eq!(r.damage_type, None);
+        assert_eq!(r.cast_time_ms, None);
+        assert_eq!(r.recast_charges, Some(1));
+        assert_eq!(r.rank_by_level[5], 1);
+
+        config.champion.khazix.q_evolved = true;
+        config.champion.khazix.w_evolved = true;
+        config.champion.khazix.e_evolved = true;
+        config.champion.khazix.r_evolved = true;
+        let (abilities, _) = pull_abilities_data(Champion::Khazix, &config).unwrap();
+
+        let q = find_ability_for_cast(
+            &abilities,
+            AttackType::Q,
+            &config,
+            &CastContext {
+                isolated_target: true,
+            },
+        );
+        assert_eq!(q.variation_name.as_deref(), Some("Increased Damage"));
+        assert_eq!(q.ad_damage.get(&5), Some(&378.0));
+        assert_eq!(q.cooldown_ms.as_ref().unwrap().get(&1), Some(&2200));
+        assert_eq!(q.range, Some(375.0));
+        // no refund when the target is not isolated
+        let q = find_ability(&abilities, AttackType::Q, &config);
+        assert_eq!(q.cooldown_ms.as_ref().unwrap().get(&1), Some(&4000));
+        assert_eq!(
+            find_ability(&abilities, AttackType::R, &config).recast_charges,
+            Some(2)
+        );
+        assert!(matches!(
+            find_ability(&abilities, AttackType::W, &config).hit_pattern,
+            HitPattern::Cone { .. }
+        ));
+        assert_eq!(
+            find_ability(&abilities, AttackType::E, &config).range,
+            Some(900.0)
+        );
+    }
+}
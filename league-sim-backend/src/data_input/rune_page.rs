@@ -0,0 +1,408 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+
+use crate::error::SimError;
+
+use super::runes::Rune;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RuneTree {
+    Precision,
+    Domination,
+    Sorcery,
+    Resolve,
+    Inspiration,
+}
+
+impl Rune {
+    // tree and row of the rune. The keystones are on row 0. None for the stat shards
+    pub fn slot(&self) -> Option<(RuneTree, usize)> {
+        use RuneTree::*;
+
+        let slot = match self {
+            Rune::PressTheAttack | Rune::LethalTempo | Rune::FleetFootwork | Rune::Conqueror => {
+                (Precision, 0)
+            }
+            Rune::AbsorbLife | Rune::Triumph | Rune::PresenceOfMind => (Precision, 1),
+            Rune::LegendAlacrity | Rune::LegendHaste | Rune::LegendBloodline => (Precision, 2),
+            Rune::CoupDeGrace | Rune::CutDown | Rune::LastStand => (Precision, 3),
+
+            Rune::Electrocute | Rune::DarkHarvest | Rune::HailOfBlades => (Domination, 0),
+            Rune::CheapShot | Rune::TasteOfBlood | Rune::SuddenImpact => (Domination, 1),
+            Rune::SixthSense | Rune::GrislyMementos | Rune::DeepWard => (Domination, 2),
+            Rune::TreasureHunter | Rune::RelentlessHunter | Rune::UltimateHunter => (Domination, 3),
+
+            Rune::SummonAery | Rune::ArcaneComet | Rune::PhaseRush => (Sorcery, 0),
+            Rune::NullifyingOrb | Rune::ManaflowBand | Rune::NimbusCloak => (Sorcery, 1),
+            Rune::Transcendence | Rune::Celerity | Rune::AbsoluteFocus => (Sorcery, 2),
+            Rune::Scorch | Rune::Waterwalking | Rune::GatheringStorm => (Sorcery, 3),
+
+            Rune::GraspOfTheUndying | Rune::Aftershock | Rune::Guardian => (Resolve, 0),
+            Rune::Demolish | Rune::FontOfLife | Rune::ShieldBash => (Resolve, 1),
+            Rune::Conditioning | Rune::SecondWind | Rune::BonePlating => (Resolve, 2),
+            Rune::Overgrowth | Rune::Revitalize | Rune::Unflinching => (Resolve, 3),
+
+            Rune::GlacialAugment | Rune::UnsealedSpellbook | Rune::FirstStrike => (Inspiration, 0),
+            Rune::HextechFlashtraption | Rune::MagicalFootwear | Rune::CashBack => (Inspiration, 1),
+            Rune::TripleTonic | Rune::TimeWarpTonic | Rune::BiscuitDelivery => (Inspiration, 2),
+            Rune::CosmicInsight | Rune::ApproachVelocity | Rune::JackOfAllTrades => {
+                (Inspiration, 3)
+            }
+
+            Rune::AdaptiveForce1
+            | Rune::AdaptiveForce2
+            | Rune::AttackSpeed
+            | Rune::AbilityHaste
+            | Rune::MoveSpeed
+            | Rune::HealthScaling1
+            | Rune::HealthScaling2
+            | Rune::Health
+            | Rune::TenacityAndSlowResist => return None,
+        };
+
+        Some(slot)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum StatShard {
+    AdaptiveForce,
+    AttackSpeed,
+    AbilityHaste,
+    MoveSpeed,
+    HealthScaling,
+    Health,
+    TenacityAndSlowResist,
+}
+
+impl StatShard {
+    // the rune granted by the shard in the given slot (0: offense, 1: flex, 2: defense).
+    // None when the shard is not available in that slot
+    fn rune(&self, slot: usize) -> Option<Rune> {
+        match (slot, self) {
+            (0, StatShard::AdaptiveForce) => Some(Rune::AdaptiveForce1),
+            (0, StatShard::AttackSpeed) => Some(Rune::AttackSpeed),
+            (0, StatShard::AbilityHaste) => Some(Rune::AbilityHaste),
+            (1, StatShard::AdaptiveForce) => Some(Rune::AdaptiveForce2),
+            (1, StatShard::MoveSpeed) => Some(Rune::MoveSpeed),
+            (1, StatShard::HealthScaling) => Some(Rune::HealthScaling1),
+            (2, StatShard::Health) => Some(Rune::Health),
+            (2, StatShard::TenacityAndSlowResist) => Some(Rune::TenacityAndSlowResist),
+            (2, StatShard::HealthScaling) => Some(Rune::HealthScaling2),
+            _ => None,
+        }
+    }
+}
+
+// a full rune page: a keystone with one rune per row of its tree, two runes from
+// different rows of a secondary tree and the three stat shards
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct RunePage {
+    pub keystone: Rune,
+    pub primary: Vec<Rune>,
+    pub secondary: Vec<Rune>,
+    pub shards: Vec<StatShard>,
+}
+
+// the page used before it could be chosen
+impl Default for RunePage {
+    fn default() -> Self {
+        RunePage {
+            keystone: Rune::DarkHarvest,
+            primary: vec![Rune::SuddenImpact, Rune::SixthSense, Rune::UltimateHunter],
+            secondary: vec![Rune::AbsoluteFocus, Rune::GatheringStorm],
+            shards: vec![
+                StatShard::AdaptiveForce,
+                StatShard::AdaptiveForce,
+                StatShard::Health,
+            ],
+        }
+    }
+}
+
+impl RunePage {
+    // validates the page against the structure of the rune trees
+    pub fn runes(&self) -> Result<HashSet<Rune>, SimError> {
+        let primary_tree = match self.keystone.slot() {
+            Some((tree, 0)) => tree,
+            _ => return Err(SimError::invalid_input("runes.keystone", "not a keystone")),
+        };
+
+        if self.primary.len() != 3 {
+            return Err(SimError::invalid_input(
+                "runes.primary",
+                "must hold exactly 3 runes",
+            ));
+        }
+        for (index, rune) in self.primary.iter().enumerate() {
+            if rune.slot() != Some((primary_tree, index + 1)) {
+                return Err(SimError::invalid_input(
+                    &format!("runes.primary[{}]", index),
+                    format!(
+                        "{:?} is not in the row {} of the {:?} tree",
+                        rune,
+                        index + 1,
+                        primary_tree
+                    ),
+                ));
+            }
+        }
+
+        if self.secondary.len() != 2 {
+            return Err(SimError::invalid_input(
+                "runes.secondary",
+                "must hold exactly 2 runes",
+            ));
+        }
+        let mut secondary_tree = None;
+        for (index, rune) in self.secondary.iter().enumerate() {
+            let path = format!("runes.secondary[{}]", index);
+            let (tree, row) = rune
+                .slot()
+                .ok_or_else(|| SimError::invalid_input(&path, "stat shards go in runes.shards"))?;
+
+            if row == 0 {
+                return Err(SimError::invalid_input(
+                    &path,
+                    "keystones cannot be secondary",
+                ));
+            }
+            if tree == primary_tree {
+                return Err(SimError::invalid_input(
+                    &path,
+                    "the secondary tree must differ from the primary tree",
+                ));
+            }
+            if secondary_tree.is_some_and(|secondary_tree| secondary_tree != tree) {
+                return Err(SimError::invalid_input(
+                    &path,
+                    "both secondary runes must come from the same tree",
+                ));
+            }
+            secondary_tree = Some(tree);
+        }
+        if !self.secondary.iter().map(|rune| rune.slot()).all_unique() {
+            return Err(SimError::invalid_input(
+                "runes.secondary",
+                "the secondary runes must come from different rows",
+            ));
+        }
+
+        if self.shards.len() != 3 {
+            return Err(SimError::invalid_input(
+                "runes.shards",
+                "must hold exactly 3 stat shards",
+            ));
+        }
+        let mut runes: HashSet<Rune> = HashSet::new();
+        for (slot, shard) in self.shards.iter().enumerate() {
+            let rune = shard.rune(slot).ok_or_else(|| {
+                SimError::invalid_input(
+                    &format!("runes.shards[{}]", slot),
+                    format!("{:?} is not available in this slot", shard),
+                )
+            })?;
+            runes.insert(rune);
+        }
+
+        runes.insert(self.keystone);
+        runes.extend(self.primary.iter().copied());
+        runes.extend(self.secondary.iter().copied());
+
+        Ok(runes)
+    }
+}
+
+// candidate runes and stat shards of the rune optimizer. The optimizer tries every legal
+// page made of them
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+pub struct RunePool {
+    // keystones and runes, of any tree
+    pub runes: Vec<Rune>,
+    // when empty, the shards of the rune page are kept
+    pub shards: Vec<StatShard>,
+}
+
+impl RunePool {
+    pub fn pages(&self, default_shards: &[StatShard]) -> Vec<RunePage> {
+        let trees = [
+            RuneTree::Precision,
+            RuneTree::Domination,
+            RuneTree::Sorcery,
+            RuneTree::Resolve,
+            RuneTree::Inspiration,
+        ];
+        let candidates = |tree: RuneTree, row: usize| {
+            self.runes
+                .iter()
+                .unique()
+                .filter(move |rune| rune.slot() == Some((tree, row)))
+                .copied()
+        };
+
+        let shard_combinations = if self.shards.is_empty() {
+            vec![default_shards.to_vec()]
+        } else {
+            (0..3)
+                .map(|slot| {
+                    self.shards
+                        .iter()
+                        .unique()
+                        .filter(|shard| shard.rune(slot).is_some())
+                        .copied()
+                        .collect_vec()
+                })
+                .multi_cartesian_product()
+                .collect_vec()
+        };
+
+        let mut pages = Vec::new();
+        for primary_tree in trees {
+            let primary_combinations = (1..=3)
+                .map(|row| candidates(primary_tree, row).collect_vec())
+                .multi_cartesian_product()
+                .collect_vec();
+            let secondary_combinations = trees
+                .iter()
+                .filter(|&&tree| tree != primary_tree)
+                .flat_map(|&tree| {
+                    (1..=3)
+                        .flat_map(move |row| candidates(tree, row))
+                        .tuple_combinations()
+                        .filter(|(a, b)| a.slot() != b.slot())
+                        .map(|(a, b)| vec![a, b])
+                })
+                .collect_vec();
+
+            for keystone in candidates(primary_tree, 0) {
+                for primary in primary_combinations.iter() {
+                    for secondary in secondary_combinations.iter() {
+                        for shards in shard_combinations.iter() {
+                            pages.push(RunePage {
+                                keystone,
+                                primary: primary.clone(),
+                                secondary: secondary.clone(),
+                                shards: shards.clone(),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        pages
+    }
+}
+
+#[cfg(test)]
+mod rune_page_tests {
+    use super::*;
+
+    fn page(keystone: Rune, primary: Vec<Rune>, secondary: Vec<Rune>) -> RunePage {
+        RunePage {
+            keystone,
+            primary,
+            secondary,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn default_page_is_valid() {
+        let runes = RunePage::default().runes().unwrap();
+
+        assert_eq!(runes.len(), 9);
+        assert!(runes.contains(&Rune::DarkHarvest));
+        assert!(runes.contains(&Rune::AdaptiveForce1));
+        assert!(runes.contains(&Rune::AdaptiveForce2));
+    }
+
+    #[test]
+    fn pool_pages_are_legal() {
+        let pool = RunePool {
+            runes: vec![
+                Rune::DarkHarvest,
+                Rune::Electrocute,
+                Rune::SuddenImpact,
+                Rune::SixthSense,
+                Rune::UltimateHunter,
+                Rune::AbsoluteFocus,
+                Rune::GatheringStorm,
+                Rune::Scorch,
+            ],
+            shards: vec![],
+        };
+
+        let pages = pool.pages(&RunePage::default().shards);
+
+        // 2 keystones, with 2 secondary pairs (Scorch and Gathering Storm share a row)
+        assert_eq!(pages.len(), 4);
+        assert!(pages.contains(&RunePage::default()));
+        assert!(pages.iter().all(|page| page.runes().is_ok()));
+    }
+
+    #[test]
+    fn rejects_pages_not_matching_the_trees() {
+        let path_of = |page: RunePage| match page.runes() {
+            Err(SimError::InvalidInput { path, .. }) => path,
+            result => panic!("unexpected result {:?}", result),
+        };
+
+        // not a keystone
+        assert_eq!(
+            path_of(page(Rune::SuddenImpact, vec![], vec![])),
+            "runes.keystone"
+        );
+        // rows out of order
+        assert_eq!(
+            path_of(page(
+                Rune::Electrocute,
+                vec![Rune::SixthSense, Rune::SuddenImpact, Rune::UltimateHunter],
+                vec![Rune::AbsoluteFocus, Rune::GatheringStorm],
+            )),
+            "runes.primary[0]"
+        );
+        // secondary runes from the primary tree
+        assert_eq!(
+            path_of(page(
+                Rune::Electrocute,
+                vec![Rune::SuddenImpact, Rune::SixthSense, Rune::UltimateHunter],
+                vec![Rune::TasteOfBlood, Rune::GatheringStorm],
+            )),
+            "runes.secondary[0]"
+        );
+        // secondary runes from two trees
+        assert_eq!(
+            path_of(page(
+                Rune::Electrocute,
+                vec![Rune::SuddenImpact, Rune::SixthSense, Rune::UltimateHunter],
+                vec![Rune::AbsoluteFocus, Rune::CoupDeGrace],
+            )),
+            "runes.secondary[1]"
+        );
+        // secondary runes from the same row
+        assert_eq!(
+            path_of(page(
+                Rune::Electrocute,
+                vec![Rune::SuddenImpact, Rune::SixthSense, Rune::UltimateHunter],
+                vec![Rune::Scorch, Rune::GatheringStorm],
+            )),
+            "runes.secondary"
+        );
+        // attack speed is an offense shard only
+        assert_eq!(
+            path_of(RunePage {
+                shards: vec![
+                    StatShard::AdaptiveForce,
+                    StatShard::AttackSpeed,
+                    StatShard::Health
+                ],
+                ..Default::default()
+            }),
+            "runes.shards[1]"
+        );
+    }
+}
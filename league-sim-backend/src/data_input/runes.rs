@@ -1,22 +1,100 @@
 use super::{
     champions::AdaptiveType,
-    common::{self, AttackerStats, Aura, Champion, DamageType, GameParams, PassiveEffect},
+    common::{
+        self, attacker_health_perc, AttackerStats, Aura, DamageType, GameParams, PassiveEffect,
+        Unit,
+    },
 };
 use crate::{
     attack::AttackType,
     simulation::{self, DamageInfo, DamageSource, State},
 };
 
-#[derive(Clone, PartialEq, Eq, Hash, Debug, serde::Serialize)]
+// every rune of the rune trees (see rune_page.rs for their position in the trees),
+// followed by the stat shards. Most of them have no effect in the simulation.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, serde::Serialize, serde::Deserialize)]
 pub enum Rune {
+    // Precision
+    PressTheAttack,
+    LethalTempo,
+    FleetFootwork,
+    Conqueror,
+    AbsorbLife,
+    Triumph,
+    PresenceOfMind,
+    LegendAlacrity,
+    LegendHaste,
+    LegendBloodline,
+    CoupDeGrace,
+    CutDown,
+    LastStand,
+
+    // Domination
+    Electrocute,
     DarkHarvest,
+    HailOfBlades,
+    CheapShot,
+    TasteOfBlood,
     SuddenImpact,
+    SixthSense,
+    GrislyMementos,
+    DeepWard,
+    TreasureHunter,
+    RelentlessHunter,
+    UltimateHunter,
+
+    // Sorcery
+    SummonAery,
+    ArcaneComet,
+    PhaseRush,
+    NullifyingOrb,
+    ManaflowBand,
+    NimbusCloak,
+    Transcendence,
+    Celerity,
     AbsoluteFocus,
+    Scorch,
+    Waterwalking,
     GatheringStorm,
+
+    // Resolve
+    GraspOfTheUndying,
+    Aftershock,
+    Guardian,
+    Demolish,
+    FontOfLife,
+    ShieldBash,
+    Conditioning,
+    SecondWind,
+    BonePlating,
+    Overgrowth,
+    Revitalize,
+    Unflinching,
+
+    // Inspiration
+    GlacialAugment,
+    UnsealedSpellbook,
+    FirstStrike,
+    HextechFlashtraption,
+    MagicalFootwear,
+    CashBack,
+    TripleTonic,
+    TimeWarpTonic,
+    BiscuitDelivery,
+    CosmicInsight,
+    ApproachVelocity,
+    JackOfAllTrades,
+
+    // Stat shards. The number tells the slot, for the shards available in two slots
     AdaptiveForce1,
     AdaptiveForce2,
     AttackSpeed,
     AbilityHaste,
+    MoveSpeed,
+    HealthScaling1,
+    HealthScaling2,
+    Health,
+    TenacityAndSlowResist,
 }
 
 impl Rune {
@@ -59,6 +137,20 @@ impl Rune {
                 ability_haste: 8.0,
                 ..Default::default()
             }),
+            Rune::MoveSpeed => Some(AttackerStats {
+                movement_speed_perc_bonus: 0.02,
+                ..Default::default()
+            }),
+            _ => None,
+        }
+    }
+
+    // health granted by the stat shards, see https://wiki.leagueoflegends.com/en-us/Rune#Stat_shards
+    pub fn bonus_health(&self, level: u64) -> f64 {
+        match self {
+            Rune::Health => 65.0,
+            Rune::HealthScaling1 | Rune::HealthScaling2 => scale_with_level(10.0, 180.0, level),
+            _ => 0.0,
         }
     }
 
@@ -66,13 +158,12 @@ impl Rune {
         match self {
             Rune::DarkHarvest => Some(PassiveEffect::DarkHarvest),
             Rune::SuddenImpact => Some(PassiveEffect::SuddenImpact),
+            Rune::Electrocute => Some(PassiveEffect::Electrocute),
+            Rune::HailOfBlades => Some(PassiveEffect::HailOfBlades),
+            Rune::FirstStrike => Some(PassiveEffect::FirstStrike),
+            Rune::Conqueror => Some(PassiveEffect::Conqueror),
 
-            Rune::AbsoluteFocus => None,
-            Rune::GatheringStorm => None,
-            Rune::AdaptiveForce1 => None,
-            Rune::AdaptiveForce2 => None,
-            Rune::AttackSpeed => None,
-            Rune::AbilityHaste => None,
+            _ => None,
         }
     }
 
@@ -86,34 +177,58 @@ impl Rune {
         events: &mut std::collections::BinaryHeap<crate::simulation::Event>,
     ) {
         match self {
-            Rune::DarkHarvest => {
-                game_params.runes_data.dark_harvest.handle_on_pre_damage(
-                    damage_info,
-                    attacker_stats,
-                    state,
-                    game_params,
-                    event,
-                    events,
-                );
-            }
-
+            Rune::DarkHarvest => game_params.runes_data.dark_harvest.handle_on_pre_damage(
+                damage_info,
+                attacker_stats,
+                state,
+                game_params,
+                event,
+                events,
+            ),
+            Rune::HailOfBlades => game_params.runes_data.hail_of_blades.handle_on_pre_damage(
+                damage_info,
+                state,
+                game_params,
+                event,
+                events,
+            ),
             _ => (),
         }
     }
 
-    // pub fn handle_on_post_damage(
-    //     &self,
-    //     damage_info: &DamageInfo,
-    //     attacker_stats: &AttackerStats,
-    //     state: &mut State<'_>,
-    //     game_params: &GameParams<'_>,
-    //     event: &crate::simulation::Event,
-    //     events: &mut std::collections::BinaryHeap<crate::simulation::Event>,
-    // ) {
-    //     match self {
-    //         _ => (),
-    //     }
-    // }
+    pub fn handle_on_post_damage(
+        &self,
+        damage_info: &DamageInfo,
+        attacker_stats: &AttackerStats,
+        state: &mut State<'_>,
+        game_params: &GameParams<'_>,
+        event: &crate::simulation::Event,
+        events: &mut std::collections::BinaryHeap<crate::simulation::Event>,
+    ) {
+        match self {
+            Rune::Electrocute => game_params.runes_data.electrocute.handle_on_post_damage(
+                damage_info,
+                attacker_stats,
+                state,
+                game_params,
+                event,
+                events,
+            ),
+            Rune::FirstStrike => game_params.runes_data.first_strike.handle_on_post_damage(
+                damage_info,
+                state,
+                event,
+                events,
+            ),
+            Rune::Conqueror => game_params.runes_data.conqueror.handle_on_post_damage(
+                damage_info,
+                state,
+                game_params,
+                events,
+            ),
+            _ => (),
+        }
+    }
 
     pub(crate) fn handle_dash_event(
         &self,
@@ -122,17 +237,13 @@ impl Rune {
         state: &mut State<'_>,
         game_params: &GameParams<'_>,
     ) {
-        match self {
-            Rune::SuddenImpact => {
-                game_params.runes_data.sudden_impact.handle_dash_event(
-                    event,
-                    events,
-                    state,
-                    game_params,
-                );
-            }
-
-            _ => (),
+        if self == &Rune::SuddenImpact {
+            game_params.runes_data.sudden_impact.handle_dash_event(
+                event,
+                events,
+                state,
+                game_params,
+            );
         }
     }
 
@@ -143,20 +254,16 @@ impl Rune {
         state: &mut State<'_>,
         game_params: &GameParams<'_>,
     ) {
-        match self {
-            Rune::SuddenImpact => {
-                game_params
-                    .runes_data
-                    .sudden_impact
-                    .handle_stealth_exit_event(event, events, state, game_params);
-            }
-
-            _ => (),
+        if self == &Rune::SuddenImpact {
+            game_params
+                .runes_data
+                .sudden_impact
+                .handle_stealth_exit_event(event, events, state, game_params);
         }
     }
 }
 
-struct DarkHarvest {
+pub struct DarkHarvest {
     hp_perc_threshold: f64,
     base_damage: f64,
     damage_per_soul: f64,
@@ -203,17 +310,7 @@ impl DarkHarvest {
             .insert(PassiveEffect::DarkHarvest, event.time_ms + self.cooldown);
 
         // fetch the current of stacks
-        let stack_count = state
-            .config
-            .get("RUNE_DARK_HARVEST_STACKS")
-            .unwrap_or(
-                game_params
-                    .initial_config
-                    .get("RUNE_DARK_HARVEST_STACKS")
-                    .unwrap_or(&"0".to_string()),
-            )
-            .parse::<u64>()
-            .unwrap();
+        let stack_count = state.config.runes.dark_harvest.stacks;
 
         // trigger the damage
         let unmitigated_damage = self.base_damage
@@ -221,10 +318,7 @@ impl DarkHarvest {
             + self.bonus_ad * attacker_stats.ad_bonus
             + self.bonus_ap * attacker_stats.ability_power;
 
-        let damage_type = match game_params.champion_data.adaptive_type {
-            AdaptiveType::Physical => DamageType::Physical,
-            AdaptiveType::Magic => DamageType::Magical,
-        };
+        let damage_type = adaptive_damage_type(game_params);
 
         let attacker_stats = common::compute_attacker_stats(game_params, state);
         let target_stats = common::compute_target_stats(game_params, state);
@@ -239,10 +333,274 @@ impl DarkHarvest {
         simulation::on_damage_from_rune(&mitigated_damage, damage_type, state, Rune::DarkHarvest);
 
         // set new stacks value
-        state.config.insert(
-            "RUNE_DARK_HARVEST_STACKS".to_string(),
-            (stack_count + 1).to_string(),
+        state.config.runes.dark_harvest.stacks = stack_count + 1;
+    }
+}
+
+fn adaptive_damage_type(game_params: &GameParams<'_>) -> DamageType {
+    match game_params.champion_data.adaptive_type {
+        AdaptiveType::Physical => DamageType::Physical,
+        AdaptiveType::Magic => DamageType::Magical,
+    }
+}
+
+// linear scaling from level 1 to level 18
+fn scale_with_level(min: f64, max: f64, level: u64) -> f64 {
+    min + (max - min) / 17.0 * (level as f64 - 1.0)
+}
+
+// only the attacks, the abilities and the item actives hitting the target count
+// towards the keystones. Each damage instance counts as a separate hit.
+fn is_hit(damage_info: &DamageInfo) -> bool {
+    damage_info.source == DamageSource::Ability || damage_info.source == DamageSource::ItemActive
+}
+
+pub struct Electrocute {
+    hits_required: u64,
+    window: u64,
+    min_damage: f64,
+    max_damage: f64,
+    bonus_ad: f64,
+    bonus_ap: f64,
+    min_cooldown: u64,
+    max_cooldown: u64,
+}
+
+impl Electrocute {
+    fn handle_on_post_damage(
+        &self,
+        damage_info: &DamageInfo,
+        attacker_stats: &AttackerStats,
+        state: &mut State<'_>,
+        game_params: &GameParams<'_>,
+        event: &crate::simulation::Event,
+        events: &mut std::collections::BinaryHeap<crate::simulation::Event>,
+    ) {
+        if !is_hit(damage_info)
+            || state
+                .effects_cooldowns
+                .contains_key(&PassiveEffect::Electrocute)
+        {
+            return;
+        }
+
+        // the window starts with the first hit, the following hits do not extend it
+        let hits = if let Some(aura_app) = state.target_auras.get_mut(&Aura::Electrocute) {
+            aura_app.stacks = Some(aura_app.stacks.unwrap() + 1);
+            aura_app.stacks.unwrap()
+        } else {
+            state.add_target_aura(Aura::Electrocute, Some(self.window), Some(1), events);
+            1
+        };
+
+        if hits < self.hits_required {
+            return;
+        }
+
+        state.end_early_target_aura(&Aura::Electrocute, game_params, event, events);
+
+        simulation::insert_passive_triggered_event(
+            events,
+            event.time_ms,
+            PassiveEffect::Electrocute,
+        );
+
+        // the cooldown goes down with the level
+        let cooldown = self.max_cooldown
+            - (self.max_cooldown - self.min_cooldown) * (game_params.level - 1) / 17;
+        state
+            .effects_cooldowns
+            .insert(PassiveEffect::Electrocute, event.time_ms + cooldown);
+
+        let unmitigated_damage =
+            scale_with_level(self.min_damage, self.max_damage, game_params.level)
+                + self.bonus_ad * attacker_stats.ad_bonus
+                + self.bonus_ap * attacker_stats.ability_power;
+
+        let damage_type = adaptive_damage_type(game_params);
+        let target_stats = common::compute_target_stats(game_params, state);
+        let mitigated_damage = crate::attack::compute_mitigated_damage(
+            attacker_stats,
+            &target_stats,
+            unmitigated_damage,
+            damage_type,
+        );
+
+        simulation::on_damage_from_rune(&mitigated_damage, damage_type, state, Rune::Electrocute);
+    }
+}
+
+pub struct HailOfBlades {
+    empowered_attacks: u64,
+    duration: u64,
+    attack_speed_melee: f64,
+    attack_speed_ranged: f64,
+    cooldown: u64,
+}
+
+impl HailOfBlades {
+    // the attack triggering the rune is the first of the empowered attacks. The aura holds
+    // the number of empowered attacks left, which get the bonus attack speed.
+    fn handle_on_pre_damage(
+        &self,
+        damage_info: &DamageInfo,
+        state: &mut State<'_>,
+        game_params: &GameParams<'_>,
+        event: &crate::simulation::Event,
+        events: &mut std::collections::BinaryHeap<crate::simulation::Event>,
+    ) {
+        if damage_info.source_ability != Some(AttackType::AA) {
+            return;
+        }
+
+        if let Some(aura_app) = state.attacker_auras.get_mut(&Aura::HailOfBlades) {
+            let attacks_left = aura_app.stacks.unwrap() - 1;
+            if attacks_left == 0 {
+                state.end_early_attacker_aura(&Aura::HailOfBlades, game_params, event, events);
+            } else {
+                aura_app.stacks = Some(attacks_left);
+            }
+            return;
+        }
+
+        if state
+            .effects_cooldowns
+            .contains_key(&PassiveEffect::HailOfBlades)
+        {
+            return;
+        }
+
+        simulation::insert_passive_triggered_event(
+            events,
+            event.time_ms,
+            PassiveEffect::HailOfBlades,
+        );
+
+        state
+            .effects_cooldowns
+            .insert(PassiveEffect::HailOfBlades, event.time_ms + self.cooldown);
+
+        state.add_attacker_aura(
+            Aura::HailOfBlades,
+            Some(self.duration),
+            Some(self.empowered_attacks - 1),
+            events,
+        );
+    }
+
+    pub fn offensive_stats(&self, game_params: &GameParams<'_>) -> AttackerStats {
+        let attack_speed_bonus = match game_params.champion_data.attack_type {
+            super::champions::AttackType::Melee => self.attack_speed_melee,
+            super::champions::AttackType::Ranged => self.attack_speed_ranged,
+        };
+
+        AttackerStats {
+            attack_speed_bonus,
+            ..Default::default()
+        }
+    }
+}
+
+pub struct FirstStrike {
+    duration: u64,
+    bonus_damage: f64,
+    cooldown: u64,
+}
+
+impl FirstStrike {
+    // the window only opens when the attacker initiates the combat, i.e. lands its hit
+    // before the target (which only fights back in a duel) has damaged it. The hit opening
+    // the window is amplified as well.
+    fn handle_on_post_damage(
+        &self,
+        damage_info: &DamageInfo,
+        state: &mut State<'_>,
+        event: &crate::simulation::Event,
+        events: &mut std::collections::BinaryHeap<crate::simulation::Event>,
+    ) {
+        if !state.attacker_auras.contains_key(&Aura::FirstStrike) {
+            if !is_hit(damage_info)
+                || state
+                    .effects_cooldowns
+                    .contains_key(&PassiveEffect::FirstStrike)
+                || state
+                    .damage_history
+                    .iter()
+                    .any(|damage_info| damage_info.source_unit == Unit::Target)
+            {
+                return;
+            }
+
+            simulation::insert_passive_triggered_event(
+                events,
+                event.time_ms,
+                PassiveEffect::FirstStrike,
+            );
+
+            state
+                .effects_cooldowns
+                .insert(PassiveEffect::FirstStrike, event.time_ms + self.cooldown);
+
+            state.add_attacker_aura(Aura::FirstStrike, Some(self.duration), None, events);
+        }
+
+        // the bonus damage is dealt as true damage
+        let true_damage = damage_info.amount * self.bonus_damage;
+        simulation::on_damage_from_rune(&true_damage, DamageType::True, state, Rune::FirstStrike);
+    }
+}
+
+pub struct Conqueror {
+    max_stacks: u64,
+    stacks_melee: u64,
+    stacks_ranged: u64,
+    duration: u64,
+    min_adaptive_force_per_stack: f64,
+    max_adaptive_force_per_stack: f64,
+}
+
+impl Conqueror {
+    // the healing at max stacks is not simulated
+    fn handle_on_post_damage(
+        &self,
+        damage_info: &DamageInfo,
+        state: &mut State<'_>,
+        game_params: &GameParams<'_>,
+        events: &mut std::collections::BinaryHeap<crate::simulation::Event>,
+    ) {
+        if !is_hit(damage_info) {
+            return;
+        }
+
+        let stacks_gained = match game_params.champion_data.attack_type {
+            super::champions::AttackType::Melee => self.stacks_melee,
+            super::champions::AttackType::Ranged => self.stacks_ranged,
+        };
+        let stacks = state
+            .attacker_auras
+            .get(&Aura::Conqueror)
+            .map_or(0, |aura_app| aura_app.stacks.unwrap())
+            + stacks_gained;
+
+        state.add_attacker_aura(
+            Aura::Conqueror,
+            Some(self.duration),
+            Some(stacks.min(self.max_stacks)),
+            events,
+        );
+    }
+
+    pub fn offensive_stats(&self, stacks: u64, game_params: &GameParams<'_>) -> AttackerStats {
+        let adaptive_force_per_stack = scale_with_level(
+            self.min_adaptive_force_per_stack,
+            self.max_adaptive_force_per_stack,
+            game_params.level,
         );
+
+        AttackerStats {
+            adaptive_force: adaptive_force_per_stack * stacks as f64,
+            ..Default::default()
+        }
     }
 }
 
@@ -259,7 +617,7 @@ impl SuddenImpact {
         event: &simulation::Event,
         events: &mut std::collections::BinaryHeap<simulation::Event>,
         state: &mut State<'_>,
-        game_params: &GameParams<'_>,
+        _game_params: &GameParams<'_>,
     ) {
         // check if it is in cooldown
         if state
@@ -332,44 +690,49 @@ impl SuddenImpact {
     }
 }
 
-struct AbsoluteFocus {
+pub struct AbsoluteFocus {
     hp_perc_threshold: f64,
     min_damage: f64,
     max_damage: f64,
 }
 
 impl AbsoluteFocus {
-    fn offensive_stats(&self, _state: &State<'_>, game_params: &GameParams<'_>) -> AttackerStats {
-        let adaptive_force: f64 = if game_params.attacker_hp_perc > self.hp_perc_threshold {
-            self.min_damage
-                + (self.max_damage - self.min_damage) / 17.0 * (game_params.level as f64 - 1.0)
-        } else {
-            0.0
-        };
-
-        return AttackerStats {
+    fn offensive_stats(&self, state: &State<'_>, game_params: &GameParams<'_>) -> AttackerStats {
+        let adaptive_force: f64 =
+            if attacker_health_perc(state, game_params) > self.hp_perc_threshold {
+                self.min_damage
+                    + (self.max_damage - self.min_damage) / 17.0 * (game_params.level as f64 - 1.0)
+            } else {
+                0.0
+            };
+
+        AttackerStats {
             adaptive_force,
             ..Default::default()
-        };
+        }
     }
 }
 
-struct GatheringStorm {
+pub struct GatheringStorm {
     coefficient: f64,
 }
 
 impl GatheringStorm {
     fn offensive_stats(&self, _state: &State<'_>, game_params: &GameParams<'_>) -> AttackerStats {
         let x: u64 = 1 + game_params.start_time_ms / 600_000;
-        return AttackerStats {
+        AttackerStats {
             adaptive_force: self.coefficient * ((x * (x - 1)) as f64),
             ..Default::default()
-        };
+        }
     }
 }
 
 pub struct RunesData {
     pub dark_harvest: DarkHarvest,
+    pub electrocute: Electrocute,
+    pub hail_of_blades: HailOfBlades,
+    pub first_strike: FirstStrike,
+    pub conqueror: Conqueror,
     pub sudden_impact: SuddenImpact,
     pub absolute_focus: AbsoluteFocus,
     pub gathering_storm: GatheringStorm,
@@ -405,6 +768,42 @@ pub fn pull_runes() -> RunesData {
         cooldown: 35000,
     };
 
+    // the values of the following keystones come from
+    // https://wiki.leagueoflegends.com/en-us/Rune_(League_of_Legends)
+    let electrocute = Electrocute {
+        hits_required: 3,
+        window: 3_000,
+        min_damage: 30.0,
+        max_damage: 220.0,
+        bonus_ad: 0.1,
+        bonus_ap: 0.05,
+        min_cooldown: 20_000,
+        max_cooldown: 25_000,
+    };
+
+    let hail_of_blades = HailOfBlades {
+        empowered_attacks: 3,
+        duration: 3_000,
+        attack_speed_melee: 1.6,
+        attack_speed_ranged: 0.8,
+        cooldown: 12_000,
+    };
+
+    let first_strike = FirstStrike {
+        duration: 3_000,
+        bonus_damage: 0.07,
+        cooldown: 25_000,
+    };
+
+    let conqueror = Conqueror {
+        max_stacks: 12,
+        stacks_melee: 2,
+        stacks_ranged: 1,
+        duration: 5_000,
+        min_adaptive_force_per_stack: 1.8,
+        max_adaptive_force_per_stack: 4.0,
+    };
+
     let sudden_impact = SuddenImpact {
         min_damage: 20.0,
         max_damage: 80.0,
@@ -420,12 +819,16 @@ pub fn pull_runes() -> RunesData {
 
     let gathering_storm = GatheringStorm { coefficient: 4.0 };
 
-    return RunesData {
+    RunesData {
         dark_harvest,
+        electrocute,
+        hail_of_blades,
+        first_strike,
+        conqueror,
         sudden_impact,
         absolute_focus,
         gathering_storm,
-    };
+    }
 }
 
 pub fn collect_runes_stats(state: &State, game_params: &GameParams) -> AttackerStats {
@@ -434,11 +837,10 @@ pub fn collect_runes_stats(state: &State, game_params: &GameParams) -> AttackerS
     };
 
     for selected_rune in game_params.runes.iter() {
-        let rune_stats = selected_rune.offensive_stats(state, game_params);
-        if rune_stats.is_some() {
-            offensive_stats += rune_stats.unwrap();
+        if let Some(rune_stats) = selected_rune.offensive_stats(state, game_params) {
+            offensive_stats += rune_stats;
         }
     }
 
-    return offensive_stats;
+    offensive_stats
 }
@@ -0,0 +1,142 @@
+use crate::{attack::AttackType, error::SimError};
+
+use super::abilities::SpellData;
+
+const RANKED_ABILITIES: [AttackType; 4] =
+    [AttackType::Q, AttackType::W, AttackType::E, AttackType::R];
+
+// levels at which each rank of R can be learned
+const R_RANK_LEVELS: [u64; 3] = [6, 11, 16];
+const MAX_BASIC_RANK: u64 = 5;
+
+// replaces the ranks of the kit of the champion with the ones of the skill order (the ability
+// leveled up at each level, starting with level 1)
+pub fn apply_skill_order(
+    abilities: &mut [SpellData],
+    skill_order: &[AttackType],
+    level: u64,
+) -> Result<(), SimError> {
+    let ranks_by_level = ranks_by_level(skill_order)?;
+
+    if (skill_order.len() as u64) < level {
+        return Err(SimError::invalid_input(
+            "champion.skillOrder",
+            format!(
+                "must hold one ability per level up to the level of the champion ({})",
+                level
+            ),
+        ));
+    }
+
+    for ability in abilities.iter_mut() {
+        if let Some(index) = RANKED_ABILITIES
+            .iter()
+            .position(|&attack_type| attack_type == ability.attack_type)
+        {
+            ability.rank_by_level = ranks_by_level[index];
+        }
+    }
+
+    Ok(())
+}
+
+// ranks of Q, W, E and R (in that order) for each level. Past the end of the skill order,
+// the ranks do not change anymore
+fn ranks_by_level(skill_order: &[AttackType]) -> Result<[[u64; 18]; 4], SimError> {
+    if skill_order.len() > 18 {
+        return Err(SimError::invalid_input(
+            "champion.skillOrder",
+            "holds more than 18 levels",
+        ));
+    }
+
+    let mut ranks = [0; 4];
+    let mut ranks_by_level = [[0; 18]; 4];
+    for level in 1..=18 {
+        if let Some(&attack_type) = skill_order.get(level as usize - 1) {
+            let path = format!("champion.skillOrder[{}]", level - 1);
+            let index = RANKED_ABILITIES
+                .iter()
+                .position(|&ranked| ranked == attack_type)
+                .ok_or_else(|| {
+                    SimError::invalid_input(&path, "only Q, W, E and R can be leveled up")
+                })?;
+
+            ranks[index] += 1;
+            let rank = ranks[index];
+            if attack_type == AttackType::R {
+                if rank as usize > R_RANK_LEVELS.len() || level < R_RANK_LEVELS[rank as usize - 1] {
+                    return Err(SimError::invalid_input(
+                        &path,
+                        format!("R can only be leveled up at levels {:?}", R_RANK_LEVELS),
+                    ));
+                }
+            } else if rank > MAX_BASIC_RANK || level < 2 * rank - 1 {
+                // a basic ability cannot have more than half of the levels (rounded up)
+                return Err(SimError::invalid_input(
+                    &path,
+                    format!("{} cannot be rank {} at level {}", attack_type, rank, level),
+                ));
+            }
+        }
+
+        for (index, rank) in ranks.iter().enumerate() {
+            ranks_by_level[index][level as usize - 1] = *rank;
+        }
+    }
+
+    Ok(ranks_by_level)
+}
+
+#[cfg(test)]
+mod skill_order_tests {
+    use super::*;
+    use crate::data_input::{
+        abilities::{find_ability, pull_abilities_data},
+        common::Champion,
+        config::ScenarioConfig,
+    };
+    use AttackType::*;
+
+    #[test]
+    fn default_skill_order_matches_the_kit() {
+        let config = ScenarioConfig::default();
+        let (mut abilities, _) = pull_abilities_data(Champion::Khazix, &config).unwrap();
+        let kit_ranks = RANKED_ABILITIES
+            .map(|attack_type| find_ability(&abilities, attack_type, &config).rank_by_level);
+
+        let skill_order = [Q, W, E, Q, Q, R, Q, W, Q, W, R, W, W, E, E, R, E, E];
+        apply_skill_order(&mut abilities, &skill_order, 18).unwrap();
+
+        assert_eq!(
+            RANKED_ABILITIES
+                .map(|attack_type| find_ability(&abilities, attack_type, &config).rank_by_level),
+            kit_ranks
+        );
+    }
+
+    #[test]
+    fn rejects_skill_orders_breaking_the_level_gates() {
+        let path_of = |skill_order: &[AttackType], level: u64| {
+            let config = ScenarioConfig::default();
+            let (mut abilities, _) = pull_abilities_data(Champion::Khazix, &config).unwrap();
+            match apply_skill_order(&mut abilities, skill_order, level) {
+                Err(SimError::InvalidInput { path, .. }) => path,
+                result => panic!("unexpected result {:?}", result),
+            }
+        };
+
+        // R before level 6
+        assert_eq!(path_of(&[Q, W, R], 3), "champion.skillOrder[2]");
+        // rank 2 of R before level 11
+        assert_eq!(
+            path_of(&[Q, W, E, Q, Q, R, Q, W, Q, R], 10),
+            "champion.skillOrder[9]"
+        );
+        // rank 2 of Q at level 2
+        assert_eq!(path_of(&[Q, Q], 2), "champion.skillOrder[1]");
+        assert_eq!(path_of(&[Q, AA], 2), "champion.skillOrder[1]");
+        // too short for the level of the champion
+        assert_eq!(path_of(&[Q, W, E], 6), "champion.skillOrder");
+    }
+}
@@ -0,0 +1,159 @@
+use std::collections::{BinaryHeap, HashMap};
+
+use crate::{
+    area::HitPattern,
+    attack::{AttackType, SpellCategory},
+    simulation::{self, Event, State},
+};
+
+use super::{
+    abilities::{ScriptedEffect, SpellCharges, SpellData},
+    common::{AttackerStats, Aura, DamageType, GameParams},
+};
+
+// The summoner spells are not part of the game data we pull, their values come from
+// https://wiki.leagueoflegends.com/en-us/Summoner_spell
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum SummonerSpell {
+    Ignite,
+    Smite,
+    Flash,
+}
+
+impl SummonerSpell {
+    pub fn attack_type(&self) -> AttackType {
+        match self {
+            SummonerSpell::Ignite => AttackType::Ignite,
+            SummonerSpell::Smite => AttackType::Smite,
+            SummonerSpell::Flash => AttackType::Flash,
+        }
+    }
+}
+
+pub struct Ignite {
+    base_damage: f64,
+    damage_per_level: f64,
+    duration: u64,
+    tick_interval: u64,
+}
+
+pub const IGNITE: Ignite = Ignite {
+    base_damage: 50.0,
+    damage_per_level: 20.0,
+    duration: 5_000,
+    tick_interval: 1_000,
+};
+
+impl ScriptedEffect for Ignite {
+    fn on_effect(
+        &self,
+        _attacker_stats: &AttackerStats,
+        state: &mut State<'_>,
+        _game_params: &GameParams<'_>,
+        _event: &Event,
+        events: &mut BinaryHeap<Event>,
+    ) {
+        state.add_target_aura(Aura::Ignite, Some(self.duration), None, events);
+        // the target does not heal in the simulation, so grievous wounds has no effect yet
+        state.add_target_aura(Aura::GrievousWounds, Some(self.duration), None, events);
+
+        for tick in 1..=self.duration / self.tick_interval {
+            simulation::insert_ignite_tick_event(events, state.time_ms + tick * self.tick_interval);
+        }
+    }
+}
+
+impl Ignite {
+    // the total damage is split evenly between the ticks
+    pub fn on_tick(&self, state: &mut State<'_>, game_params: &GameParams<'_>) {
+        let total_damage = self.base_damage + self.damage_per_level * game_params.level as f64;
+        let tick_damage = total_damage * self.tick_interval as f64 / self.duration as f64;
+
+        simulation::on_damage_from_ability(
+            &tick_damage,
+            DamageType::True,
+            state,
+            AttackType::Ignite,
+        );
+    }
+}
+
+// true damage of Unleashed Smite against champions, from 20 at level 1 to 160 at level 18
+fn unleashed_smite_damage(level: u64) -> f64 {
+    20.0 + 140.0 * (level - 1) as f64 / 17.0
+}
+
+// the summoner spells share the SpellData of abilities, so that the simulation casts them
+// like any other command
+pub fn pull_summoner_spells() -> Vec<SpellData> {
+    let mut smite = summoner_spell(
+        AttackType::Smite,
+        15_000,
+        Some(500.0),
+        Some(unleashed_smite_damage),
+        None,
+        None,
+    );
+    smite.charges = Some(SpellCharges {
+        count: 2,
+        recharge_ms: 90_000,
+    });
+
+    vec![
+        summoner_spell(
+            AttackType::Ignite,
+            180_000,
+            Some(600.0),
+            None,
+            None,
+            Some(&IGNITE),
+        ),
+        smite,
+        // Flash blinks the attacker towards its target (see area::on_dash)
+        summoner_spell(
+            AttackType::Flash,
+            300_000,
+            None,
+            None,
+            Some(SpellCategory::Dash),
+            None,
+        ),
+    ]
+}
+
+// the damage of summoner spells is keyed by the level of the attacker
+fn summoner_spell(
+    attack_type: AttackType,
+    cooldown_ms: u64,
+    range: Option<f64>,
+    true_damage: Option<fn(u64) -> f64>,
+    category: Option<SpellCategory>,
+    active_effect: Option<&'static dyn ScriptedEffect>,
+) -> SpellData {
+    SpellData {
+        key: attack_type.to_string(),
+        attack_type,
+        coefficient_ad: 0.0,
+        coefficient_ap: 0.0,
+        ad_damage: true_damage
+            .map(|damage| (1..=18).map(|level| (level, damage(level))).collect())
+            .unwrap_or_default(),
+        ap_damage: HashMap::new(),
+        variation_name: None,
+        selected_when: None,
+        // summoner spells have a single rank, available from level 1
+        rank_by_level: [1; 18],
+        cast_time_ms: None,
+        range,
+        cooldown_ms: Some(HashMap::from([(1, cooldown_ms)])),
+        cost: None,
+        category,
+        damage_type: true_damage.map(|_| DamageType::True),
+        hit_pattern: HitPattern::Single,
+        active_effect,
+        recast_gap_duration: None,
+        recast_charges: None,
+        recast_window: None,
+        charges: None,
+    }
+}
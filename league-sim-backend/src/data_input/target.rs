@@ -0,0 +1,142 @@
+use crate::error::SimError;
+
+use super::{
+    champions::stat_increase,
+    champions_gen::get_base_champion_stats,
+    common::{Champion, DefensiveStats, TargetStats},
+    items::pull_items_data,
+};
+
+// Stats of a target champion at the given level, holding the given items.
+// Base stats grow with the level, the items only give bonus stats.
+pub fn compute_champion_target_stats(
+    champion: Champion,
+    level: u64,
+    item_ids: &[u64],
+    health_percentage: f64,
+) -> Result<TargetStats, SimError> {
+    if !(1..=18).contains(&level) {
+        return Err(SimError::invalid_input(
+            "target.level",
+            format!("level must be between 1 and 18, got {}", level),
+        ));
+    }
+
+    let (_, champion_stats) = get_base_champion_stats(champion);
+    let items_map = pull_items_data(item_ids)?;
+
+    let mut item_stats = DefensiveStats::default();
+    for item_id in item_ids.iter() {
+        let defensive_stats = &items_map[item_id].defensive_stats;
+        item_stats.health += defensive_stats.health;
+        item_stats.armor += defensive_stats.armor;
+        item_stats.magic_resistance += defensive_stats.magic_resistance;
+    }
+
+    let level = level as f64;
+    let max_health = champion_stats.health_flat
+        + stat_increase(champion_stats.health_per_level, level)
+        + item_stats.health;
+    let armor_base =
+        champion_stats.armor_flat + stat_increase(champion_stats.armor_per_level, level);
+    let magic_resistance_base = champion_stats.magic_resistance_flat
+        + stat_increase(champion_stats.magic_resistance_per_level, level);
+
+    Ok(TargetStats {
+        armor: armor_base + item_stats.armor,
+        armor_bonus: item_stats.armor,
+        magic_resistance: magic_resistance_base + item_stats.magic_resistance,
+        magic_resistance_bonus: item_stats.magic_resistance,
+        armor_reduction_flat: 0.0,
+        armor_reduction_perc: 0.0,
+        magic_resistance_reduction_flat: 0.0,
+        magic_resistance_reduction_perc: 0.0,
+        max_health,
+        current_health: max_health * health_percentage / 100.0,
+    })
+}
+
+// offense of a target fighting back in a duel
+#[derive(Debug, Clone, PartialEq)]
+pub struct TargetOffensiveStats {
+    pub attack_damage: f64,
+    // attacks per second
+    pub attack_speed: f64,
+    // part of the attack timer spent winding up the basic attack
+    pub windup_percent: f64,
+}
+
+// same as compute_champion_target_stats, for the offense of the target champion
+pub fn compute_champion_target_offensive_stats(
+    champion: Champion,
+    level: u64,
+    item_ids: &[u64],
+) -> Result<TargetOffensiveStats, SimError> {
+    let (_, champion_stats) = get_base_champion_stats(champion);
+    let items_map = pull_items_data(item_ids)?;
+
+    let (ad_bonus, attack_speed_bonus) = item_ids.iter().fold((0.0, 0.0), |acc, item_id| {
+        let offensive_stats = &items_map[item_id].offensive_stats;
+        (
+            acc.0 + offensive_stats.ad_bonus,
+            acc.1 + offensive_stats.attack_speed_bonus,
+        )
+    });
+
+    let level = level as f64;
+    // see https://wiki.leagueoflegends.com/en-us/Attack_speed#Generalization
+    let attack_speed_bonus =
+        stat_increase(champion_stats.attack_speed_per_level, level) + attack_speed_bonus;
+
+    Ok(TargetOffensiveStats {
+        attack_damage: champion_stats.attack_damage_flat
+            + stat_increase(champion_stats.attack_damage_per_level, level)
+            + ad_bonus,
+        attack_speed: champion_stats.attack_speed_flat
+            + attack_speed_bonus * champion_stats.attack_speed_ratio,
+        windup_percent: if champion_stats.attack_delay_offset != 0.0 {
+            0.3 + champion_stats.attack_delay_offset
+        } else {
+            champion_stats.attack_cast_time / champion_stats.attack_total_time
+        },
+    })
+}
+
+#[cfg(test)]
+mod target_tests {
+    use super::*;
+
+    #[test]
+    fn scales_base_stats_with_level_and_adds_item_stats() {
+        let level_1 = compute_champion_target_stats(Champion::Khazix, 1, &[], 100.0).unwrap();
+        assert_eq!(level_1.max_health, 643.0);
+        assert_eq!(level_1.armor, 32.0);
+        assert_eq!(level_1.armor_bonus, 0.0);
+        assert_eq!(level_1.magic_resistance, 32.0);
+
+        // Randuin's Omen: 350 health, 75 armor
+        let level_11 = compute_champion_target_stats(Champion::Khazix, 11, &[3143], 50.0).unwrap();
+        let armor_base = 32.0 + stat_increase(4.2, 11.0);
+        let max_health = 643.0 + stat_increase(99.0, 11.0) + 350.0;
+
+        assert!((level_11.armor - (armor_base + 75.0)).abs() < 1e-9);
+        assert_eq!(level_11.armor_bonus, 75.0);
+        assert!((level_11.max_health - max_health).abs() < 1e-9);
+        assert!((level_11.current_health - max_health / 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn computes_the_offense_of_the_target_champion() {
+        // Bloodthirster: 80 attack damage
+        let offense =
+            compute_champion_target_offensive_stats(Champion::Khazix, 1, &[3072]).unwrap();
+        assert_eq!(offense.attack_damage, 140.0);
+        assert_eq!(offense.attack_speed, 0.668);
+        assert!(offense.windup_percent > 0.0 && offense.windup_percent < 1.0);
+    }
+
+    #[test]
+    fn rejects_levels_out_of_range() {
+        assert!(compute_champion_target_stats(Champion::Khazix, 19, &[], 100.0).is_err());
+    }
+}
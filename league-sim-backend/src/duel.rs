@@ -0,0 +1,404 @@
+use std::collections::HashMap;
+
+use crate::{
+    attack::AttackType,
+    data_input::{
+        common::{attacker_max_health, DamageType, GameParams, Unit},
+        target::TargetOffensiveStats,
+    },
+    error::SimError,
+    rotation::CommandPolicy,
+    simulation::{self, DamageInfo, Event, HealInfo},
+};
+
+// The target fights back: on top of the attacker executing its commands, the target
+// executes its own commands against the attacker, until one of the two dies.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DuelSettings {
+    // derived from the level and the items of the target when it is a champion.
+    // Required otherwise
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attack_damage: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attack_speed: Option<f64>,
+    // priority list of the target
+    pub commands: Vec<TargetCommand>,
+    // the target does not start any new command past this point
+    pub max_duration_ms: u64,
+}
+
+impl Default for DuelSettings {
+    fn default() -> Self {
+        DuelSettings {
+            attack_damage: None,
+            attack_speed: None,
+            commands: vec![TargetCommand::AutoAttack],
+            max_duration_ms: 30_000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type")]
+pub enum TargetCommand {
+    // physical damage equal to the attack damage of the target, once per attack timer
+    AutoAttack,
+    // flat damage, before the resistances of the attacker
+    Spell {
+        name: String,
+        damage: f64,
+        #[serde(rename = "damageType")]
+        damage_type: DamageType,
+        #[serde(rename = "cooldownMs")]
+        cooldown_ms: u64,
+        #[serde(rename = "castTimeMs", default)]
+        cast_time_ms: u64,
+    },
+}
+
+// Command policy of the target. On each decision point, the first command of the list that is
+// available the soonest gets executed. The target only casts one command at a time.
+#[derive(Debug, Clone)]
+pub struct TargetPolicy {
+    offensive_stats: TargetOffensiveStats,
+    commands: Vec<TargetCommand>,
+    max_duration_ms: u64,
+    // command index -> time at which it is available again
+    cooldowns: HashMap<usize, u64>,
+    casting: Option<usize>,
+}
+
+impl TargetPolicy {
+    pub fn new(
+        offensive_stats: TargetOffensiveStats,
+        settings: &DuelSettings,
+    ) -> Result<Self, SimError> {
+        if settings.commands.is_empty() {
+            return Err(SimError::invalid_input(
+                "duel.commands",
+                "at least one command is needed",
+            ));
+        }
+        if offensive_stats.attack_speed <= 0.0
+            && settings.commands.contains(&TargetCommand::AutoAttack)
+        {
+            return Err(SimError::invalid_input(
+                "duel.attackSpeed",
+                "must be positive",
+            ));
+        }
+
+        Ok(TargetPolicy {
+            offensive_stats,
+            commands: settings.commands.clone(),
+            max_duration_ms: settings.max_duration_ms,
+            cooldowns: HashMap::new(),
+            casting: None,
+        })
+    }
+
+    // target of a plain simulation, taking the damage without fighting back
+    pub fn passive() -> Self {
+        TargetPolicy {
+            offensive_stats: TargetOffensiveStats {
+                attack_damage: 0.0,
+                attack_speed: 0.0,
+                windup_percent: 0.0,
+            },
+            commands: Vec::new(),
+            max_duration_ms: 0,
+            cooldowns: HashMap::new(),
+            casting: None,
+        }
+    }
+
+    pub fn fights_back(&self) -> bool {
+        !self.commands.is_empty()
+    }
+
+    // start time of the next command, None once the duel has lasted long enough
+    pub fn next_cast_time(&self, time_ms: u64) -> Option<u64> {
+        let start_ms = (0..self.commands.len())
+            .map(|index| self.available_at(index, time_ms))
+            .min()?;
+
+        (start_ms < self.max_duration_ms).then_some(start_ms)
+    }
+
+    // picks the command to execute and returns its cast time, None when nothing is available
+    pub fn start_cast(&mut self, time_ms: u64) -> Option<u64> {
+        let index =
+            (0..self.commands.len()).find(|&index| self.available_at(index, time_ms) <= time_ms)?;
+        self.casting = Some(index);
+
+        let cast_time_ms = match &self.commands[index] {
+            TargetCommand::AutoAttack => {
+                // the attack timer starts with the windup
+                let attack_timer_ms = self.attack_timer_ms();
+                self.cooldowns.insert(index, time_ms + attack_timer_ms);
+                (attack_timer_ms as f64 * self.offensive_stats.windup_percent).round() as u64
+            }
+            TargetCommand::Spell { cast_time_ms, .. } => *cast_time_ms,
+        };
+        Some(cast_time_ms)
+    }
+
+    // damage dealt by the command being cast, before mitigation. None when nothing is cast
+    pub fn end_cast(&mut self, time_ms: u64) -> Option<(f64, DamageType)> {
+        let index = self.casting.take()?;
+
+        let damage = match &self.commands[index] {
+            TargetCommand::AutoAttack => (self.offensive_stats.attack_damage, DamageType::Physical),
+            TargetCommand::Spell {
+                damage,
+                damage_type,
+                cooldown_ms,
+                ..
+            } => {
+                self.cooldowns.insert(index, time_ms + cooldown_ms);
+                (*damage, *damage_type)
+            }
+        };
+        Some(damage)
+    }
+
+    fn available_at(&self, index: usize, time_ms: u64) -> u64 {
+        self.cooldowns
+            .get(&index)
+            .map_or(time_ms, |end_ms| u64::max(*end_ms, time_ms))
+    }
+
+    fn attack_timer_ms(&self) -> u64 {
+        (1000.0 / self.offensive_stats.attack_speed).round() as u64
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DuelResult {
+    // unit left standing. None when both are still alive once they ran out of commands
+    pub winner: Option<Unit>,
+    // time of the death ending the duel (or of the last damage)
+    pub time_ms: u64,
+    pub attacker_health: f64,
+    pub attacker_max_health: f64,
+    pub target_health: f64,
+    pub target_max_health: f64,
+    pub damage_dealt: f64,
+    pub damage_taken: f64,
+    pub selected_commands: Vec<AttackType>,
+    // both ways, see DamageInfo::source_unit
+    pub damage_history: Vec<DamageInfo>,
+    pub heal_history: Vec<HealInfo>,
+    pub event_history: Vec<Event>,
+}
+
+pub fn run(
+    command_policy: impl CommandPolicy,
+    target_policy: &mut TargetPolicy,
+    game_params: &GameParams,
+) -> Result<DuelResult, SimError> {
+    let (
+        (damage, damage_history, heal_history, event_history, attack_history, time_ms, kill),
+        attacker_health,
+    ) = simulation::run_duel(command_policy, target_policy, game_params)?;
+
+    let damage_taken: f64 = damage_history
+        .iter()
+        .filter(|damage_info| damage_info.target_unit == Unit::Attacker)
+        .map(|damage_info| damage_info.amount)
+        .sum();
+    let last_damage_ms = damage_history
+        .iter()
+        .map(|damage_info| damage_info.time_ms)
+        .max()
+        .unwrap_or_default();
+
+    let initial_target_stats = game_params.initial_target_stats;
+    let winner = if kill {
+        Some(Unit::Attacker)
+    } else if attacker_health <= 0.0 {
+        Some(Unit::Target)
+    } else {
+        None
+    };
+
+    Ok(DuelResult {
+        winner,
+        time_ms: if kill { time_ms } else { last_damage_ms },
+        attacker_health: f64::max(attacker_health, 0.0),
+        attacker_max_health: attacker_max_health(game_params),
+        target_health: f64::max(initial_target_stats.current_health - damage, 0.0),
+        target_max_health: initial_target_stats.max_health,
+        damage_dealt: damage,
+        damage_taken,
+        selected_commands: attack_history,
+        damage_history,
+        heal_history,
+        event_history,
+    })
+}
+
+#[cfg(test)]
+mod duel_tests {
+    use super::*;
+    use crate::area::Battlefield;
+    use crate::data_input::{
+        self,
+        common::{compile_passive_effects, Champion, CritHandlingChoice, TargetStats},
+        config::ScenarioConfig,
+        runes::Rune,
+    };
+    use std::collections::{HashSet, VecDeque};
+
+    fn target_policy(commands: Vec<TargetCommand>) -> TargetPolicy {
+        TargetPolicy::new(
+            TargetOffensiveStats {
+                attack_damage: 200.0,
+                attack_speed: 1.0,
+                windup_percent: 0.25,
+            },
+            &DuelSettings {
+                commands,
+                ..Default::default()
+            },
+        )
+        .unwrap()
+    }
+
+    // level 1 Kha'Zix auto attacking a target with 200 attack damage. The windup of the target
+    // (250ms) is shorter than the one of Kha'Zix
+    fn run_auto_attack_duel(
+        attacker_hp_perc: f64,
+        target_health: f64,
+        runes: HashSet<Rune>,
+    ) -> DuelResult {
+        let config = ScenarioConfig::default();
+        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config).unwrap();
+
+        let mut game_params: GameParams<'_> = GameParams {
+            champion: Champion::Khazix,
+            champion_data: &static_data.champion_data,
+            champion_stats: &static_data.base_champion_stats,
+            level: 1,
+            items: &Vec::new(),
+            config: &config,
+            abilities: &static_data.abilities,
+            initial_target_stats: &TargetStats {
+                armor: 0.0,
+                armor_bonus: 0.0,
+                magic_resistance: 0.0,
+                magic_resistance_bonus: 0.0,
+                armor_reduction_flat: 0.0,
+                armor_reduction_perc: 0.0,
+                magic_resistance_reduction_flat: 0.0,
+                magic_resistance_reduction_perc: 0.0,
+                max_health: 1_000.0,
+                current_health: target_health,
+            },
+            runes: &runes,
+            attacker_hp_perc,
+            runes_data: &static_data.runes_data,
+            passive_effects: &mut Vec::new(),
+            crit_handling: CritHandlingChoice::Min,
+            initial_attacker_auras: &Vec::new(),
+            initial_target_auras: &Vec::new(),
+            abilities_extra_data: &static_data.abilities_extra_data,
+            start_time_ms: 0,
+            capture_event_history: false,
+            weave_auto_attacks: false,
+            battlefield: &Battlefield::default(),
+        };
+
+        compile_passive_effects(&mut game_params);
+
+        run(
+            VecDeque::from([AttackType::AA, AttackType::AA]),
+            &mut target_policy(vec![TargetCommand::AutoAttack]),
+            &game_params,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn the_target_kills_a_low_health_attacker_first() {
+        let result = run_auto_attack_duel(10.0, 1_000.0, HashSet::new());
+
+        assert_eq!(result.winner, Some(Unit::Target));
+        assert_eq!(result.time_ms, 250);
+        assert_eq!(result.attacker_health, 0.0);
+        assert_eq!(result.damage_dealt, 0.0);
+
+        // 200 physical damage against the 32 armor of Kha'Zix
+        let damage_taken = &result.damage_history[0];
+        assert_eq!(damage_taken.source_unit, Unit::Target);
+        assert_eq!(damage_taken.target_unit, Unit::Attacker);
+        assert!((damage_taken.amount - 200.0 * 100.0 / 132.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn the_attacker_wins_with_the_health_it_has_left() {
+        let result = run_auto_attack_duel(100.0, 1.0, HashSet::new());
+
+        assert_eq!(result.winner, Some(Unit::Attacker));
+        assert_eq!(result.target_health, 0.0);
+        assert!(result.time_ms > 250);
+        assert!(
+            (result.attacker_health - (result.attacker_max_health - result.damage_taken)).abs()
+                < 1e-9
+        );
+        assert!(result.damage_taken > 0.0);
+    }
+
+    #[test]
+    fn first_strike_does_not_trigger_when_the_target_strikes_first() {
+        let result = run_auto_attack_duel(100.0, 1_000.0, HashSet::from([Rune::FirstStrike]));
+
+        // the target lands its first attack before the end of the windup of Kha'Zix
+        assert_eq!(result.damage_history[0].source_unit, Unit::Target);
+        assert!(result.damage_dealt > 0.0);
+        assert!(result
+            .damage_history
+            .iter()
+            .all(|damage_info| damage_info.source_rune != Some(Rune::FirstStrike)));
+    }
+
+    #[test]
+    fn spells_take_priority_over_auto_attacks_when_available() {
+        let mut policy = target_policy(vec![
+            TargetCommand::Spell {
+                name: "Q".to_string(),
+                damage: 100.0,
+                damage_type: DamageType::Magical,
+                cooldown_ms: 5_000,
+                cast_time_ms: 200,
+            },
+            TargetCommand::AutoAttack,
+        ]);
+
+        assert_eq!(policy.next_cast_time(0), Some(0));
+        assert_eq!(policy.start_cast(0), Some(200));
+        assert_eq!(policy.end_cast(200), Some((100.0, DamageType::Magical)));
+
+        // the spell is on cooldown: auto attack right away
+        assert_eq!(policy.next_cast_time(200), Some(200));
+        assert_eq!(policy.start_cast(200), Some(250));
+        assert_eq!(policy.end_cast(450), Some((200.0, DamageType::Physical)));
+
+        // the attack timer runs out before the cooldown of the spell
+        assert_eq!(policy.next_cast_time(450), Some(1_200));
+        assert_eq!(policy.start_cast(450), None);
+        assert_eq!(policy.end_cast(450), None);
+    }
+
+    #[test]
+    fn a_passive_target_never_casts() {
+        let mut policy = TargetPolicy::passive();
+
+        assert!(!policy.fights_back());
+        assert_eq!(policy.next_cast_time(0), None);
+        assert_eq!(policy.start_cast(0), None);
+        assert_eq!(policy.end_cast(0), None);
+    }
+}
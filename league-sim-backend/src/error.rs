@@ -0,0 +1,134 @@
+use std::fmt;
+
+use crate::attack::AttackType;
+
+// Errors surfaced to the caller (frontend or native binary) instead of panicking.
+// Internal invariant violations (e.g. an aura callback running without its aura) still panic.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(tag = "kind")]
+pub enum SimError {
+    // the input could not be parsed, or holds an unsupported value. `path` points to the
+    // offending field (e.g. "game.critHandling")
+    InvalidInput {
+        path: String,
+        reason: String,
+    },
+    // the bundled game data could not be interpreted
+    InvalidGameData {
+        reason: String,
+    },
+    UnknownItem {
+        item_id: u64,
+    },
+    InvalidItemSelection {
+        reason: String,
+    },
+    // `command_index` is the position of the command in the list of executed commands
+    UnknownCommand {
+        command: String,
+        command_index: usize,
+    },
+    SpellOnCooldown {
+        command: AttackType,
+        command_index: usize,
+        time_ms: u64,
+    },
+    // the command costs more mana (or energy) than the attacker can hold
+    NotEnoughResource {
+        command: AttackType,
+        command_index: usize,
+        time_ms: u64,
+    },
+    // the target runs away faster than the attacker can chase it
+    TargetOutOfReach {
+        command: AttackType,
+        command_index: usize,
+        time_ms: u64,
+    },
+    SpellNotLearned {
+        command: AttackType,
+        command_index: usize,
+    },
+    // the active of an item which is not part of the build
+    ItemNotEquipped {
+        command: AttackType,
+        command_index: usize,
+    },
+}
+
+impl SimError {
+    pub fn invalid_input(path: &str, reason: impl Into<String>) -> Self {
+        SimError::InvalidInput {
+            path: path.to_string(),
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for SimError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SimError::InvalidInput { path, reason } => {
+                write!(f, "invalid input at '{}': {}", path, reason)
+            }
+            SimError::InvalidGameData { reason } => write!(f, "invalid game data: {}", reason),
+            SimError::UnknownItem { item_id } => write!(f, "unknown item id: {}", item_id),
+            SimError::InvalidItemSelection { reason } => {
+                write!(f, "invalid item selection: {}", reason)
+            }
+            SimError::UnknownCommand {
+                command,
+                command_index,
+            } => write!(
+                f,
+                "unknown command '{}' at index {}",
+                command, command_index
+            ),
+            SimError::SpellOnCooldown {
+                command,
+                command_index,
+                time_ms,
+            } => write!(
+                f,
+                "command {} at index {} cast while on cooldown (at {}ms)",
+                command, command_index, time_ms
+            ),
+            SimError::NotEnoughResource {
+                command,
+                command_index,
+                time_ms,
+            } => write!(
+                f,
+                "command {} at index {} cast without enough resource (at {}ms)",
+                command, command_index, time_ms
+            ),
+            SimError::TargetOutOfReach {
+                command,
+                command_index,
+                time_ms,
+            } => write!(
+                f,
+                "command {} at index {} cast on a target out of reach (at {}ms)",
+                command, command_index, time_ms
+            ),
+            SimError::SpellNotLearned {
+                command,
+                command_index,
+            } => write!(
+                f,
+                "command {} at index {} has not been learned yet",
+                command, command_index
+            ),
+            SimError::ItemNotEquipped {
+                command,
+                command_index,
+            } => write!(
+                f,
+                "command {} at index {} is the active of an item which is not equipped",
+                command, command_index
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SimError {}
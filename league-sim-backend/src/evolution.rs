@@ -0,0 +1,171 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use itertools::Itertools;
+
+use crate::{attack::AttackType, data_input::config::ScenarioConfig, error::SimError};
+
+// Kha'Zix evolves one of his abilities at each of these levels
+pub const EVOLUTION_LEVELS: [u64; 3] = [6, 11, 16];
+
+const EVOLVING_ABILITIES: [AttackType; 4] =
+    [AttackType::Q, AttackType::W, AttackType::E, AttackType::R];
+
+// (damage, time, kill) of the fight at an evolution point
+pub type FightOutcome = (f64, u64, bool);
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvolutionPoint {
+    pub level: u64,
+    pub damage: f64,
+    // kill time when the target dies
+    pub time_ms: u64,
+    pub kill: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvolutionOrderResult {
+    // ability evolved at each evolution level
+    pub order: Vec<AttackType>,
+    pub points: Vec<EvolutionPoint>,
+}
+
+impl EvolutionOrderResult {
+    fn kills(&self) -> usize {
+        self.points.iter().filter(|point| point.kill).count()
+    }
+
+    fn total_kill_time_ms(&self) -> u64 {
+        self.points
+            .iter()
+            .filter(|point| point.kill)
+            .map(|point| point.time_ms)
+            .sum()
+    }
+
+    fn total_damage(&self) -> f64 {
+        self.points.iter().map(|point| point.damage).sum()
+    }
+}
+
+// the config with exactly the given abilities evolved
+pub fn evolved_config(config: &ScenarioConfig, evolved: &[AttackType]) -> ScenarioConfig {
+    let mut config = config.clone();
+    let khazix = &mut config.champion.khazix;
+
+    khazix.q_evolved = evolved.contains(&AttackType::Q);
+    khazix.w_evolved = evolved.contains(&AttackType::W);
+    khazix.e_evolved = evolved.contains(&AttackType::E);
+    khazix.r_evolved = evolved.contains(&AttackType::R);
+
+    config
+}
+
+// Runs the fight at each evolution level, for every order in which the abilities can be
+// evolved. The orders killing the target at the most evolution points come first, then the
+// ones killing it the fastest (sum of the kill times), then the ones dealing the most damage.
+pub fn rank_evolution_orders(
+    config: &ScenarioConfig,
+    mut simulate_fight: impl FnMut(u64, &ScenarioConfig) -> Result<FightOutcome, SimError>,
+) -> Result<Vec<EvolutionOrderResult>, SimError> {
+    // the outcome at an evolution point only depends on the abilities evolved so far, not on
+    // the order in which they were evolved
+    let mut outcomes: HashMap<(u64, [bool; 4]), FightOutcome> = HashMap::new();
+    let mut results: Vec<EvolutionOrderResult> = Vec::new();
+
+    for order in EVOLVING_ABILITIES
+        .into_iter()
+        .permutations(EVOLUTION_LEVELS.len())
+    {
+        let mut points: Vec<EvolutionPoint> = Vec::new();
+
+        for (index, level) in EVOLUTION_LEVELS.into_iter().enumerate() {
+            let evolved = &order[..=index];
+            let key = (
+                level,
+                EVOLVING_ABILITIES.map(|ability| evolved.contains(&ability)),
+            );
+
+            let (damage, time_ms, kill) = match outcomes.get(&key) {
+                Some(outcome) => *outcome,
+                None => {
+                    let outcome = simulate_fight(level, &evolved_config(config, evolved))?;
+                    outcomes.insert(key, outcome);
+                    outcome
+                }
+            };
+
+            points.push(EvolutionPoint {
+                level,
+                damage,
+                time_ms,
+                kill,
+            });
+        }
+
+        results.push(EvolutionOrderResult { order, points });
+    }
+
+    results.sort_by(compare_orders);
+
+    Ok(results)
+}
+
+fn compare_orders(a: &EvolutionOrderResult, b: &EvolutionOrderResult) -> Ordering {
+    b.kills()
+        .cmp(&a.kills())
+        .then(a.total_kill_time_ms().cmp(&b.total_kill_time_ms()))
+        .then(b.total_damage().total_cmp(&a.total_damage()))
+}
+
+#[cfg(test)]
+mod evolution_tests {
+    use super::*;
+
+    #[test]
+    fn orders_are_ranked_by_kills_then_kill_time() {
+        let mut fights = 0;
+
+        let results = rank_evolution_orders(&ScenarioConfig::default(), |_, config| {
+            fights += 1;
+            let khazix = &config.champion.khazix;
+
+            // the evolved E kills, the evolved Q kills faster
+            let kill = khazix.e_evolved;
+            let time_ms = if khazix.q_evolved { 2_000 } else { 3_000 };
+            Ok((1_000.0, time_ms, kill))
+        })
+        .unwrap();
+
+        // 4 abilities evolved 3 at a time
+        assert_eq!(results.len(), 24);
+        // one fight per set of evolved abilities: 4 at level 6, 6 at level 11 and 4 at level 16
+        assert_eq!(fights, 14);
+
+        assert_eq!(
+            results[0].order,
+            vec![AttackType::E, AttackType::Q, AttackType::W]
+        );
+        assert_eq!(results[0].kills(), 3);
+        assert_eq!(results[0].points[1].level, 11);
+        assert_eq!(results[0].points[1].time_ms, 2_000);
+        // E evolved second, then last
+        assert_eq!(results[6].kills(), 2);
+        assert_eq!(results[12].kills(), 1);
+        assert!(results[12].points[2].kill);
+        // without the evolved E, no kill
+        assert_eq!(results.last().unwrap().kills(), 0);
+    }
+
+    #[test]
+    fn evolved_config_only_evolves_the_given_abilities() {
+        let mut config = ScenarioConfig::default();
+        config.champion.khazix.r_evolved = true;
+
+        let config = evolved_config(&config, &[AttackType::W]);
+
+        assert!(config.champion.khazix.w_evolved);
+        assert!(!config.champion.khazix.r_evolved);
+    }
+}
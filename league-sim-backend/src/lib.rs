@@ -1,96 +1,311 @@
 mod utils;
 
-use utils::set_panic_hook;
+use utils::{log, set_panic_hook};
 use wasm_bindgen::prelude::*;
 
 use std::{
-    collections::{HashMap, HashSet, VecDeque},
-    hash::Hash,
-    ops::{Add, Mul},
+    collections::{HashSet, VecDeque},
     sync::{
         atomic::{AtomicUsize, Ordering},
         Arc,
     },
-    u64, // time::Instant,
+    // time::Instant,
 };
 
+mod area;
 mod attack;
 mod data_input;
+mod duel;
+mod error;
+mod evolution;
+mod monte_carlo;
+mod rotation;
+mod scenario;
 mod simulation;
 
-use crossbeam::queue::ArrayQueue;
+use area::{Battlefield, Position, SecondaryTarget, SecondaryTargetResult};
+pub use data_input::rune_page::RunePage;
 use data_input::{
+    abilities::{find_ability, SpellData},
     common::{
         compile_passive_effects, Aura, AuraApplication, Champion, CritHandlingChoice, GameParams,
         TargetStats,
     },
-    items::{above_gold_cap, has_item_group_duplicates, Item, ItemData},
+    config::ScenarioConfig,
+    items::{above_gold_cap, has_item_group_duplicates, ItemData},
+    rune_page::RunePool,
     runes::Rune,
+    skill_order::apply_skill_order,
+    summoner_spells::SummonerSpell,
+    target::{
+        compute_champion_target_offensive_stats, compute_champion_target_stats,
+        TargetOffensiveStats,
+    },
 };
+pub use duel::{DuelResult, DuelSettings};
+pub use error::SimError;
+pub use evolution::{EvolutionOrderResult, EvolutionPoint};
 use itertools::Itertools;
-use rayon::iter::{ParallelBridge, ParallelIterator};
-use simulation::State;
-
-#[derive(Debug, Clone, serde::Deserialize)]
-struct SimulationInputData {
-    mode: String,
-    #[serde(rename(deserialize = "abilitySequence"))]
+pub use monte_carlo::{HistogramBucket, MonteCarloResult, MonteCarloSettings};
+use rotation::PriorityList;
+pub use scenario::{load_scenario, save_scenario, ScenarioFormat};
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SimulationInputData {
+    // items, runes, itemsAndRunes, combo, single, monteCarlo or duel. Optional as the native binary
+    // selects the mode itself
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub mode: String,
+    #[serde(rename = "abilitySequence")]
     ability_sequence: Vec<String>,
     champion: ChampionInputData,
-    config: HashMap<String, String>,
+    #[serde(default)]
+    config: ScenarioConfig,
     game: GameInputData,
     items: ItemInputData,
-    // runes: Vec<RuneInputData>,
-    #[serde(rename(deserialize = "selectedItemIds"))]
+    // only used by the monteCarlo mode, defaults apply when missing
+    #[serde(
+        rename = "monteCarlo",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub monte_carlo: Option<MonteCarloSettings>,
+    // only used by the duel mode, defaults apply when missing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duel: Option<DuelSettings>,
+    // the default page is the one used before the runes could be chosen
+    #[serde(default)]
+    runes: RunePage,
+    // candidates of the runes and itemsAndRunes modes
+    #[serde(rename = "runePool", default, skip_serializing_if = "Option::is_none")]
+    rune_pool: Option<RunePool>,
+    // when provided, replaces the ability sequence in the items and single modes
+    #[serde(
+        rename = "priorityList",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    priority_list: Option<PriorityList>,
+    #[serde(rename = "selectedItemIds")]
     selected_item_ids: Vec<u64>,
     target: TargetInputData,
+    // enemies standing around the target, hit by the abilities dealing damage in an area
+    #[serde(
+        rename = "secondaryTargets",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    secondary_targets: Vec<SecondaryTargetInputData>,
     general: GeneralInputData,
 }
 
-#[derive(Debug, Clone, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 struct ChampionInputData {
+    // selects the game data and the kit of the attacker
+    champion: Champion,
     level: u64,
-    #[serde(rename(deserialize = "healthPercentage"))]
+    #[serde(rename = "healthPercentage")]
     health_percentage: f64,
-    #[serde(rename(deserialize = "unseenThreatBuff"))]
+    #[serde(rename = "unseenThreatBuff")]
     unseen_threat_buff: bool,
+    // at most two. Their commands can only be used when selected
+    #[serde(
+        rename = "summonerSpells",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    summoner_spells: Vec<SummonerSpell>,
+    // ability leveled up at each level. Defaults to the skill order of the champion kit
+    #[serde(
+        rename = "skillOrder",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    skill_order: Option<Vec<attack::AttackType>>,
+    // relative to the target. Defaults to melee range
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    position: Option<Position>,
 }
 
-#[derive(Debug, Clone, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 struct GameInputData {
-    #[serde(rename(deserialize = "critHandling"))]
+    #[serde(rename = "critHandling")]
     crit_handling: String,
-    #[serde(rename(deserialize = "gameTime"))]
+    // only used when crits are rolled
+    #[serde(rename = "critSeed", default, skip_serializing_if = "Option::is_none")]
+    crit_seed: Option<u64>,
+    #[serde(rename = "gameTime")]
     game_time: u64,
+    // the fight goes on against the secondary targets once the target is dead
+    #[serde(
+        rename = "continueAfterKill",
+        default,
+        skip_serializing_if = "std::ops::Not::not"
+    )]
+    continue_after_kill: bool,
+    // the target runs away from the attacker at this speed
+    #[serde(
+        rename = "targetMovementSpeed",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    target_movement_speed: Option<f64>,
 }
 
-#[derive(Debug, Clone, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 struct ItemInputData {
-    #[serde(rename(deserialize = "maxGold"))]
+    #[serde(rename = "maxGold", skip_serializing_if = "Option::is_none")]
     max_gold: Option<u64>,
-    #[serde(rename(deserialize = "numItems"))]
+    #[serde(rename = "numItems")]
     num_items: u64,
 }
 
-#[derive(Debug, Clone, serde::Deserialize)]
-struct TargetInputData {
+// the target is either a champion (its stats are then derived from its level and items)
+// or a bag of stats
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+enum TargetInputData {
+    Champion(TargetChampionInputData),
+    Stats(TargetStatsInputData),
+}
+
+impl TargetInputData {
+    fn stats(&self) -> Result<TargetStats, SimError> {
+        match self {
+            TargetInputData::Champion(target) => compute_champion_target_stats(
+                target.champion()?,
+                target.level,
+                &target.item_ids,
+                target.health_percentage,
+            ),
+            TargetInputData::Stats(target) => Ok(TargetStats {
+                armor: target.armor as f64,
+                armor_bonus: 0.0,
+                max_health: target.max_health as f64,
+                current_health: target.current_health as f64,
+                magic_resistance: target.magic_resistance as f64,
+                magic_resistance_bonus: 0.0,
+                armor_reduction_flat: 0.0,
+                armor_reduction_perc: 0.0,
+                magic_resistance_reduction_flat: 0.0,
+                magic_resistance_reduction_perc: 0.0,
+            }),
+        }
+    }
+
+    // the settings of the duel override the offense derived from the target champion
+    fn offensive_stats(&self, settings: &DuelSettings) -> Result<TargetOffensiveStats, SimError> {
+        let offensive_stats = match self {
+            TargetInputData::Champion(target) => compute_champion_target_offensive_stats(
+                target.champion()?,
+                target.level,
+                &target.item_ids,
+            )?,
+            TargetInputData::Stats(_) => TargetOffensiveStats {
+                attack_damage: settings.attack_damage.ok_or_else(|| {
+                    SimError::invalid_input(
+                        "duel.attackDamage",
+                        "required when the target is not a champion",
+                    )
+                })?,
+                attack_speed: settings.attack_speed.ok_or_else(|| {
+                    SimError::invalid_input(
+                        "duel.attackSpeed",
+                        "required when the target is not a champion",
+                    )
+                })?,
+                windup_percent: 0.25,
+            },
+        };
+
+        Ok(TargetOffensiveStats {
+            attack_damage: settings
+                .attack_damage
+                .unwrap_or(offensive_stats.attack_damage),
+            attack_speed: settings
+                .attack_speed
+                .unwrap_or(offensive_stats.attack_speed),
+            ..offensive_stats
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+struct SecondaryTargetInputData {
+    #[serde(flatten)]
+    target: TargetInputData,
+    // relative to the target
+    position: Position,
+}
+
+impl SimulationInputData {
+    fn battlefield(&self) -> Result<Battlefield, SimError> {
+        Ok(Battlefield {
+            attacker_position: self
+                .champion
+                .position
+                .unwrap_or(area::DEFAULT_ATTACKER_POSITION),
+            secondary_targets: self
+                .secondary_targets
+                .iter()
+                .map(|secondary_target| {
+                    Ok(SecondaryTarget {
+                        stats: secondary_target.target.stats()?,
+                        position: secondary_target.position,
+                    })
+                })
+                .collect::<Result<Vec<_>, SimError>>()?,
+            target_movement_speed: self.game.target_movement_speed.unwrap_or_default(),
+            continue_after_kill: self.game.continue_after_kill,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+struct TargetChampionInputData {
+    // kept as a name so that a champion without game data is reported as such, rather than
+    // failing to match any variant of TargetInputData
+    champion: String,
+    level: u64,
+    #[serde(rename = "itemIds", default)]
+    item_ids: Vec<u64>,
+    #[serde(rename = "healthPercentage", default = "full_health")]
+    health_percentage: f64,
+}
+
+impl TargetChampionInputData {
+    fn champion(&self) -> Result<Champion, SimError> {
+        serde_json::from_value(serde_json::Value::String(self.champion.clone())).map_err(|_| {
+            SimError::invalid_input(
+                "target.champion",
+                format!("no game data for the champion '{}'", self.champion),
+            )
+        })
+    }
+}
+
+fn full_health() -> f64 {
+    100.0
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+struct TargetStatsInputData {
     armor: u64,
-    #[serde(rename(deserialize = "maxHealth"))]
+    #[serde(rename = "maxHealth")]
     max_health: u64,
-    #[serde(rename(deserialize = "currentHealth"))]
+    #[serde(rename = "currentHealth")]
     current_health: u64,
-    #[serde(rename(deserialize = "magicResistance"))]
+    #[serde(rename = "magicResistance")]
     magic_resistance: u64,
 }
 
-#[derive(Debug, Clone, serde::Deserialize)]
-
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 struct GeneralInputData {
-    #[serde(rename(deserialize = "topResultNumber"))]
+    #[serde(rename = "topResultNumber")]
     pub top_result_number: u64,
-    #[serde(rename(deserialize = "sortCriteria"))]
+    #[serde(rename = "sortCriteria")]
     pub sort_criteria: String,
-    #[serde(rename(deserialize = "showDetailledEventHistory"))]
+    #[serde(rename = "showDetailledEventHistory")]
     pub show_detailled_event_history: bool,
 }
 
@@ -103,31 +318,34 @@ struct Build {
     selected_commands: Vec<attack::AttackType>,
     kill: bool,
     damage_history: Vec<simulation::DamageInfo>,
+    heal_history: Vec<simulation::HealInfo>,
     event_history: Vec<simulation::Event>,
+    rune_page: RunePage,
+    secondary_targets: Vec<SecondaryTargetResult>,
 }
 
 #[derive(Debug, Clone, serde::Serialize)]
-struct TopResult {
-    damage: f64,
-    dps: f64,
-    item_names: Vec<String>,
-    cost: u64,
-    time_ms: u64,
-    selected_commands: Vec<attack::AttackType>,
-    kill: bool,
-    damage_history: Vec<simulation::DamageInfo>,
-    event_history: Vec<simulation::Event>,
+pub struct TopResult {
+    pub damage: f64,
+    pub dps: f64,
+    pub item_names: Vec<String>,
+    pub cost: u64,
+    pub time_ms: u64,
+    pub selected_commands: Vec<attack::AttackType>,
+    pub kill: bool,
+    pub damage_history: Vec<simulation::DamageInfo>,
+    pub heal_history: Vec<simulation::HealInfo>,
+    pub event_history: Vec<simulation::Event>,
+    pub rune_page: RunePage,
+    // same order as the secondary targets of the input
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub secondary_targets: Vec<SecondaryTargetResult>,
 }
 
 #[wasm_bindgen]
 extern "C" {
     fn alert(s: &str);
 
-    // Use `js_namespace` here to bind `console.log(..)` instead of just
-    // `log(..)`
-    #[wasm_bindgen(js_namespace = console)]
-    fn log(s: &str);
-
     // The `console.log` is quite polymorphic, so we can bind it with multiple
     // signatures. Note that we need to use `js_name` to ensure we always call
     // `log` in JS.
@@ -149,94 +367,252 @@ pub fn init() {
     set_panic_hook();
 }
 
+// JSON schema of the `config` section of the simulation input
+#[wasm_bindgen]
+pub fn scenario_config_schema() -> String {
+    ScenarioConfig::json_schema().to_string()
+}
+
 #[wasm_bindgen]
 pub fn execute_simulation(js_val: JsValue) -> Result<JsValue, JsValue> {
     // log(format!("execute_simulation: {:#?}", js_val).as_str());
 
     let deserializer = serde_wasm_bindgen::Deserializer::from(js_val);
     let result: Result<SimulationInputData, _> = serde_path_to_error::deserialize(deserializer);
-    match result {
-        Ok(simulation_input_data) => {
-            log(format!("execute_simulation: {:#?}", simulation_input_data).as_str());
-
-            let mut runes: HashSet<Rune> = HashSet::new();
-            runes.insert(Rune::DarkHarvest);
-            runes.insert(Rune::SuddenImpact);
-            runes.insert(Rune::AbsoluteFocus);
-            runes.insert(Rune::GatheringStorm);
-            runes.insert(Rune::AdaptiveForce1);
-            runes.insert(Rune::AdaptiveForce2);
-            // runes.insert(Rune::AbilityHaste);
-
-            let results: Vec<TopResult>;
-
-            match simulation_input_data.mode.as_str() {
-                "items" => {
-                    results = optimize_items(simulation_input_data, runes);
-                }
-                "combo" => {
-                    results = optimize_combo(simulation_input_data, runes);
-                }
-                "single" => {
-                    results = run_single(simulation_input_data, runes);
-                }
-                _ => {
-                    panic!("Unknown mode: {:#?}", simulation_input_data.mode);
-                }
-            }
+    let simulation_input_data = match result {
+        Ok(simulation_input_data) => simulation_input_data,
+        Err(err) => {
+            let sim_error =
+                SimError::invalid_input(&err.path().to_string(), err.inner().to_string());
+            return Err(serde_wasm_bindgen::to_value(&sim_error)?);
+        }
+    };
+
+    log(format!("execute_simulation: {:#?}", simulation_input_data).as_str());
 
-            // let map: HashMap<String, String> = HashMap::new();
-            Ok(serde_wasm_bindgen::to_value(&results)?)
+    // the monteCarlo mode returns a distribution instead of a list of results, the duel
+    // mode the outcome of the fight and the evolutions mode the ranked evolution orders
+    let output = match simulation_input_data.mode.as_str() {
+        "monteCarlo" => simulate_monte_carlo(simulation_input_data)
+            .map(|result| serde_wasm_bindgen::to_value(&result)),
+        "duel" => {
+            simulate_duel(simulation_input_data).map(|result| serde_wasm_bindgen::to_value(&result))
         }
-        Err(err) => {
-            let path = err.path().to_string();
-            panic!("Parsing error at path: {}", path);
+        "evolutions" => simulate_evolutions(simulation_input_data)
+            .map(|results| serde_wasm_bindgen::to_value(&results)),
+        _ => simulate(simulation_input_data).map(|results| serde_wasm_bindgen::to_value(&results)),
+    };
+
+    match output {
+        Ok(value) => Ok(value?),
+        Err(sim_error) => Err(serde_wasm_bindgen::to_value(&sim_error)?),
+    }
+}
+
+// entry point shared by the wasm build and the native binary
+pub fn simulate(simulation_input_data: SimulationInputData) -> Result<Vec<TopResult>, SimError> {
+    let runes = simulation_input_data.runes.runes()?;
+
+    match simulation_input_data.mode.as_str() {
+        "items" => {
+            let rune_pages = vec![simulation_input_data.runes.clone()];
+            optimize_builds(simulation_input_data, true, rune_pages)
+        }
+        "runes" => {
+            let rune_pages = rune_pool_pages(&simulation_input_data)?;
+            optimize_builds(simulation_input_data, false, rune_pages)
         }
+        "itemsAndRunes" => {
+            let rune_pages = rune_pool_pages(&simulation_input_data)?;
+            optimize_builds(simulation_input_data, true, rune_pages)
+        }
+        "combo" => optimize_combo(simulation_input_data, runes),
+        "single" => run_single(simulation_input_data, runes),
+        mode => Err(SimError::invalid_input(
+            "mode",
+            format!("unknown mode '{}'", mode),
+        )),
     }
+}
+
+// parses the ability sequence, and checks that the summoner spells used by the ability
+// sequence and the priority list are selected
+fn parse_commands(input: &SimulationInputData) -> Result<VecDeque<attack::AttackType>, SimError> {
+    let summoner_spells = parse_summoner_spells(&input.champion)?;
 
-    // log(format!("execute_simulation: {:#?}", simulation_input_data).as_str());
+    let ability_sequence = input
+        .ability_sequence
+        .iter()
+        .enumerate()
+        .map(|(command_index, ability)| {
+            let command =
+                attack::AttackType::from_str(ability).ok_or_else(|| SimError::UnknownCommand {
+                    command: ability.clone(),
+                    command_index,
+                })?;
+            ensure_summoner_spell_selected(
+                command,
+                &summoner_spells,
+                &format!("abilitySequence[{}]", command_index),
+            )?;
+
+            Ok(command)
+        })
+        .collect::<Result<VecDeque<attack::AttackType>, SimError>>()?;
+
+    if let Some(priority_list) = &input.priority_list {
+        for (index, action) in priority_list.actions.iter().enumerate() {
+            ensure_summoner_spell_selected(
+                action.command,
+                &summoner_spells,
+                &format!("priorityList.actions[{}].command", index),
+            )?;
+        }
+    }
+
+    Ok(ability_sequence)
 }
 
-fn optimize_items(input: SimulationInputData, runes: HashSet<Rune>) -> Vec<TopResult> {
-    let mut selected_commands = VecDeque::new();
-    input.ability_sequence.iter().for_each(|ability| {
-        selected_commands.push_back(attack::AttackType::from_str(ability));
-    });
+fn parse_summoner_spells(
+    champion: &ChampionInputData,
+) -> Result<Vec<attack::AttackType>, SimError> {
+    if champion.summoner_spells.len() > 2 {
+        return Err(SimError::invalid_input(
+            "champion.summonerSpells",
+            "at most two summoner spells can be selected",
+        ));
+    }
 
-    let target_stats: TargetStats = TargetStats {
-        armor: input.target.armor as f64,
-        max_health: input.target.max_health as f64,
-        current_health: input.target.current_health as f64,
-        magic_resistance: input.target.magic_resistance as f64,
-    };
+    if !champion.summoner_spells.iter().all_unique() {
+        return Err(SimError::invalid_input(
+            "champion.summonerSpells",
+            "the same summoner spell is selected twice",
+        ));
+    }
 
-    let static_data =
-        data_input::parse_files(Champion::Khazix, &input.selected_item_ids, &input.config);
+    Ok(champion
+        .summoner_spells
+        .iter()
+        .map(|summoner_spell| summoner_spell.attack_type())
+        .collect())
+}
 
-    let perms = input
-        .selected_item_ids
-        .into_iter()
-        .combinations(input.items.num_items as usize);
+fn ensure_summoner_spell_selected(
+    command: attack::AttackType,
+    summoner_spells: &[attack::AttackType],
+    path: &str,
+) -> Result<(), SimError> {
+    if command.is_summoner_spell() && !summoner_spells.contains(&command) {
+        return Err(SimError::invalid_input(
+            path,
+            format!("{} is not one of the selected summoner spells", command),
+        ));
+    }
+
+    Ok(())
+}
+
+// game data of the champion and the selected items, with the ranks of the abilities
+// following the skill order of the input
+fn parse_static_data(input: &SimulationInputData) -> Result<data_input::StaticData, SimError> {
+    let mut static_data = data_input::parse_files(
+        input.champion.champion,
+        &input.selected_item_ids,
+        &input.config,
+    )?;
+
+    if let Some(skill_order) = &input.champion.skill_order {
+        apply_skill_order(
+            &mut static_data.abilities,
+            skill_order,
+            input.champion.level,
+        )?;
+    }
+
+    Ok(static_data)
+}
+
+fn parse_crit_handling(game: &GameInputData) -> Result<CritHandlingChoice, SimError> {
+    match game.crit_handling.as_str() {
+        "average" => Ok(CritHandlingChoice::Avg),
+        "never" => Ok(CritHandlingChoice::Min),
+        "always" => Ok(CritHandlingChoice::Max),
+        "random" => Ok(CritHandlingChoice::Random {
+            seed: game.crit_seed.unwrap_or_default(),
+        }),
+        _ => Err(SimError::invalid_input(
+            "game.critHandling",
+            format!("unknown crit handling '{}'", game.crit_handling),
+        )),
+    }
+}
+
+// the legal rune pages made of the rune pool
+fn rune_pool_pages(input: &SimulationInputData) -> Result<Vec<RunePage>, SimError> {
+    let rune_pool = input.rune_pool.as_ref().ok_or_else(|| {
+        SimError::invalid_input("runePool", format!("required by the {} mode", input.mode))
+    })?;
+
+    let rune_pages = rune_pool.pages(&input.runes.shards);
+    if rune_pages.is_empty() {
+        return Err(SimError::invalid_input(
+            "runePool",
+            "no legal rune page can be made from the pool",
+        ));
+    }
+
+    Ok(rune_pages)
+}
+
+// simulates every rune page with every combination of the selected items. When
+// `optimize_items` is false, the selected items are the only build tried
+fn optimize_builds(
+    input: SimulationInputData,
+    optimize_items: bool,
+    rune_pages: Vec<RunePage>,
+) -> Result<Vec<TopResult>, SimError> {
+    let selected_commands = parse_commands(&input)?;
+    let crit_handling = parse_crit_handling(&input.game)?;
+
+    let target_stats: TargetStats = input.target.stats()?;
+    let battlefield = input.battlefield()?;
+
+    let static_data = parse_static_data(&input)?;
+
+    let num_items = if optimize_items {
+        input.items.num_items as usize
+    } else {
+        input.selected_item_ids.len()
+    };
+    let perms = input.selected_item_ids.into_iter().combinations(num_items);
     let progress = Arc::new(AtomicUsize::new(0));
-    let size: usize = perms.size_hint().1.unwrap();
+    let size: usize = perms.size_hint().1.unwrap() * rune_pages.len();
     // let best_builds: ArrayQueue<Build> = ArrayQueue::new(size);
     let mut best_builds: Vec<Build> = Vec::with_capacity(size);
 
     // perms.par_bridge().for_each(|selected_item_ids| {
-    perms.for_each(|selected_item_ids| {
-        let mut selected_items: Vec<&ItemData> = Vec::new();
-        for selected_item_id in selected_item_ids.iter() {
-            let new_item = static_data.items_map.get(selected_item_id).unwrap();
-            selected_items.push(new_item);
-        }
-
+    for selected_item_ids in perms {
+        // parse_static_data has already ensured that every selected item is known
+        let selected_items: Vec<&ItemData> = selected_item_ids
+            .iter()
+            .map(|selected_item_id| &static_data.items_map[selected_item_id])
+            .collect();
+
+        // the ability sequence may use the active of an item which is not part of the build
         if has_item_group_duplicates(&selected_items)
+            || selected_commands.iter().any(|command| {
+                command.item().is_some_and(|item| {
+                    selected_items
+                        .iter()
+                        .all(|item_data| item_data.item != item)
+                })
+            })
             || input
                 .items
                 .max_gold
                 .is_some_and(|gold_cap| above_gold_cap(&selected_items, &gold_cap))
         {
-            return;
+            continue;
         }
 
         let mut initial_attacker_auras: Vec<AuraApplication> = Vec::new();
@@ -250,81 +626,99 @@ fn optimize_items(input: SimulationInputData, runes: HashSet<Rune>) -> Vec<TopRe
             });
         }
 
-        let crit_handling = match input.game.crit_handling.as_str() {
-            "average" => CritHandlingChoice::Avg,
-            "never" => CritHandlingChoice::Min,
-            "always" => CritHandlingChoice::Max,
-            &_ => panic!(),
-        };
-
-        let mut game_params: GameParams<'_> = GameParams {
-            champion: Champion::Khazix,
-            champion_data: &static_data.champion_data,
-            champion_stats: &static_data.base_champion_stats,
-            level: input.champion.level,
-            items: &selected_items,
-            initial_config: &input.config,
-            abilities: &static_data.abilities,
-            initial_target_stats: &target_stats,
-            runes: &runes,
-            attacker_hp_perc: input.champion.health_percentage,
-            runes_data: &static_data.runes_data,
-            passive_effects: &mut Vec::new(),
-            crit_handling,
-            initial_attacker_auras: &initial_attacker_auras,
-            initial_target_auras: &Vec::new(),
-            abilities_extra_data: &static_data.abilities_extra_data,
-            start_time_ms: input.game.game_time * 60 * 1000,
-            capture_event_history: input.general.show_detailled_event_history,
-            weave_auto_attacks: false,
-        };
+        for rune_page in rune_pages.iter() {
+            let mut game_params: GameParams<'_> = GameParams {
+                champion: input.champion.champion,
+                champion_data: &static_data.champion_data,
+                champion_stats: &static_data.base_champion_stats,
+                level: input.champion.level,
+                items: &selected_items,
+                config: &input.config,
+                abilities: &static_data.abilities,
+                initial_target_stats: &target_stats,
+                runes: &rune_page.runes()?,
+                attacker_hp_perc: input.champion.health_percentage,
+                runes_data: &static_data.runes_data,
+                passive_effects: &mut Vec::new(),
+                crit_handling,
+                initial_attacker_auras: &initial_attacker_auras,
+                initial_target_auras: &Vec::new(),
+                abilities_extra_data: &static_data.abilities_extra_data,
+                start_time_ms: input.game.game_time * 60 * 1000,
+                capture_event_history: input.general.show_detailled_event_history,
+                weave_auto_attacks: false,
+                battlefield: &battlefield,
+            };
 
-        compile_passive_effects(&mut game_params);
+            compile_passive_effects(&mut game_params);
 
-        let (damage, damage_history, event_history, _attack_history, time_ms, kill) =
-            simulation::run(selected_commands.clone(), &game_params);
+            let (
+                damage,
+                damage_history,
+                heal_history,
+                event_history,
+                attack_history,
+                time_ms,
+                kill,
+            ) = match &input.priority_list {
+                Some(priority_list) => simulation::run(priority_list.clone(), &game_params)?,
+                None => simulation::run(selected_commands.clone(), &game_params)?,
+            };
 
-        let build = Build {
-            damage,
-            item_ids: selected_item_ids.clone(),
-            dps: damage * (1000_f64 / time_ms as f64),
-            selected_commands: selected_commands.clone().into(),
-            time_ms,
-            kill,
-            damage_history,
-            event_history,
-        };
+            let build = Build {
+                damage,
+                item_ids: selected_item_ids.clone(),
+                dps: damage * (1000_f64 / time_ms as f64),
+                // with a priority list, the commands are only known once the simulation has run
+                selected_commands: if input.priority_list.is_some() {
+                    attack_history
+                } else {
+                    selected_commands.clone().into()
+                },
+                time_ms,
+                kill,
+                secondary_targets: area::secondary_target_results(
+                    &damage_history,
+                    game_params.battlefield,
+                ),
+                damage_history,
+                heal_history,
+                event_history,
+                rune_page: rune_page.clone(),
+            };
 
-        best_builds.push(build);
+            best_builds.push(build);
 
-        let current_progress = progress.fetch_add(1, Ordering::Relaxed);
-        log(format!(
-            "Progress: {:#?}%",
-            (current_progress as f64 / size as f64 * 100.0) as u32
-        )
-        .as_str());
-    });
+            let current_progress = progress.fetch_add(1, Ordering::Relaxed);
+            log(format!(
+                "Progress: {:#?}%",
+                (current_progress as f64 / size as f64 * 100.0) as u32
+            )
+            .as_str());
+        }
+    }
 
-    let results: Vec<TopResult> = sort_best_builds(best_builds, input.general.sort_criteria)
+    let results: Vec<TopResult> = sort_best_builds(best_builds, &input.general.sort_criteria)?
         .take(input.general.top_result_number as usize)
         .map(|build| map_to_top_result(build, &static_data))
         .collect_vec();
 
-    results
+    Ok(results)
 }
 
-fn optimize_combo(input: SimulationInputData, runes: HashSet<Rune>) -> Vec<TopResult> {
-    let target_stats: TargetStats = TargetStats {
-        armor: input.target.armor as f64,
-        max_health: input.target.max_health as f64,
-        current_health: input.target.current_health as f64,
-        magic_resistance: input.target.magic_resistance as f64,
-    };
+fn optimize_combo(
+    input: SimulationInputData,
+    runes: HashSet<Rune>,
+) -> Result<Vec<TopResult>, SimError> {
+    let crit_handling = parse_crit_handling(&input.game)?;
+    let summoner_spells = parse_summoner_spells(&input.champion)?;
+
+    let target_stats: TargetStats = input.target.stats()?;
+    let battlefield = input.battlefield()?;
 
-    let static_data =
-        data_input::parse_files(Champion::Khazix, &input.selected_item_ids, &input.config);
+    let static_data = parse_static_data(&input)?;
 
-    let selected_items: Vec<&ItemData> = static_data.items_map.values().collect();
+    let selected_items = selected_build(&input, &static_data)?;
     log(format!("selected_items: {:#?}", selected_items).as_str());
 
     let mut initial_attacker_auras: Vec<AuraApplication> = Vec::new();
@@ -338,20 +732,13 @@ fn optimize_combo(input: SimulationInputData, runes: HashSet<Rune>) -> Vec<TopRe
         });
     }
 
-    let crit_handling = match input.game.crit_handling.as_str() {
-        "average" => CritHandlingChoice::Avg,
-        "never" => CritHandlingChoice::Min,
-        "always" => CritHandlingChoice::Max,
-        &_ => panic!(),
-    };
-
     let mut game_params: GameParams<'_> = GameParams {
-        champion: Champion::Khazix,
+        champion: input.champion.champion,
         champion_data: &static_data.champion_data,
         champion_stats: &static_data.base_champion_stats,
         level: input.champion.level,
         items: &selected_items,
-        initial_config: &input.config,
+        config: &input.config,
         abilities: &static_data.abilities,
         initial_target_stats: &target_stats,
         runes: &runes,
@@ -365,6 +752,7 @@ fn optimize_combo(input: SimulationInputData, runes: HashSet<Rune>) -> Vec<TopRe
         start_time_ms: input.game.game_time * 60 * 1000,
         capture_event_history: input.general.show_detailled_event_history,
         weave_auto_attacks: true,
+        battlefield: &battlefield,
     };
 
     compile_passive_effects(&mut game_params);
@@ -378,11 +766,12 @@ fn optimize_combo(input: SimulationInputData, runes: HashSet<Rune>) -> Vec<TopRe
         let (
             _damage,
             _damage_history,
+            _heal_history,
             _event_history,
             _attack_history,
             basic_combo_time_ms,
             kill_result,
-        ) = simulation::run(basic_combo_commands.clone(), &game_params);
+        ) = simulation::run(basic_combo_commands.clone(), &game_params)?;
         kill = kill_result;
         max_time_ms = basic_combo_time_ms;
     }
@@ -393,68 +782,103 @@ fn optimize_combo(input: SimulationInputData, runes: HashSet<Rune>) -> Vec<TopRe
     // from here, we can already eliminiate all combos that are longer than the basic combo.
     // on each iteration, when we find a shorter combo that kills the target, we update the max_time_ms
     // and skip all combos that are longer than the new max_time_ms.
-    let mut possible_commands = Vec::new();
-    possible_commands.push(attack::AttackType::AA);
-    possible_commands.push(attack::AttackType::Q);
-    possible_commands.push(attack::AttackType::W);
-    possible_commands.push(attack::AttackType::E);
-    possible_commands.push(attack::AttackType::R);
+    let mut possible_commands = vec![
+        attack::AttackType::AA,
+        attack::AttackType::Q,
+        attack::AttackType::W,
+        attack::AttackType::E,
+        attack::AttackType::R,
+    ];
+    possible_commands.extend(summoner_spells);
+    // and the actives of the items
+    possible_commands.extend(item_actives(&static_data.abilities, &selected_items));
 
     let mut best_builds: Vec<Build> = Vec::new();
     test_next_possibilities(
         &possible_commands,
         &VecDeque::new(),
         &game_params,
+        &input.runes,
         &mut best_builds,
         &mut max_time_ms,
-    );
+    )?;
 
-    let results: Vec<Build> = sort_best_builds(best_builds, "time_asc".to_string()).collect_vec();
+    let results: Vec<Build> = sort_best_builds(best_builds, "time_asc")?.collect_vec();
 
     let mut filtered_results = results.clone();
-    let first_result = results.get(0);
-    if first_result.is_some() {
+    if let Some(first_result) = results.first() {
         filtered_results.retain(|result| {
-            result.time_ms == first_result.unwrap().time_ms
-                && result.selected_commands.len() == first_result.unwrap().selected_commands.len()
+            result.time_ms == first_result.time_ms
+                && result.selected_commands.len() == first_result.selected_commands.len()
         });
     }
 
-    filtered_results
+    Ok(filtered_results
         .into_iter()
         .map(|build| map_to_top_result(build, &static_data))
         .unique_by(|result| result.selected_commands.to_owned())
-        .collect_vec()
+        .collect_vec())
+}
+
+// the commands casting the actives of the given items
+fn item_actives(abilities: &[SpellData], items: &[&ItemData]) -> Vec<attack::AttackType> {
+    abilities
+        .iter()
+        .map(|ability| ability.attack_type)
+        .filter(|command| {
+            command
+                .item()
+                .is_some_and(|item| items.iter().any(|item_data| item_data.item == item))
+        })
+        .collect()
 }
 
 fn test_next_possibilities(
-    possible_commands: &Vec<attack::AttackType>,
+    possible_commands: &[attack::AttackType],
     commands_so_far: &VecDeque<attack::AttackType>,
     game_params: &GameParams<'_>,
+    rune_page: &RunePage,
     best_builds: &mut Vec<Build>,
     max_time_ms: &mut u64,
-) {
+) -> Result<(), SimError> {
     for next_command in possible_commands.iter() {
+        // the cooldowns of the summoner spells outlast a fight: each charge is cast at most once
+        if next_command.is_summoner_spell()
+            && commands_so_far
+                .iter()
+                .filter(|command| *command == next_command)
+                .count() as u64
+                >= summoner_spell_charges(*next_command, game_params)
+        {
+            continue;
+        }
+
         let mut selected_commands: VecDeque<attack::AttackType> = commands_so_far.clone();
-        selected_commands.push_back(next_command.clone());
+        selected_commands.push_back(*next_command);
 
         // log(format!("test_next_possibilities: {:#?}", selected_commands).as_str());
 
-        let (damage, damage_history, event_history, attack_history, time_ms, kill) =
-            simulation::run(selected_commands.clone(), &game_params);
+        let (damage, damage_history, heal_history, event_history, attack_history, time_ms, kill) =
+            simulation::run(selected_commands.clone(), game_params)?;
 
         if time_ms > *max_time_ms {
-            return;
+            return Ok(());
         } else if kill {
             let new_build = Build {
-                damage: damage.clone(),
+                damage,
                 item_ids: game_params.items.iter().map(|item| item.id).collect(),
-                dps: damage.clone() * (1000_f64 / time_ms as f64),
-                selected_commands: attack_history.into(),
+                dps: damage * (1000_f64 / time_ms as f64),
+                selected_commands: attack_history,
                 time_ms,
                 kill,
+                secondary_targets: area::secondary_target_results(
+                    &damage_history,
+                    game_params.battlefield,
+                ),
                 damage_history,
+                heal_history,
                 event_history,
+                rune_page: rune_page.clone(),
             };
 
             best_builds.push(new_build);
@@ -462,49 +886,146 @@ fn test_next_possibilities(
                 *max_time_ms = time_ms;
             }
 
-            return;
+            return Ok(());
         } else {
             test_next_possibilities(
                 possible_commands,
                 &selected_commands,
                 game_params,
+                rune_page,
                 best_builds,
                 max_time_ms,
-            );
+            )?;
         }
     }
+
+    Ok(())
 }
 
-fn run_single(input: SimulationInputData, runes: HashSet<Rune>) -> Vec<TopResult> {
-    let mut selected_commands = VecDeque::new();
-    input.ability_sequence.iter().for_each(|ability| {
-        selected_commands.push_back(attack::AttackType::from_str(ability));
-    });
+fn summoner_spell_charges(summoner_spell: attack::AttackType, game_params: &GameParams) -> u64 {
+    find_ability(game_params.abilities, summoner_spell, game_params.config)
+        .charges
+        .map_or(1, |charges| charges.count)
+}
 
-    let target_stats: TargetStats = TargetStats {
-        armor: input.target.armor as f64,
-        max_health: input.target.max_health as f64,
-        current_health: input.target.current_health as f64,
-        magic_resistance: input.target.magic_resistance as f64,
-    };
+fn run_single(
+    input: SimulationInputData,
+    runes: HashSet<Rune>,
+) -> Result<Vec<TopResult>, SimError> {
+    let selected_commands = parse_commands(&input)?;
+
+    with_selected_build(&input, &runes, |game_params, static_data| {
+        let (damage, damage_history, heal_history, event_history, attack_history, time_ms, kill) =
+            match &input.priority_list {
+                Some(priority_list) => simulation::run(priority_list.clone(), game_params)?,
+                None => simulation::run(selected_commands.clone(), game_params)?,
+            };
 
-    let static_data =
-        data_input::parse_files(Champion::Khazix, &input.selected_item_ids, &input.config);
+        let build = Build {
+            damage,
+            item_ids: input.selected_item_ids.clone(),
+            dps: damage * (1000_f64 / time_ms as f64),
+            // with a priority list, the commands are only known once the simulation has run
+            selected_commands: if input.priority_list.is_some() {
+                attack_history
+            } else {
+                selected_commands.clone().into()
+            },
+            time_ms,
+            kill,
+            secondary_targets: area::secondary_target_results(
+                &damage_history,
+                game_params.battlefield,
+            ),
+            damage_history,
+            heal_history,
+            event_history,
+            rune_page: input.runes.clone(),
+        };
 
-    let mut selected_items: Vec<&ItemData> = Vec::new();
-    for selected_item_id in input.selected_item_ids.iter() {
-        let new_item = static_data.items_map.get(selected_item_id).unwrap();
-        selected_items.push(new_item);
-    }
+        Ok(vec![map_to_top_result(build, static_data)])
+    })
+}
 
-    if has_item_group_duplicates(&selected_items)
-        || input
-            .items
-            .max_gold
-            .is_some_and(|gold_cap| above_gold_cap(&selected_items, &gold_cap))
-    {
-        panic!("Invalid item selection: above gold cap or duplicate item groups");
-    }
+// runs the ability sequence (or priority list) with the selected items many times,
+// see monte_carlo::run
+pub fn simulate_monte_carlo(input: SimulationInputData) -> Result<MonteCarloResult, SimError> {
+    let selected_commands = parse_commands(&input)?;
+    let settings = input.monte_carlo.clone().unwrap_or_default();
+
+    with_selected_build(
+        &input,
+        &input.runes.runes()?,
+        |game_params, _| match &input.priority_list {
+            Some(priority_list) => monte_carlo::run(priority_list, game_params, &settings),
+            None => monte_carlo::run(&selected_commands, game_params, &settings),
+        },
+    )
+}
+
+// runs the ability sequence (or priority list) with the selected items against a target
+// fighting back, see duel::run
+pub fn simulate_duel(input: SimulationInputData) -> Result<DuelResult, SimError> {
+    let selected_commands = parse_commands(&input)?;
+    let settings = input.duel.clone().unwrap_or_default();
+    let mut target_policy =
+        duel::TargetPolicy::new(input.target.offensive_stats(&settings)?, &settings)?;
+
+    with_selected_build(
+        &input,
+        &input.runes.runes()?,
+        |game_params, _| match &input.priority_list {
+            Some(priority_list) => {
+                duel::run(priority_list.clone(), &mut target_policy, game_params)
+            }
+            None => duel::run(selected_commands.clone(), &mut target_policy, game_params),
+        },
+    )
+}
+
+// runs the ability sequence (or priority list) with the selected items at each evolution
+// level, for every evolution order of Kha'Zix, see evolution::rank_evolution_orders
+pub fn simulate_evolutions(
+    input: SimulationInputData,
+) -> Result<Vec<EvolutionOrderResult>, SimError> {
+    let selected_commands = parse_commands(&input)?;
+    let runes = input.runes.runes()?;
+
+    evolution::rank_evolution_orders(&input.config, |level, config| {
+        let input = SimulationInputData {
+            config: config.clone(),
+            champion: ChampionInputData {
+                level,
+                ..input.champion.clone()
+            },
+            ..input.clone()
+        };
+
+        with_selected_build(&input, &runes, |game_params, _| {
+            let (damage, _, _, _, _, time_ms, kill) = match &input.priority_list {
+                Some(priority_list) => simulation::run(priority_list.clone(), game_params)?,
+                None => simulation::run(selected_commands.clone(), game_params)?,
+            };
+
+            Ok((damage, time_ms, kill))
+        })
+    })
+}
+
+// sets up the game params of a fight with exactly the selected items, then hands them over
+fn with_selected_build<T>(
+    input: &SimulationInputData,
+    runes: &HashSet<Rune>,
+    simulate_fight: impl FnOnce(&GameParams, &data_input::StaticData) -> Result<T, SimError>,
+) -> Result<T, SimError> {
+    let crit_handling = parse_crit_handling(&input.game)?;
+
+    let target_stats: TargetStats = input.target.stats()?;
+    let battlefield = input.battlefield()?;
+
+    let static_data = parse_static_data(input)?;
+
+    let selected_items = selected_build(input, &static_data)?;
 
     let mut initial_attacker_auras: Vec<AuraApplication> = Vec::new();
 
@@ -517,23 +1038,16 @@ fn run_single(input: SimulationInputData, runes: HashSet<Rune>) -> Vec<TopResult
         });
     }
 
-    let crit_handling = match input.game.crit_handling.as_str() {
-        "average" => CritHandlingChoice::Avg,
-        "never" => CritHandlingChoice::Min,
-        "always" => CritHandlingChoice::Max,
-        &_ => panic!(),
-    };
-
     let mut game_params: GameParams<'_> = GameParams {
-        champion: Champion::Khazix,
+        champion: input.champion.champion,
         champion_data: &static_data.champion_data,
         champion_stats: &static_data.base_champion_stats,
         level: input.champion.level,
         items: &selected_items,
-        initial_config: &input.config,
+        config: &input.config,
         abilities: &static_data.abilities,
         initial_target_stats: &target_stats,
-        runes: &runes,
+        runes,
         attacker_hp_perc: input.champion.health_percentage,
         runes_data: &static_data.runes_data,
         passive_effects: &mut Vec::new(),
@@ -544,63 +1058,81 @@ fn run_single(input: SimulationInputData, runes: HashSet<Rune>) -> Vec<TopResult
         start_time_ms: input.game.game_time * 60 * 1000,
         capture_event_history: input.general.show_detailled_event_history,
         weave_auto_attacks: false,
+        battlefield: &battlefield,
     };
 
     compile_passive_effects(&mut game_params);
 
-    let (damage, damage_history, event_history, _attack_history, time_ms, kill) =
-        simulation::run(selected_commands.clone(), &game_params);
-
-    let build = Build {
-        damage,
-        item_ids: input.selected_item_ids.clone(),
-        dps: damage * (1000_f64 / time_ms as f64),
-        selected_commands: selected_commands.clone().into(),
-        time_ms,
-        kill,
-        damage_history,
-        event_history,
-    };
+    simulate_fight(&game_params, &static_data)
+}
+
+// exactly the selected items, as long as they make a valid build
+fn selected_build<'a>(
+    input: &SimulationInputData,
+    static_data: &'a data_input::StaticData,
+) -> Result<Vec<&'a ItemData>, SimError> {
+    let selected_items: Vec<&ItemData> = input
+        .selected_item_ids
+        .iter()
+        .map(|selected_item_id| &static_data.items_map[selected_item_id])
+        .collect();
+
+    if has_item_group_duplicates(&selected_items) {
+        return Err(SimError::InvalidItemSelection {
+            reason: "duplicate item groups".to_string(),
+        });
+    }
+
+    if let Some(gold_cap) = input.items.max_gold {
+        if above_gold_cap(&selected_items, &gold_cap) {
+            return Err(SimError::InvalidItemSelection {
+                reason: format!("above the gold cap of {}", gold_cap),
+            });
+        }
+    }
 
-    vec![map_to_top_result(build, &static_data)]
+    Ok(selected_items)
 }
 
-fn sort_best_builds(best_builds: Vec<Build>, sort_criteria: String) -> std::vec::IntoIter<Build> {
+fn sort_best_builds(
+    best_builds: Vec<Build>,
+    sort_criteria: &str,
+) -> Result<std::vec::IntoIter<Build>, SimError> {
     let compare_dps = |a: &Build, b: &Build| {
-        let kill_ord = b.kill.partial_cmp(&a.kill).unwrap();
+        let kill_ord = b.kill.cmp(&a.kill);
         if kill_ord != std::cmp::Ordering::Equal {
             return kill_ord;
         }
 
-        let dps_ord = b.dps.partial_cmp(&a.dps).unwrap();
+        let dps_ord = b.dps.total_cmp(&a.dps);
         if dps_ord != std::cmp::Ordering::Equal {
             return dps_ord;
         }
 
-        a.time_ms.partial_cmp(&b.time_ms).unwrap()
+        a.time_ms.cmp(&b.time_ms)
     };
     let compare_damage = |a: &Build, b: &Build| {
-        let kill_ord = b.kill.partial_cmp(&a.kill).unwrap();
+        let kill_ord = b.kill.cmp(&a.kill);
         if kill_ord != std::cmp::Ordering::Equal {
             return kill_ord;
         }
 
-        let damage_ord = b.damage.partial_cmp(&a.damage).unwrap();
+        let damage_ord = b.damage.total_cmp(&a.damage);
         if damage_ord != std::cmp::Ordering::Equal {
             return damage_ord;
         }
 
-        a.time_ms.partial_cmp(&b.time_ms).unwrap()
+        a.time_ms.cmp(&b.time_ms)
     };
     let compare_time = |a: &Build, b: &Build| {
         // show kills first
-        let kill_ord = b.kill.partial_cmp(&a.kill).unwrap();
+        let kill_ord = b.kill.cmp(&a.kill);
         if kill_ord != std::cmp::Ordering::Equal {
             return kill_ord;
         }
 
         // show fastest times first
-        let time_ord = a.time_ms.partial_cmp(&b.time_ms).unwrap();
+        let time_ord = a.time_ms.cmp(&b.time_ms);
         if time_ord != std::cmp::Ordering::Equal {
             return time_ord;
         }
@@ -612,25 +1144,30 @@ fn sort_best_builds(best_builds: Vec<Build>, sort_criteria: String) -> std::vec:
         }
 
         // defaults to highest damage
-        b.damage.partial_cmp(&a.damage).unwrap()
+        b.damage.total_cmp(&a.damage)
     };
 
-    let cmp_fct = match sort_criteria.as_str() {
+    let cmp_fct = match sort_criteria {
         "dps_desc" => compare_dps,
         "damage_desc" => compare_damage,
         "time_asc" => compare_time,
-        &_ => panic!(),
+        _ => {
+            return Err(SimError::invalid_input(
+                "general.sortCriteria",
+                format!("unknown sort criteria '{}'", sort_criteria),
+            ))
+        }
     };
 
-    best_builds.into_iter().sorted_by(cmp_fct)
+    Ok(best_builds.into_iter().sorted_by(cmp_fct))
 }
 
 fn map_to_top_result(build: Build, static_data: &data_input::StaticData) -> TopResult {
     let item_names = build
         .item_ids
         .iter()
-        .map(|item_id| static_data.items_map.get(item_id).unwrap().item.clone())
-        .map(|item_name| Item::to_string(item_name))
+        .map(|item_id| static_data.items_map.get(item_id).unwrap().item)
+        .map(|item| item.to_string())
         .collect_vec();
 
     let cost = build
@@ -648,6 +1185,73 @@ fn map_to_top_result(build: Build, static_data: &data_input::StaticData) -> TopR
         selected_commands: build.selected_commands,
         kill: build.kill,
         damage_history: build.damage_history,
+        heal_history: build.heal_history,
         event_history: build.event_history,
+        rune_page: build.rune_page,
+        secondary_targets: build.secondary_targets,
+    }
+}
+
+#[cfg(test)]
+mod combo_tests {
+    use super::*;
+    use crate::scenario::{load_scenario, ScenarioFormat};
+
+    const SCENARIO: &str = include_str!("../scenarios/khazix_lethality.json");
+
+    fn combo_input(selected_item_ids: Vec<u64>) -> SimulationInputData {
+        SimulationInputData {
+            mode: "combo".to_string(),
+            selected_item_ids,
+            ..load_scenario(SCENARIO, ScenarioFormat::Json).unwrap()
+        }
+    }
+
+    #[test]
+    fn the_combo_build_respects_the_gold_cap() {
+        let input = combo_input(vec![6698, 6692, 3814]);
+        let max_gold = input.items.max_gold.unwrap();
+
+        let results = simulate(input).unwrap();
+
+        assert!(!results.is_empty());
+        assert!(results.iter().all(|result| result.cost <= max_gold));
+
+        // the whole item pool of the scenario costs more than the gold cap
+        assert!(matches!(
+            simulate(combo_input(vec![3142, 6701, 3814, 6694, 6698, 6692, 6697])),
+            Err(SimError::InvalidItemSelection { .. })
+        ));
+    }
+}
+
+#[cfg(test)]
+mod target_tests {
+    use super::*;
+    use crate::scenario::{load_scenario, ScenarioFormat};
+
+    const SCENARIO: &str = include_str!("../scenarios/khazix_vs_khazix.json");
+
+    #[test]
+    fn a_target_champion_without_game_data_is_rejected_by_name() {
+        let mut scenario: serde_json::Value = serde_json::from_str(SCENARIO).unwrap();
+        scenario["target"] = serde_json::json!({
+            "champion": "Jinx",
+            "level": 11,
+            "itemIds": [3026]
+        });
+
+        let input = SimulationInputData {
+            mode: "single".to_string(),
+            ..load_scenario(&scenario.to_string(), ScenarioFormat::Json).unwrap()
+        };
+
+        match simulate(input) {
+            Err(SimError::InvalidInput { path, reason }) => {
+                assert_eq!(path, "target.champion");
+                assert!(reason.contains("Jinx"));
+            }
+            other => panic!("expected an invalid input, got {:?}", other),
+        }
     }
 }
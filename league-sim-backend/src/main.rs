@@ -1,585 +1,372 @@
 use std::{
-    collections::{HashMap, HashSet, VecDeque},
-    ops::{Add, Mul},
-    sync::{
-        atomic::{AtomicUsize, Ordering},
-        Arc,
-    },
-    time::Instant,
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    process::ExitCode,
 };
 
-mod attack;
-mod data_input;
-mod simulation;
-
-use crossbeam::queue::ArrayQueue;
-use data_input::{
-    common::{
-        compile_passive_effects, Aura, AuraApplication, Champion, CritHandlingChoice, GameParams,
-        TargetStats,
-    },
-    items::{above_gold_cap, has_item_group_duplicates, Item, ItemData},
-    runes::Rune,
+use clap::{Parser, Subcommand, ValueEnum};
+use league_sim_backend::{
+    load_scenario, save_scenario, simulate, simulate_duel, simulate_evolutions,
+    simulate_monte_carlo, DuelResult, EvolutionOrderResult, MonteCarloResult, RunePage,
+    ScenarioFormat, SimError, SimulationInputData, TopResult,
 };
-use itertools::Itertools;
-use rayon::iter::{ParallelBridge, ParallelIterator};
-use simulation::State;
-
-#[derive(Debug, Clone)]
-struct Build {
-    damage: f64,
-    dps: f64,
-    item_ids: Vec<u64>,
-    time_ms: u64,
-    selected_commands: Vec<attack::AttackType>,
-    kill: bool,
-}
 
-#[derive(Debug, Clone)]
-struct TopResult {
-    damage: f64,
-    dps: f64,
-    item_names: Vec<String>,
-    cost: u64,
-    time_ms: u64,
-    selected_commands: Vec<attack::AttackType>,
+/// Runs league-sim simulations from a scenario file (JSON or TOML, same layout as the frontend input).
+/// Scenarios written for an older schema version are migrated on load.
+#[derive(Parser)]
+#[command(name = "league-sim", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = OutputFormat::Table, global = true)]
+    format: OutputFormat,
 }
 
-fn main() -> std::io::Result<()> {
-    // list of configs provided by the user. Static once the simulation is running
-    let mut config: HashMap<String, String> = HashMap::new();
-    config.insert(
-        "CHAMPION_KHAZIX_ISOLATED_TARGET".to_string(),
-        "TRUE".to_string(),
-    );
-
-    config.insert("CHAMPION_KHAZIX_Q_EVOLVED".to_string(), "TRUE".to_string());
-    config.insert("CHAMPION_KHAZIX_R_EVOLVED".to_string(), "FALSE".to_string());
-    config.insert("RUNE_DARK_HARVEST_STACKS".to_string(), "0".to_string());
-    config.insert(
-        "ITEM_HUBRIS_EMINENCE_ACTIVE".to_string(),
-        "FALSE".to_string(),
-    );
-    config.insert(
-        "ITEM_OPPORTUNITY_PREPARATION_READY".to_string(),
-        "TRUE".to_string(),
-    );
-
-    let item_ids: Vec<u64> = vec![
-        3158, // Ionian Boots of Lucidity
-        3006, // Berserker's Greaves
-        3142, // Youmuu's Ghostblade
-        6701, // Opportunity
-        3814, // Edge of Night
-        6694, // Serylda's Grudge
-        6698, // Profane Hydra
-        6692, // Eclipse
-        3156, // Maw of Malmortius
-        3179, // Umbral Glaive
-        6697, // Hubris
-        6333, // Death's Dance
-        3036, // Lord Dominik's Regards
-        3033, // Mortal Reminder
-        6609, // Chempunk Chainsword
-        3071, // Black Cleaver
-        6676, // The Collector
-        3072, // Bloodthirster
-        6699, // Voltaic Cyclosword
-        6695, // Serpent's Fang
-        3026, // Guardian Angel
-        3161, // Spear of Shojin
-        6696, // Axiom Arc
-        6610, // Sundered Sky
-        3074, // Ravenous Hydra
-        // 3004, // Manamune
-        3143, // Randuin's Omen
-        3110, // Frozen Heart
-        6631, // Stridebreaker
-        3153, // Blade of the Ruined King
-    ];
-
-    let mut runes: HashSet<Rune> = HashSet::new();
-    runes.insert(Rune::DarkHarvest);
-    runes.insert(Rune::SuddenImpact);
-    runes.insert(Rune::AbsoluteFocus);
-    runes.insert(Rune::GatheringStorm);
-    runes.insert(Rune::AdaptiveForce1);
-    runes.insert(Rune::AdaptiveForce2);
-    // runes.insert(Rune::AbilityHaste);
+#[derive(Subcommand)]
+enum Command {
+    /// Finds the best builds amongst the selected items
+    Items { scenario: PathBuf },
+    /// Finds the best rune pages amongst the rune pool, with the selected items
+    Runes { scenario: PathBuf },
+    /// Finds the best combinations of builds (amongst the selected items) and rune pages
+    /// (amongst the rune pool)
+    ItemsAndRunes { scenario: PathBuf },
+    /// Finds the fastest ability combos killing the target with the selected items
+    Combo { scenario: PathBuf },
+    /// Simulates the ability sequence (or priority list) with the selected items
+    Single { scenario: PathBuf },
+    /// Prints the time needed to kill the target with the selected items
+    Ttk { scenario: PathBuf },
+    /// Runs the ability sequence (or priority list) many times with different seeds and prints
+    /// the distribution of the kill time. Crits are only rolled with `"critHandling": "random"`
+    MonteCarlo {
+        scenario: PathBuf,
+        /// Number of iterations. Overrides the one of the scenario
+        #[arg(long)]
+        iterations: Option<u64>,
+        /// Kills after this time (in ms) do not count towards the kill probability within budget.
+        /// Overrides the one of the scenario
+        #[arg(long)]
+        time_budget_ms: Option<u64>,
+    },
+    /// Simulates the ability sequence (or priority list) with the selected items against a target
+    /// fighting back (see the `duel` section of the scenario), and prints who dies first
+    Duel { scenario: PathBuf },
+    /// Ranks the orders in which Kha'Zix evolves his abilities, by the kill time with the selected
+    /// items at each evolution level (6, 11 and 16)
+    Evolutions { scenario: PathBuf },
+    /// Prints the scenario migrated to the latest schema version
+    Migrate {
+        scenario: PathBuf,
+        /// Format of the migrated scenario. Defaults to the format of the input file
+        #[arg(long, value_enum)]
+        to: Option<FileFormat>,
+    },
+}
 
-    run_multiple(config, item_ids, runes);
-    // run_single(config, item_ids, runes);
-    // run_ttk(config, item_ids, runes);
+#[derive(Clone, Copy, ValueEnum)]
+enum FileFormat {
+    Json,
+    Toml,
+}
 
-    Ok(())
+#[derive(Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    Table,
+    Json,
 }
 
-fn run_multiple(config: HashMap<String, String>, item_ids: Vec<u64>, runes: HashSet<Rune>) {
-    let global_start = Instant::now();
-
-    let mut selected_commands = VecDeque::new();
-    selected_commands.push_back(attack::AttackType::R);
-    selected_commands.push_back(attack::AttackType::AA);
-    selected_commands.push_back(attack::AttackType::Q);
-    selected_commands.push_back(attack::AttackType::W);
-    // selected_commands.push_back(attack::AttackType::E);
-    // selected_commands.push_back(attack::AttackType::R);
-    // selected_commands.push_back(attack::AttackType::AA);
-    // selected_commands.push_back(attack::AttackType::Q);
-    let hp_perc = 100.0;
-    let level: u64 = 18;
-    let gold_cap: u64 = 20000;
-    let target_stats: TargetStats = TargetStats {
-        armor: 100.0,
-        max_health: 2400.0,
-        current_health: 2400.0,
-        magic_resistance: 100.0,
-    };
+fn main() -> ExitCode {
+    let cli = Cli::parse();
 
-    let static_data = data_input::parse_files(Champion::Khazix, &item_ids, &config);
-
-    // return;
-
-    let perms = item_ids.into_iter().combinations(5);
-    let progress = Arc::new(AtomicUsize::new(0));
-    let size: usize = perms.size_hint().1.unwrap();
-    let best_builds: ArrayQueue<Build> = ArrayQueue::new(size);
-
-    // perms.par_bridge().for_each(|selected_item_ids| {
-    perms.for_each(|selected_item_ids| {
-        let now = Instant::now();
-        // let champ_stats: ChampionStats = base_champion_stats.clone();
-        let mut selected_items: Vec<&ItemData> = Vec::new();
-        // println!("items:");
-        for selected_item_id in selected_item_ids.iter() {
-            let new_item = static_data.items_map.get(selected_item_id).unwrap();
-            // // println!("{:#?}", new_item.name);
-            selected_items.push(new_item);
+    match run(&cli) {
+        Ok(output) => {
+            // a closed stdout (e.g. piped into head) is not an error
+            let _ = writeln!(std::io::stdout(), "{}", output);
+            ExitCode::SUCCESS
         }
-
-        if has_item_group_duplicates(&selected_items) || above_gold_cap(&selected_items, &gold_cap)
-        {
-            return;
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
         }
+    }
+}
 
-        let mut game_params: GameParams<'_> = GameParams {
-            champion: Champion::Khazix,
-            champion_data: &static_data.champion_data,
-            champion_stats: &static_data.base_champion_stats,
-            level: level,
-            items: &selected_items,
-            initial_config: &config,
-            abilities: &static_data.abilities,
-            initial_target_stats: &target_stats,
-            runes: &runes,
-            attacker_hp_perc: hp_perc,
-            runes_data: &static_data.runes_data,
-            passive_effects: &mut Vec::new(),
-            crit_handling: CritHandlingChoice::Min,
-            initial_attacker_auras: &vec![AuraApplication {
-                aura: Aura::UnseenThreat,
-                stacks: None,
-                start_ms: 0,
-                end_ms: None,
-            }],
-            initial_target_auras: &Vec::new(),
-            abilities_extra_data: &static_data.abilities_extra_data,
-            start_time_ms: 0,
-            capture_event_history: false,
-            weave_auto_attacks: false,
-        };
-
-        compile_passive_effects(&mut game_params);
-
-        let (damage, _damage_history, _, _, time_ms, kill) =
-            simulation::run(selected_commands.clone(), &game_params);
-
-        // println!("DPS:: {:#?}", damage * (1000_f64 / time_ms as f64));
-        let build = Build {
-            damage: damage.clone(),
-            item_ids: selected_item_ids.clone(),
-            dps: damage.clone() * (1000_f64 / time_ms as f64),
-            selected_commands: selected_commands.clone().into(),
-            time_ms,
-            kill,
-        };
-
-        let push_result = best_builds.push(build);
-        if push_result.is_err() {
-            panic!();
+fn run(cli: &Cli) -> Result<String, SimError> {
+    let (mode, scenario) = match &cli.command {
+        Command::Items { scenario } => ("items", scenario),
+        Command::Runes { scenario } => ("runes", scenario),
+        Command::ItemsAndRunes { scenario } => ("itemsAndRunes", scenario),
+        Command::Combo { scenario } => ("combo", scenario),
+        Command::Single { scenario } => ("single", scenario),
+        // the time to kill is the time of the fastest combo
+        Command::Ttk { scenario } => ("combo", scenario),
+        Command::Migrate { scenario, to } => {
+            let input = read_scenario(scenario)?;
+            let format = match to {
+                Some(FileFormat::Json) => ScenarioFormat::Json,
+                Some(FileFormat::Toml) => ScenarioFormat::Toml,
+                None => ScenarioFormat::from_path(scenario),
+            };
+            return save_scenario(&input, format);
+        }
+        Command::MonteCarlo {
+            scenario,
+            iterations,
+            time_budget_ms,
+        } => {
+            let mut input = read_scenario(scenario)?;
+            let mut settings = input.monte_carlo.clone().unwrap_or_default();
+            if let Some(iterations) = iterations {
+                settings.iterations = *iterations;
+            }
+            if time_budget_ms.is_some() {
+                settings.time_budget_ms = *time_budget_ms;
+            }
+            input.mode = "monteCarlo".to_string();
+            input.monte_carlo = Some(settings);
+
+            let result = simulate_monte_carlo(input)?;
+            return Ok(match cli.format {
+                OutputFormat::Json => serde_json::to_string_pretty(&result).unwrap(),
+                OutputFormat::Table => format_monte_carlo(&result),
+            });
         }
+        Command::Duel { scenario } => {
+            let mut input = read_scenario(scenario)?;
+            input.mode = "duel".to_string();
+
+            let result = simulate_duel(input)?;
+            return Ok(match cli.format {
+                OutputFormat::Json => serde_json::to_string_pretty(&result).unwrap(),
+                OutputFormat::Table => format_duel(&result),
+            });
+        }
+        Command::Evolutions { scenario } => {
+            let mut input = read_scenario(scenario)?;
+            input.mode = "evolutions".to_string();
+
+            let results = simulate_evolutions(input)?;
+            return Ok(match cli.format {
+                OutputFormat::Json => serde_json::to_string_pretty(&results).unwrap(),
+                OutputFormat::Table => format_evolutions(&results),
+            });
+        }
+    };
 
-        let current_progress = progress.fetch_add(1, Ordering::Relaxed);
-        let elapsed = now.elapsed();
-        println!("Elapsed: {:.2?}", elapsed);
-        println!(
-            "Progress: {:#?}%",
-            current_progress as f64 / size as f64 * 100.0
-        );
-    });
+    let mut input = read_scenario(scenario)?;
+    input.mode = mode.to_string();
 
-    let results = sort_best_builds(static_data, best_builds.into_iter().collect_vec());
-    println!("Top results: {:#?}", results);
+    let mut results = simulate(input)?;
+    if let Command::Ttk { .. } = cli.command {
+        results.truncate(1);
+    }
 
-    let global_elapsed = global_start.elapsed();
-    println!("Elapsed: {:.2?}", global_elapsed);
+    Ok(match cli.format {
+        OutputFormat::Json => serde_json::to_string_pretty(&results).unwrap(),
+        OutputFormat::Table => format_table(&results),
+    })
 }
 
-fn sort_best_builds(
-    static_data: data_input::StaticData,
-    best_builds: Vec<Build>,
-) -> Vec<TopResult> {
-    let results = best_builds
-        .into_iter()
-        // .sorted_by(|a, b| b.time_ms.partial_cmp(&a.time_ms).unwrap())
-        .sorted_by(|a, b| a.time_ms.partial_cmp(&b.time_ms).unwrap())
-        .take(50)
-        .map(|build| {
-            let item_names = build
-                .item_ids
-                .iter()
-                .map(|item_id| static_data.items_map.get(item_id).unwrap().item.clone())
-                .map(|item_name| Item::to_string(item_name))
-                .collect_vec();
+fn read_scenario(path: &Path) -> Result<SimulationInputData, SimError> {
+    let content = fs::read_to_string(path)
+        .map_err(|err| SimError::invalid_input(&path.display().to_string(), err.to_string()))?;
 
-            let cost = build
-                .item_ids
-                .iter()
-                .map(|item_id| static_data.items_map.get(item_id).unwrap())
-                .fold(0, |acc, item| acc + item.total_cost);
-
-            TopResult {
-                damage: build.damage,
-                dps: build.dps,
-                item_names,
-                cost,
-                time_ms: build.time_ms,
-                selected_commands: build.selected_commands,
-            }
-        })
-        .collect_vec();
-    results
+    load_scenario(&content, ScenarioFormat::from_path(path))
 }
 
-fn run_single(config: HashMap<String, String>, item_ids: Vec<u64>, runes: HashSet<Rune>) {
-    let global_start = Instant::now();
-
-    let mut selected_commands = VecDeque::new();
-    selected_commands.push_back(attack::AttackType::Q);
-    selected_commands.push_back(attack::AttackType::W);
-    // selected_commands.push_back(attack::AttackType::E);
-    // selected_commands.push_back(attack::AttackType::R);
-    selected_commands.push_back(attack::AttackType::AA);
-    // selected_commands.push_back(attack::AttackType::R);
-    selected_commands.push_back(attack::AttackType::AA);
-    selected_commands.push_back(attack::AttackType::Q);
-    selected_commands.push_back(attack::AttackType::AA);
-    selected_commands.push_back(attack::AttackType::Q);
-    selected_commands.push_back(attack::AttackType::AA);
-    selected_commands.push_back(attack::AttackType::Q);
-    selected_commands.push_back(attack::AttackType::AA);
-    let hp_perc = 100.0;
-    let level: u64 = 18;
-    let gold_cap: u64 = 20000;
-    let target_stats: TargetStats = TargetStats {
-        armor: 100.0,
-        max_health: 2400.0,
-        current_health: 2400.0,
-        magic_resistance: 100.0,
-    };
-
-    let static_data = data_input::parse_files(Champion::Khazix, &item_ids, &config);
-
-    let mut selected_items: Vec<&ItemData> = Vec::new();
-
-    let selected_item_names: Vec<&str> = vec![
-        // "Ionian Boots of Lucidity",
-        // // "Eclipse",
-        // // "Berserker's Greaves",
-        // "Youmuu's Ghostblade",
-        // // "Profane Hydra",
-        // // "Bloodthirster",
-        // // "Opportunity",
-        // // "Black Cleaver",
-        // // "Serylda's Grudge",
-        // "Edge of Night",
-        "Youmuu's Ghostblade",
-        "Serylda's Grudge",
-        "Profane Hydra",
-        "Hubris",
-        "Voltaic Cyclosword",
-    ];
-
-    for ele in selected_item_names.iter() {
-        let found_item = static_data
-            .items_map
-            .values()
-            .find(|&item| Item::to_string(item.item) == *ele.to_string())
-            .unwrap();
-        selected_items.push(found_item);
+fn format_table(results: &[TopResult]) -> String {
+    let mut rows = vec![[
+        "#".to_string(),
+        "kill".to_string(),
+        "time (s)".to_string(),
+        "damage".to_string(),
+        "dps".to_string(),
+        "cost".to_string(),
+        "items".to_string(),
+        "runes".to_string(),
+        "commands".to_string(),
+    ]];
+
+    for (index, result) in results.iter().enumerate() {
+        rows.push([
+            (index + 1).to_string(),
+            if result.kill { "yes" } else { "no" }.to_string(),
+            format!("{:.2}", result.time_ms as f64 / 1000.0),
+            format!("{:.0}", result.damage),
+            format!("{:.0}", result.dps),
+            result.cost.to_string(),
+            result.item_names.join(", "),
+            format_rune_page(&result.rune_page),
+            result
+                .selected_commands
+                .iter()
+                .map(|command| command.to_string())
+                .collect::<Vec<_>>()
+                .join(" "),
+        ]);
     }
 
-    let mut game_params: GameParams<'_> = GameParams {
-        champion: Champion::Khazix,
-        champion_data: &static_data.champion_data,
-        champion_stats: &static_data.base_champion_stats,
-        level: level,
-        items: &selected_items,
-        initial_config: &config,
-        abilities: &static_data.abilities,
-        initial_target_stats: &target_stats,
-        runes_data: &static_data.runes_data,
-        runes: &runes,
-        attacker_hp_perc: hp_perc,
-        passive_effects: &mut Vec::new(),
-        crit_handling: CritHandlingChoice::Min,
-        // initial_attacker_auras: &vec![AuraApplication {
-        //     aura: Aura::UnseenThreat,
-        //     stacks: None,
-        //     start_ms: 0,
-        //     end_ms: None,
-        // }],
-        // initial_attacker_auras: &vec![],
-        // initial_attacker_auras: &vec![AuraApplication {
-        //     aura: Aura::HubrisEminence,
-        //     stacks: Some(17),
-        //     start_ms: 0,
-        //     end_ms: Some(90_000),
-        // }],
-        initial_attacker_auras: &vec![
-            AuraApplication {
-                aura: Aura::UnseenThreat,
-                stacks: None,
-                start_ms: 0,
-                end_ms: None,
-            },
-            AuraApplication {
-                aura: Aura::Energized,
-                stacks: Some(100),
-                start_ms: 0,
-                end_ms: None,
-            },
-        ],
-        initial_target_auras: &Vec::new(),
-        abilities_extra_data: &static_data.abilities_extra_data,
-        start_time_ms: 0,
-        capture_event_history: false,
-        weave_auto_attacks: false,
-    };
-
-    compile_passive_effects(&mut game_params);
+    let mut widths = [0; 9];
+    for row in rows.iter() {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
 
-    let (damage, damage_history, _, _, time_ms, kill) =
-        simulation::run(selected_commands.clone(), &game_params);
-    println!("kill: {:#?}", kill);
-    println!("damage: {:#?}", damage);
-    println!("time_ms: {:#?}", time_ms);
-    println!("DPS: {:#?}", damage * (1000_f64 / time_ms as f64));
-    println!("damage_history: {:#?}", damage_history);
+    rows.iter()
+        .map(|row| {
+            row.iter()
+                .zip(widths.iter())
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
 
-    let global_elapsed = global_start.elapsed();
-    println!("Elapsed: {:.2?}", global_elapsed);
+// keystone, then the primary and secondary runes. The stat shards are left out
+fn format_rune_page(rune_page: &RunePage) -> String {
+    std::iter::once(&rune_page.keystone)
+        .chain(rune_page.primary.iter())
+        .chain(rune_page.secondary.iter())
+        .map(|rune| format!("{:?}", rune))
+        .collect::<Vec<_>>()
+        .join(" ")
 }
 
-fn run_ttk(config: HashMap<String, String>, item_ids: Vec<u64>, runes: HashSet<Rune>) {
-    let global_start = Instant::now();
-
-    let hp_perc = 100.0;
-    let level: u64 = 18;
-    let gold_cap: u64 = 20000;
-    let target_stats: TargetStats = TargetStats {
-        armor: 100.0,
-        max_health: 2400.0,
-        current_health: 2400.0,
-        magic_resistance: 100.0,
+fn format_monte_carlo(result: &MonteCarloResult) -> String {
+    let seconds = |time_ms: Option<f64>| match time_ms {
+        Some(time_ms) => format!("{:.2}s", time_ms / 1000.0),
+        None => "-".to_string(),
     };
 
-    let static_data = data_input::parse_files(Champion::Khazix, &item_ids, &config);
-
-    let mut selected_items: Vec<&ItemData> = Vec::new();
-
-    let selected_item_names: Vec<&str> = vec![
-        // "Ionian Boots of Lucidity",
-        // // "Eclipse",
-        // // "Berserker's Greaves",
-        // "Youmuu's Ghostblade",
-        // // "Profane Hydra",
-        // // "Bloodthirster",
-        // // "Opportunity",
-        // // "Black Cleaver",
-        // // "Serylda's Grudge",
-        // "Edge of Night",
-        "Youmuu's Ghostblade",
-        "Serylda's Grudge",
-        "Profane Hydra",
-        "Hubris",
-        "Voltaic Cyclosword",
+    let mut lines = vec![
+        format!("iterations: {}", result.iterations),
+        format!("kill probability: {:.1}%", result.kill_probability * 100.0),
     ];
-
-    for ele in selected_item_names.iter() {
-        let found_item = static_data
-            .items_map
-            .values()
-            .find(|&item| Item::to_string(item.item) == *ele.to_string())
-            .unwrap();
-        selected_items.push(found_item);
+    if let Some(probability) = result.kill_probability_within_budget {
+        lines.push(format!(
+            "kill probability within budget: {:.1}%",
+            probability * 100.0
+        ));
     }
-
-    let mut game_params: GameParams<'_> = GameParams {
-        champion: Champion::Khazix,
-        champion_data: &static_data.champion_data,
-        champion_stats: &static_data.base_champion_stats,
-        level: level,
-        items: &selected_items,
-        initial_config: &config,
-        abilities: &static_data.abilities,
-        initial_target_stats: &target_stats,
-        runes_data: &static_data.runes_data,
-        runes: &runes,
-        attacker_hp_perc: hp_perc,
-        passive_effects: &mut Vec::new(),
-        crit_handling: CritHandlingChoice::Min,
-        initial_attacker_auras: &vec![
-            // AuraApplication {
-            //     aura: Aura::UnseenThreat,
-            //     stacks: None,
-            //     start_ms: 0,
-            //     end_ms: None,
-            // },
-            AuraApplication {
-                aura: Aura::Energized,
-                stacks: Some(100),
-                start_ms: 0,
-                end_ms: None,
-            },
-        ],
-        initial_target_auras: &Vec::new(),
-        abilities_extra_data: &static_data.abilities_extra_data,
-        start_time_ms: 0,
-        capture_event_history: false,
-        weave_auto_attacks: false,
-    };
-
-    compile_passive_effects(&mut game_params);
-
-    let mut possible_commands = Vec::new();
-    possible_commands.push(attack::AttackType::AA);
-    possible_commands.push(attack::AttackType::Q);
-    possible_commands.push(attack::AttackType::W);
-    possible_commands.push(attack::AttackType::E);
-    possible_commands.push(attack::AttackType::R);
-
-    // let perms = possible_commands
-    //     .into_iter()
-    //     .combinations_with_replacement(1);
-
-    // let size: usize = perms.size_hint().1.unwrap();
-    let mut best_builds: Vec<Build> = Vec::new();
-    // let mut best_build: Option<Build> = Option::None;
-
-    test_next_possibilities(
-        &possible_commands,
-        &VecDeque::new(),
-        &game_params,
-        &mut best_builds,
-    );
-
-    // perms.par_bridge().for_each(|selected_item_ids| {
-    // perms.for_each(|selected_commands| {
-    //     println!("selected_commands: {:#?}", selected_commands);
-
-    //     let (damage, damage_history, time_ms, kill) =
-    //         simulation::run(selected_commands.clone().into(), &game_params);
-    //     println!("damage: {:#?}", damage);
-    //     println!("time_ms: {:#?}", time_ms);
-    //     println!("DPS: {:#?}", damage * (1000_f64 / time_ms as f64));
-    //     // println!("damage_history: {:#?}", damage_history);
-
-    //     let build = Build {
-    //         damage: damage.clone(),
-    //         item_ids: selected_items
-    //             .iter()
-    //             .map(|item_data| item_data.id)
-    //             .collect_vec()
-    //             .clone(),
-    //         dps: damage.clone() * (1000_f64 / time_ms as f64),
-    //         selected_commands,
-    //         time_ms,
-    //         kill,
-    //     };
-
-    //     best_builds.push(build);
-    // });
-
-    // let mut builds = best_builds.into_iter().collect_vec();
-    // builds.retain(|build| build.kill);
-
-    let results = sort_best_builds(static_data, best_builds);
-    let mut filtered_results = results.clone();
-    let best_build = results.get(0);
-    if best_build.is_some() {
-        filtered_results.retain(|result| result.time_ms == best_build.unwrap().time_ms);
-        println!("Top results: {:#?}", filtered_results);
+    lines.push(format!(
+        "kill time: mean {}, median {}, p10 {}, p90 {}",
+        seconds(result.mean_kill_time_ms),
+        seconds(result.median_kill_time_ms.map(|time_ms| time_ms as f64)),
+        seconds(result.p10_kill_time_ms.map(|time_ms| time_ms as f64)),
+        seconds(result.p90_kill_time_ms.map(|time_ms| time_ms as f64)),
+    ));
+
+    // one bar per bucket, the most populated bucket being 40 characters wide
+    let max_count = result
+        .histogram
+        .iter()
+        .map(|bucket| bucket.count)
+        .max()
+        .unwrap_or_default();
+    for bucket in result.histogram.iter() {
+        let line = format!(
+            "{:>7.2}s - {:>6.2}s  {:>6}  {}",
+            bucket.start_ms as f64 / 1000.0,
+            bucket.end_ms as f64 / 1000.0,
+            bucket.count,
+            "#".repeat((bucket.count * 40).div_ceil(max_count.max(1)) as usize)
+        );
+        lines.push(line.trim_end().to_string());
     }
 
-    // println!("Best build: {:#?}", best_build);
-
-    let global_elapsed = global_start.elapsed();
-    println!("Elapsed: {:.2?}", global_elapsed);
+    lines.join("\n")
 }
 
-fn test_next_possibilities(
-    possible_commands: &Vec<attack::AttackType>,
-    commands_so_far: &VecDeque<attack::AttackType>,
-    game_params: &GameParams<'_>,
-    best_builds: &mut Vec<Build>,
-) {
-    for next_command in possible_commands.iter() {
-        let mut selected_commands: VecDeque<attack::AttackType> = commands_so_far.clone();
-        selected_commands.push_back(next_command.clone());
-
-        println!("running: {:#?}", selected_commands);
-        let (damage, damage_history, _, _, time_ms, kill) =
-            simulation::run(selected_commands.clone(), &game_params);
-        println!(
-            "damage: {:#?}. time_ms: {:#?}. kill: {:#?}",
-            damage, time_ms, kill
-        );
-
-        if kill || time_ms > 100_000 {
-            // if is_better_build(best_build, damage, time_ms) {
-            let new_build = Build {
-                damage: damage.clone(),
-                item_ids: Vec::new(),
-                dps: damage.clone() * (1000_f64 / time_ms as f64),
-                selected_commands: selected_commands.into(),
-                time_ms,
-                kill,
-            };
+fn format_duel(result: &DuelResult) -> String {
+    let winner = match result.winner {
+        Some(unit) => format!("{:?}", unit).to_lowercase(),
+        None => "none".to_string(),
+    };
 
-            best_builds.push(new_build);
-            // }
-
-            return;
-        } else {
-            test_next_possibilities(
-                possible_commands,
-                &selected_commands,
-                game_params,
-                best_builds,
-            );
-        }
-    }
+    [
+        format!(
+            "winner: {} at {:.2}s",
+            winner,
+            result.time_ms as f64 / 1000.0
+        ),
+        format!(
+            "attacker: {:.0} / {:.0} HP, {:.0} damage dealt",
+            result.attacker_health, result.attacker_max_health, result.damage_dealt
+        ),
+        format!(
+            "target: {:.0} / {:.0} HP, {:.0} damage dealt",
+            result.target_health, result.target_max_health, result.damage_taken
+        ),
+        format!(
+            "commands: {}",
+            result
+                .selected_commands
+                .iter()
+                .map(|command| command.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        ),
+    ]
+    .join("\n")
 }
 
-fn is_better_build(best_build: &Option<Build>, damage: f64, time_ms: u64) -> bool {
-    if best_build.is_none() {
-        return true;
+fn format_evolutions(results: &[EvolutionOrderResult]) -> String {
+    let mut lines = vec![format!(
+        "{:<3}  {:<9}  {}",
+        "#",
+        "order",
+        results
+            .first()
+            .map(|result| {
+                result
+                    .points
+                    .iter()
+                    .map(|point| format!("{:<14}", format!("level {}", point.level)))
+                    .collect::<Vec<_>>()
+                    .join("  ")
+            })
+            .unwrap_or_default()
+    )
+    .trim_end()
+    .to_string()];
+
+    for (index, result) in results.iter().enumerate() {
+        let order = result
+            .order
+            .iter()
+            .map(|ability| ability.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        // kill time, or the damage dealt when the target survives
+        let points = result
+            .points
+            .iter()
+            .map(|point| {
+                let outcome = if point.kill {
+                    format!("{:.2}s", point.time_ms as f64 / 1000.0)
+                } else {
+                    format!("no kill ({:.0})", point.damage)
+                };
+                format!("{:<14}", outcome)
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
+
+        lines.push(
+            format!("{:<3}  {:<9}  {}", index + 1, order, points)
+                .trim_end()
+                .to_string(),
+        );
     }
 
-    let option = best_build.as_ref();
-
-    let build = option.unwrap();
-
-    time_ms < build.time_ms || (time_ms == build.time_ms && damage > build.damage)
+    lines.join("\n")
 }
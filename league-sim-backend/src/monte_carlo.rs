@@ -0,0 +1,198 @@
+use crate::{
+    data_input::common::{CritHandlingChoice, GameParams},
+    error::SimError,
+    rotation::CommandPolicy,
+    simulation,
+};
+
+// Runs the same fight many times, each iteration with its own seed, to get the
+// distribution of the kill time. Only useful when the simulation has random rolls
+// (e.g. crits rolled on each attack): otherwise every iteration gives the same result.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct MonteCarloSettings {
+    pub iterations: u64,
+    // kills happening after this time do not count towards kill_probability_within_budget
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_budget_ms: Option<u64>,
+    pub histogram_bucket_ms: u64,
+}
+
+impl Default for MonteCarloSettings {
+    fn default() -> Self {
+        MonteCarloSettings {
+            iterations: 1000,
+            time_budget_ms: None,
+            histogram_bucket_ms: 250,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MonteCarloResult {
+    pub iterations: u64,
+    pub kill_probability: f64,
+    pub kill_probability_within_budget: Option<f64>,
+    // the kill time statistics only take the iterations ending with a kill into account
+    pub mean_kill_time_ms: Option<f64>,
+    pub median_kill_time_ms: Option<u64>,
+    pub p10_kill_time_ms: Option<u64>,
+    pub p90_kill_time_ms: Option<u64>,
+    pub histogram: Vec<HistogramBucket>,
+}
+
+// number of kills with start_ms <= kill time < end_ms
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistogramBucket {
+    pub start_ms: u64,
+    pub end_ms: u64,
+    pub count: u64,
+}
+
+pub fn run<P: CommandPolicy + Clone>(
+    command_policy: &P,
+    game_params: &GameParams,
+    settings: &MonteCarloSettings,
+) -> Result<MonteCarloResult, SimError> {
+    if settings.iterations == 0 {
+        return Err(SimError::invalid_input(
+            "monteCarlo.iterations",
+            "at least one iteration is needed",
+        ));
+    }
+    if settings.histogram_bucket_ms == 0 {
+        return Err(SimError::invalid_input(
+            "monteCarlo.histogramBucketMs",
+            "must be positive",
+        ));
+    }
+
+    // iteration i uses seed + i, so that the whole run is reproducible from the input seed
+    let base_seed = match game_params.crit_handling {
+        CritHandlingChoice::Random { seed } => seed,
+        _ => 0,
+    };
+
+    let mut kill_times_ms: Vec<u64> = Vec::new();
+    for iteration in 0..settings.iterations {
+        let (_, _, _, _, _, time_ms, kill) = simulation::run_with_seed(
+            command_policy.clone(),
+            game_params,
+            base_seed.wrapping_add(iteration),
+        )?;
+
+        if kill {
+            kill_times_ms.push(time_ms);
+        }
+    }
+
+    Ok(summarize(kill_times_ms, settings))
+}
+
+fn summarize(mut kill_times_ms: Vec<u64>, settings: &MonteCarloSettings) -> MonteCarloResult {
+    kill_times_ms.sort();
+
+    let iterations = settings.iterations as f64;
+    let kills = kill_times_ms.len();
+
+    let kill_probability_within_budget = settings.time_budget_ms.map(|time_budget_ms| {
+        kill_times_ms
+            .iter()
+            .filter(|&&time_ms| time_ms <= time_budget_ms)
+            .count() as f64
+            / iterations
+    });
+
+    let mean_kill_time_ms =
+        (kills > 0).then(|| kill_times_ms.iter().sum::<u64>() as f64 / kills as f64);
+
+    MonteCarloResult {
+        iterations: settings.iterations,
+        kill_probability: kills as f64 / iterations,
+        kill_probability_within_budget,
+        mean_kill_time_ms,
+        median_kill_time_ms: percentile(&kill_times_ms, 50.0),
+        p10_kill_time_ms: percentile(&kill_times_ms, 10.0),
+        p90_kill_time_ms: percentile(&kill_times_ms, 90.0),
+        histogram: histogram(&kill_times_ms, settings.histogram_bucket_ms),
+    }
+}
+
+// nearest-rank percentile of sorted values
+fn percentile(sorted_values: &[u64], percentile: f64) -> Option<u64> {
+    if sorted_values.is_empty() {
+        return None;
+    }
+
+    let rank = (percentile / 100.0 * sorted_values.len() as f64).ceil() as usize;
+    Some(sorted_values[rank.clamp(1, sorted_values.len()) - 1])
+}
+
+// buckets go from the fastest to the slowest kill, empty buckets included
+fn histogram(sorted_values: &[u64], bucket_ms: u64) -> Vec<HistogramBucket> {
+    let (Some(first), Some(last)) = (sorted_values.first(), sorted_values.last()) else {
+        return Vec::new();
+    };
+
+    let first_bucket = first / bucket_ms;
+    let last_bucket = last / bucket_ms;
+    let mut buckets: Vec<HistogramBucket> = (first_bucket..=last_bucket)
+        .map(|bucket| HistogramBucket {
+            start_ms: bucket * bucket_ms,
+            end_ms: (bucket + 1) * bucket_ms,
+            count: 0,
+        })
+        .collect();
+
+    for value in sorted_values.iter() {
+        buckets[(value / bucket_ms - first_bucket) as usize].count += 1;
+    }
+
+    buckets
+}
+
+#[cfg(test)]
+mod monte_carlo_tests {
+    use super::*;
+
+    #[test]
+    fn summarizes_the_kill_times() {
+        let settings = MonteCarloSettings {
+            iterations: 12,
+            time_budget_ms: Some(2_000),
+            histogram_bucket_ms: 500,
+        };
+        // 10 kills out of 12 iterations
+        let kill_times_ms = vec![
+            2_400, 1_600, 1_800, 2_000, 1_900, 2_100, 1_700, 2_600, 1_900, 2_000,
+        ];
+
+        let result = summarize(kill_times_ms, &settings);
+
+        assert_eq!(result.kill_probability, 10.0 / 12.0);
+        assert_eq!(result.kill_probability_within_budget, Some(7.0 / 12.0));
+        assert_eq!(result.mean_kill_time_ms, Some(2_000.0));
+        assert_eq!(result.median_kill_time_ms, Some(1_900));
+        assert_eq!(result.p10_kill_time_ms, Some(1_600));
+        assert_eq!(result.p90_kill_time_ms, Some(2_400));
+        assert_eq!(
+            result
+                .histogram
+                .iter()
+                .map(|bucket| (bucket.start_ms, bucket.count))
+                .collect::<Vec<_>>(),
+            vec![(1_500, 5), (2_000, 4), (2_500, 1)]
+        );
+    }
+
+    #[test]
+    fn has_no_kill_time_statistics_without_kills() {
+        let result = summarize(Vec::new(), &MonteCarloSettings::default());
+
+        assert_eq!(result.kill_probability, 0.0);
+        assert_eq!(result.median_kill_time_ms, None);
+        assert!(result.histogram.is_empty());
+    }
+}
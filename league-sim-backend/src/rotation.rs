@@ -0,0 +1,281 @@
+use std::collections::VecDeque;
+
+use crate::{
+    attack::{is_item_equipped, AttackType},
+    data_input::common::{compute_target_stats, Aura, GameParams},
+    simulation::{next_earliest_time_possible_for, State},
+};
+
+// A command policy decides what the attacker does next. The simulation consults it
+// at the end of every cast and, while the attacker is idle, on every cooldown and
+// aura event.
+pub trait CommandPolicy {
+    // returns the next command without consuming it. None means "stay idle for now".
+    fn peek_command(&self, state: &State, game_params: &GameParams) -> Option<AttackType>;
+
+    // called once the command returned by peek_command has been scheduled
+    fn on_command_scheduled(&mut self, attack_type: AttackType);
+}
+
+// fixed sequence of commands, executed in order as soon as each one is available
+impl CommandPolicy for VecDeque<AttackType> {
+    fn peek_command(&self, _state: &State, _game_params: &GameParams) -> Option<AttackType> {
+        self.front().copied()
+    }
+
+    fn on_command_scheduled(&mut self, _attack_type: AttackType) {
+        self.pop_front();
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type")]
+pub enum Condition {
+    AttackerAuraActive { aura: Aura },
+    AttackerAuraInactive { aura: Aura },
+    TargetAuraActive { aura: Aura },
+    TargetAuraInactive { aura: Aura },
+    TargetHealthBelow { percentage: f64 },
+    TargetHealthAbove { percentage: f64 },
+}
+
+impl Condition {
+    fn is_met(&self, state: &State, game_params: &GameParams) -> bool {
+        match self {
+            Condition::AttackerAuraActive { aura } => state.attacker_auras.contains_key(aura),
+            Condition::AttackerAuraInactive { aura } => !state.attacker_auras.contains_key(aura),
+            Condition::TargetAuraActive { aura } => state.target_auras.contains_key(aura),
+            Condition::TargetAuraInactive { aura } => !state.target_auras.contains_key(aura),
+            Condition::TargetHealthBelow { percentage } => {
+                target_health_perc(state, game_params) < *percentage
+            }
+            Condition::TargetHealthAbove { percentage } => {
+                target_health_perc(state, game_params) > *percentage
+            }
+        }
+    }
+}
+
+fn target_health_perc(state: &State, game_params: &GameParams) -> f64 {
+    let target_stats = compute_target_stats(game_params, state);
+    target_stats.current_health / target_stats.max_health * 100.0
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct PriorityAction {
+    pub command: AttackType,
+    #[serde(default)]
+    pub conditions: Vec<Condition>,
+}
+
+// action priority list (SimulationCraft style): on each decision point, the first action
+// that is available right now and whose conditions are all met gets executed.
+// Being available (off cooldown or recast ready, with the item equipped for item actives)
+// is an implicit condition of every action.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct PriorityList {
+    pub actions: Vec<PriorityAction>,
+    // no new command is started past this point. Needed since a priority list
+    // ending with an auto attack never runs out of commands.
+    #[serde(rename = "maxDurationMs")]
+    pub max_duration_ms: u64,
+}
+
+impl CommandPolicy for PriorityList {
+    fn peek_command(&self, state: &State, game_params: &GameParams) -> Option<AttackType> {
+        if state.time_ms >= self.max_duration_ms {
+            return None;
+        }
+
+        self.actions
+            .iter()
+            .find(|action| {
+                next_earliest_time_possible_for(action.command, state, game_params) <= state.time_ms
+                    && action
+                        .command
+                        .item()
+                        .is_none_or(|item| is_item_equipped(item, game_params))
+                    && action
+                        .conditions
+                        .iter()
+                        .all(|condition| condition.is_met(state, game_params))
+            })
+            .map(|action| action.command)
+    }
+
+    fn on_command_scheduled(&mut self, _attack_type: AttackType) {}
+}
+
+#[cfg(test)]
+mod priority_list_tests {
+    use super::*;
+    use crate::area::Battlefield;
+    use crate::{
+        data_input::{
+            self,
+            common::{
+                compile_passive_effects, AuraApplication, Champion, CritHandlingChoice, TargetStats,
+            },
+            config::ScenarioConfig,
+            runes::Rune,
+        },
+        simulation,
+    };
+    use std::collections::HashSet;
+
+    fn priority_list(actions: Vec<PriorityAction>) -> PriorityList {
+        PriorityList {
+            actions,
+            max_duration_ms: 10_000,
+        }
+    }
+
+    #[test]
+    fn picks_first_available_action_whose_conditions_are_met() {
+        let config = ScenarioConfig::default();
+        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config).unwrap();
+        let runes: HashSet<Rune> = HashSet::new();
+
+        let mut game_params: GameParams<'_> = GameParams {
+            champion: Champion::Khazix,
+            champion_data: &static_data.champion_data,
+            champion_stats: &static_data.base_champion_stats,
+            level: 18,
+            items: &Vec::new(),
+            config: &config,
+            abilities: &static_data.abilities,
+            initial_target_stats: &TargetStats {
+                armor: 0.0,
+                armor_bonus: 0.0,
+                magic_resistance: 0.0,
+                magic_resistance_bonus: 0.0,
+                armor_reduction_flat: 0.0,
+                armor_reduction_perc: 0.0,
+                magic_resistance_reduction_flat: 0.0,
+                magic_resistance_reduction_perc: 0.0,
+                max_health: 100_000.0,
+                current_health: 100_000.0,
+            },
+            runes: &runes,
+            attacker_hp_perc: 100.0,
+            runes_data: &static_data.runes_data,
+            passive_effects: &mut Vec::new(),
+            crit_handling: CritHandlingChoice::Min,
+            initial_attacker_auras: &vec![AuraApplication {
+                aura: Aura::UnseenThreat,
+                stacks: None,
+                start_ms: 0,
+                end_ms: None,
+            }],
+            initial_target_auras: &Vec::new(),
+            abilities_extra_data: &static_data.abilities_extra_data,
+            start_time_ms: 0,
+            capture_event_history: false,
+            weave_auto_attacks: false,
+            battlefield: &Battlefield::default(),
+        };
+
+        compile_passive_effects(&mut game_params);
+
+        let policy = priority_list(vec![
+            PriorityAction {
+                command: AttackType::R,
+                conditions: vec![Condition::AttackerAuraInactive {
+                    aura: Aura::UnseenThreat,
+                }],
+            },
+            PriorityAction {
+                command: AttackType::E,
+                conditions: vec![Condition::TargetHealthBelow { percentage: 40.0 }],
+            },
+            PriorityAction {
+                command: AttackType::Q,
+                conditions: vec![],
+            },
+            PriorityAction {
+                command: AttackType::AA,
+                conditions: vec![],
+            },
+        ]);
+
+        let (_, _, _, _, attack_history, _, _) = simulation::run(policy, &game_params).unwrap();
+
+        // R is skipped as Unseen Threat is already up and E as the target is healthy
+        assert_eq!(attack_history[0], AttackType::Q);
+        // Q is then on cooldown, so the list falls through to an auto attack
+        assert_eq!(attack_history[1], AttackType::AA);
+        // which consumes Unseen Threat, making R the top priority
+        assert_eq!(attack_history[2], AttackType::R);
+        assert!(!attack_history.contains(&AttackType::E));
+    }
+
+    #[test]
+    fn waits_for_cooldowns_when_nothing_is_available() {
+        let config = ScenarioConfig::default();
+        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config).unwrap();
+        let runes: HashSet<Rune> = HashSet::new();
+
+        let mut game_params: GameParams<'_> = GameParams {
+            champion: Champion::Khazix,
+            champion_data: &static_data.champion_data,
+            champion_stats: &static_data.base_champion_stats,
+            level: 18,
+            items: &Vec::new(),
+            config: &config,
+            abilities: &static_data.abilities,
+            initial_target_stats: &TargetStats {
+                armor: 0.0,
+                armor_bonus: 0.0,
+                magic_resistance: 0.0,
+                magic_resistance_bonus: 0.0,
+                armor_reduction_flat: 0.0,
+                armor_reduction_perc: 0.0,
+                magic_resistance_reduction_flat: 0.0,
+                magic_resistance_reduction_perc: 0.0,
+                max_health: 100_000.0,
+                current_health: 100_000.0,
+            },
+            runes: &runes,
+            attacker_hp_perc: 100.0,
+            runes_data: &static_data.runes_data,
+            passive_effects: &mut Vec::new(),
+            crit_handling: CritHandlingChoice::Min,
+            initial_attacker_auras: &Vec::new(),
+            initial_target_auras: &Vec::new(),
+            abilities_extra_data: &static_data.abilities_extra_data,
+            start_time_ms: 0,
+            capture_event_history: false,
+            weave_auto_attacks: false,
+            battlefield: &Battlefield::default(),
+        };
+
+        compile_passive_effects(&mut game_params);
+
+        let policy = priority_list(vec![PriorityAction {
+            command: AttackType::Q,
+            conditions: vec![],
+        }]);
+
+        let (_, damage_history, _, _, attack_history, _, kill) =
+            simulation::run(policy, &game_params).unwrap();
+
+        assert!(!kill);
+        assert!(attack_history.len() > 1);
+        assert!(attack_history.iter().all(|&attack| attack == AttackType::Q));
+
+        // each Q is cast as soon as the previous one comes off cooldown
+        let q_ability = static_data
+            .abilities
+            .iter()
+            .find(|ability| ability.attack_type == AttackType::Q)
+            .unwrap();
+        let cast_time_ms = q_ability.cast_time_ms.unwrap_or_default();
+        let cooldown_ms = *q_ability.cooldown_ms.as_ref().unwrap().get(&5).unwrap();
+
+        assert_eq!(damage_history[0].time_ms, cast_time_ms);
+        assert_eq!(
+            damage_history[1].time_ms,
+            damage_history[0].time_ms + cooldown_ms + cast_time_ms
+        );
+    }
+}
@@ -0,0 +1,344 @@
+use std::path::Path;
+
+use serde_json::{json, Map, Value};
+
+use crate::{error::SimError, SimulationInputData};
+
+// Scenario files hold a SimulationInputData along with the version of its layout.
+// Version history:
+// - 1: `config` is a flat map of string flags (e.g. "CHAMPION_KHAZIX_Q_EVOLVED": "TRUE")
+// - 2: `config` is a ScenarioConfig (champion, items and runes sections)
+// - 3: `champion.khazix.isolatedTarget` is replaced by its opposite, `alliesNearby`: the
+//   battlefield decides the isolation unless allies stand near the target
+// - 4: `champion.champion` selects the attacker, which used to be Kha'Zix
+pub const CURRENT_SCHEMA_VERSION: u64 = 4;
+
+const SCHEMA_VERSION_KEY: &str = "schemaVersion";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScenarioFormat {
+    Json,
+    Toml,
+}
+
+impl ScenarioFormat {
+    // TOML for .toml files, JSON otherwise
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => ScenarioFormat::Toml,
+            _ => ScenarioFormat::Json,
+        }
+    }
+}
+
+// parses a scenario file, migrating it to the current schema version if needed
+pub fn load_scenario(
+    content: &str,
+    format: ScenarioFormat,
+) -> Result<SimulationInputData, SimError> {
+    let value: Value = match format {
+        ScenarioFormat::Json => serde_json::from_str(content)
+            .map_err(|err| SimError::invalid_input("", err.to_string()))?,
+        ScenarioFormat::Toml => toml::from_str(content)
+            .map_err(|err| SimError::invalid_input("", err.message().to_string()))?,
+    };
+
+    let value = migrate(value)?;
+
+    serde_path_to_error::deserialize(value)
+        .map_err(|err| SimError::invalid_input(&err.path().to_string(), err.inner().to_string()))
+}
+
+// writes a scenario file at the current schema version. The keys are sorted, so that
+// saving a loaded scenario gives back the same bytes.
+pub fn save_scenario(
+    input: &SimulationInputData,
+    format: ScenarioFormat,
+) -> Result<String, SimError> {
+    let mut value = serde_json::to_value(input).unwrap();
+    value.as_object_mut().unwrap().insert(
+        SCHEMA_VERSION_KEY.to_string(),
+        json!(CURRENT_SCHEMA_VERSION),
+    );
+
+    match format {
+        ScenarioFormat::Json => Ok(serde_json::to_string_pretty(&value).unwrap() + "\n"),
+        ScenarioFormat::Toml => {
+            toml::to_string(&value).map_err(|err| SimError::invalid_input("", err.to_string()))
+        }
+    }
+}
+
+fn migrate(mut value: Value) -> Result<Value, SimError> {
+    let scenario = value
+        .as_object_mut()
+        .ok_or_else(|| SimError::invalid_input("", "a scenario must be an object"))?;
+
+    let schema_version = match scenario.remove(SCHEMA_VERSION_KEY) {
+        Some(version) => version.as_u64().ok_or_else(|| {
+            SimError::invalid_input(SCHEMA_VERSION_KEY, "must be a positive integer")
+        })?,
+        None => return Err(SimError::invalid_input(SCHEMA_VERSION_KEY, "missing field")),
+    };
+
+    if schema_version == 0 || schema_version > CURRENT_SCHEMA_VERSION {
+        return Err(SimError::invalid_input(
+            SCHEMA_VERSION_KEY,
+            format!(
+                "unsupported version {} (latest is {})",
+                schema_version, CURRENT_SCHEMA_VERSION
+            ),
+        ));
+    }
+
+    if schema_version < 2 {
+        migrate_v1_to_v2(scenario)?;
+    }
+    if schema_version < 3 {
+        migrate_v2_to_v3(scenario)?;
+    }
+    if schema_version < 4 {
+        migrate_v3_to_v4(scenario)?;
+    }
+
+    Ok(value)
+}
+
+fn migrate_v1_to_v2(scenario: &mut Map<String, Value>) -> Result<(), SimError> {
+    let Some(flags) = scenario.remove("config") else {
+        return Ok(());
+    };
+    let flags = flags
+        .as_object()
+        .ok_or_else(|| SimError::invalid_input("config", "must be a map of flags"))?;
+
+    let mut config = json!({});
+    for (key, flag) in flags.iter() {
+        let path = format!("config.{}", key);
+        let flag = flag
+            .as_str()
+            .ok_or_else(|| SimError::invalid_input(&path, "flags must be strings"))?;
+
+        let (pointer, value) = match key.as_str() {
+            "CHAMPION_KHAZIX_ISOLATED_TARGET" => (
+                "/champion/khazix/isolatedTarget",
+                parse_bool_flag(&path, flag)?,
+            ),
+            "CHAMPION_KHAZIX_Q_EVOLVED" => {
+                ("/champion/khazix/qEvolved", parse_bool_flag(&path, flag)?)
+            }
+            "CHAMPION_KHAZIX_R_EVOLVED" => {
+                ("/champion/khazix/rEvolved", parse_bool_flag(&path, flag)?)
+            }
+            "ITEM_HUBRIS_EMINENCE_ACTIVE" => (
+                "/items/hubris/eminenceActive",
+                parse_bool_flag(&path, flag)?,
+            ),
+            "ITEM_HUBRIS_EMINENCE_STACKS" => (
+                "/items/hubris/eminenceStacks",
+                parse_number_flag(&path, flag)?,
+            ),
+            "ITEM_OPPORTUNITY_PREPARATION_READY" => (
+                "/items/opportunity/preparationReady",
+                parse_bool_flag(&path, flag)?,
+            ),
+            "RUNE_DARK_HARVEST_STACKS" => {
+                ("/runes/darkHarvest/stacks", parse_number_flag(&path, flag)?)
+            }
+            _ => return Err(SimError::invalid_input(&path, "unknown flag")),
+        };
+
+        insert_at(&mut config, pointer, value);
+    }
+
+    scenario.insert("config".to_string(), config);
+
+    Ok(())
+}
+
+// a target which was not flagged as isolated had allies nearby
+fn migrate_v2_to_v3(scenario: &mut Map<String, Value>) -> Result<(), SimError> {
+    let config = scenario
+        .entry("config".to_string())
+        .or_insert_with(|| json!({}));
+
+    let isolated_target = match config.pointer_mut("/champion/khazix") {
+        Some(khazix) => {
+            let khazix = khazix.as_object_mut().ok_or_else(|| {
+                SimError::invalid_input("config.champion.khazix", "must be an object")
+            })?;
+            match khazix.remove("isolatedTarget") {
+                None => false,
+                Some(flag) => flag.as_bool().ok_or_else(|| {
+                    SimError::invalid_input(
+                        "config.champion.khazix.isolatedTarget",
+                        "must be a boolean",
+                    )
+                })?,
+            }
+        }
+        None => false,
+    };
+
+    if !isolated_target {
+        insert_at(config, "/champion/khazix/alliesNearby", json!(true));
+    }
+
+    Ok(())
+}
+
+// kha'zix was the only attacker
+fn migrate_v3_to_v4(scenario: &mut Map<String, Value>) -> Result<(), SimError> {
+    let champion = scenario
+        .entry("champion".to_string())
+        .or_insert_with(|| json!({}))
+        .as_object_mut()
+        .ok_or_else(|| SimError::invalid_input("champion", "must be an object"))?;
+
+    champion
+        .entry("champion".to_string())
+        .or_insert_with(|| json!("Khazix"));
+
+    Ok(())
+}
+
+fn parse_bool_flag(path: &str, flag: &str) -> Result<Value, SimError> {
+    match flag {
+        "TRUE" => Ok(Value::Bool(true)),
+        "FALSE" => Ok(Value::Bool(false)),
+        _ => Err(SimError::invalid_input(path, "expected TRUE or FALSE")),
+    }
+}
+
+fn parse_number_flag(path: &str, flag: &str) -> Result<Value, SimError> {
+    flag.parse::<u64>()
+        .map(|number| json!(number))
+        .map_err(|err| SimError::invalid_input(path, err.to_string()))
+}
+
+// inserts the value at the given JSON pointer, creating the intermediate objects
+fn insert_at(target: &mut Value, pointer: &str, value: Value) {
+    let mut current = target;
+    let keys: Vec<&str> = pointer.trim_start_matches('/').split('/').collect();
+    for key in keys[..keys.len() - 1].iter() {
+        current = current
+            .as_object_mut()
+            .unwrap()
+            .entry(key.to_string())
+            .or_insert_with(|| json!({}));
+    }
+
+    current
+        .as_object_mut()
+        .unwrap()
+        .insert(keys[keys.len() - 1].to_string(), value);
+}
+
+#[cfg(test)]
+mod scenario_tests {
+    use super::*;
+
+    const SCENARIO: &str = include_str!("../scenarios/khazix_lethality.json");
+
+    #[test]
+    fn round_trips_byte_for_byte() {
+        for format in [ScenarioFormat::Json, ScenarioFormat::Toml] {
+            let input = load_scenario(SCENARIO, ScenarioFormat::Json).unwrap();
+            let saved = save_scenario(&input, format).unwrap();
+            let reloaded = load_scenario(&saved, format).unwrap();
+
+            assert_eq!(reloaded, input);
+            assert_eq!(save_scenario(&reloaded, format).unwrap(), saved);
+        }
+    }
+
+    #[test]
+    fn migrates_v1_string_flags() {
+        let mut v1: Value = serde_json::from_str(SCENARIO).unwrap();
+        v1["schemaVersion"] = json!(1);
+        v1["config"] = json!({
+            "CHAMPION_KHAZIX_ISOLATED_TARGET": "TRUE",
+            "CHAMPION_KHAZIX_Q_EVOLVED": "TRUE",
+            "CHAMPION_KHAZIX_R_EVOLVED": "FALSE",
+            "RUNE_DARK_HARVEST_STACKS": "10",
+        });
+
+        let migrated = load_scenario(&v1.to_string(), ScenarioFormat::Json).unwrap();
+
+        assert_eq!(
+            migrated,
+            load_scenario(SCENARIO, ScenarioFormat::Json).unwrap()
+        );
+    }
+
+    #[test]
+    fn migrates_the_v2_isolated_target_flag() {
+        let mut v2: Value = serde_json::from_str(SCENARIO).unwrap();
+        v2["schemaVersion"] = json!(2);
+        v2["config"]["champion"]["khazix"]["isolatedTarget"] = json!(true);
+
+        let migrated = load_scenario(&v2.to_string(), ScenarioFormat::Json).unwrap();
+        assert_eq!(
+            migrated,
+            load_scenario(SCENARIO, ScenarioFormat::Json).unwrap()
+        );
+
+        // the target was not isolated by default
+        v2.as_object_mut().unwrap().remove("config");
+        let migrated = load_scenario(&v2.to_string(), ScenarioFormat::Json).unwrap();
+        let saved: Value =
+            serde_json::from_str(&save_scenario(&migrated, ScenarioFormat::Json).unwrap()).unwrap();
+        assert_eq!(
+            saved["config"]["champion"]["khazix"]["alliesNearby"],
+            json!(true)
+        );
+    }
+
+    #[test]
+    fn migrates_the_v3_attacker_to_khazix() {
+        let mut v3: Value = serde_json::from_str(SCENARIO).unwrap();
+        v3["schemaVersion"] = json!(3);
+        v3["champion"].as_object_mut().unwrap().remove("champion");
+
+        assert_eq!(
+            load_scenario(&v3.to_string(), ScenarioFormat::Json).unwrap(),
+            load_scenario(SCENARIO, ScenarioFormat::Json).unwrap()
+        );
+    }
+
+    #[test]
+    fn rejects_an_attacker_without_a_kit() {
+        let mut scenario: Value = serde_json::from_str(SCENARIO).unwrap();
+        scenario["champion"]["champion"] = json!("Jinx");
+
+        assert!(matches!(
+            load_scenario(&scenario.to_string(), ScenarioFormat::Json),
+            Err(SimError::InvalidInput { path, .. }) if path == "champion.champion"
+        ));
+    }
+
+    #[test]
+    fn rejects_unknown_v1_flags() {
+        let mut v1: Value = serde_json::from_str(SCENARIO).unwrap();
+        v1["schemaVersion"] = json!(1);
+        v1["config"] = json!({ "CHAMPION_KHAZIX_W_EVOLVED": "TRUE" });
+
+        assert_eq!(
+            load_scenario(&v1.to_string(), ScenarioFormat::Json),
+            Err(SimError::invalid_input(
+                "config.CHAMPION_KHAZIX_W_EVOLVED",
+                "unknown flag"
+            ))
+        );
+    }
+
+    #[test]
+    fn requires_a_supported_schema_version() {
+        let mut scenario: Value = serde_json::from_str(SCENARIO).unwrap();
+        scenario.as_object_mut().unwrap().remove("schemaVersion");
+        assert!(load_scenario(&scenario.to_string(), ScenarioFormat::Json).is_err());
+
+        scenario["schemaVersion"] = json!(CURRENT_SCHEMA_VERSION + 1);
+        assert!(load_scenario(&scenario.to_string(), ScenarioFormat::Json).is_err());
+    }
+}
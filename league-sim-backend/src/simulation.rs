@@ -1,22 +1,32 @@
 use std::{
     cmp::Ordering,
-    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
-    fmt, u64,
+    collections::{BinaryHeap, HashMap, HashSet},
 };
 
-use itertools::Itertools;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
 
 use crate::{
-    attack::{cast_time, simulate_spell, AttackType, SpellCategory, SpellResult},
+    area::{self, Position},
+    attack::{
+        cast_time, compute_mitigated_damage, simulate_spell, AttackType, SpellCategory, SpellResult,
+    },
     data_input::{
         abilities::{find_ability, SpellData},
+        champions::ResourceType,
         common::{
-            compute_attacker_stats, compute_target_stats, AttackerStats, Aura, AuraApplication,
-            Champion, DamageType, GameParams, PassiveEffect, Unit,
+            attacker_max_health, compute_attacker_defensive_stats, compute_attacker_stats,
+            compute_target_stats, rank_at_level, AttackerStats, Aura, AuraApplication, Champion,
+            CritHandlingChoice, DamageType, GameParams, PassiveEffect, Unit,
         },
-        items::Item,
+        config::ScenarioConfig,
+        items::{self, Item},
         runes::Rune,
+        summoner_spells::IGNITE,
     },
+    duel::TargetPolicy,
+    error::SimError,
+    rotation::CommandPolicy,
 };
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug, serde::Serialize)]
@@ -30,6 +40,16 @@ pub enum EventCategory {
     AuraTargetEnd,
     CooldownEnded,
     PassiveTriggered,
+    // a tick of Ignite's damage over time
+    IgniteTick,
+    // a tick of Death's Dance's heal over time (Defy)
+    DefyTick,
+    // enough mana (or energy) has been regenerated to cast an ability
+    ResourceRegenerated,
+    // the target fights back (see duel::run)
+    TargetCastStart,
+    TargetCastEnd,
+    AttackerDied,
     TargetDied,
 }
 
@@ -59,6 +79,8 @@ pub enum DamageSource {
     Rune,
     ItemPassive,
     ItemActive,
+    // basic attack or spell of a target fighting back
+    TargetCommand,
 }
 
 #[derive(Clone, Debug, serde::Serialize)]
@@ -70,23 +92,77 @@ pub struct DamageInfo {
     pub source_ability: Option<AttackType>,
     pub source_rune: Option<Rune>,
     pub source_item: Option<Item>,
+    // the attacker, unless the target fights back (see duel::run)
+    pub source_unit: Unit,
+    pub target_unit: Unit,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+pub enum HealSource {
+    LifeSteal,
+    Omnivamp,
+    Ability,
+    ItemPassive,
+}
+
+// healing or shield received by the attacker
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct HealInfo {
+    // for a heal, only the health actually restored (the overheal is left out)
+    pub amount: f64,
+    pub is_shield: bool,
+    pub time_ms: u64,
+    pub source: HealSource,
+    pub source_ability: Option<AttackType>,
+    pub source_item: Option<Item>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SecondaryTargetState {
+    pub damage_taken: f64,
+    pub auras: HashMap<Aura, AuraApplication>,
+    // the takedown callbacks have been called
+    pub dead: bool,
 }
 
 pub struct State<'a> {
     pub total_damage: f64,
     pub damage_history: &'a mut Vec<DamageInfo>,
+    pub heal_history: &'a mut Vec<HealInfo>,
+    // same order as the secondary targets of the battlefield
+    pub secondary_targets: &'a mut Vec<SecondaryTargetState>,
+    // unit hit by the commands of the attacker. A secondary target once the primary target
+    // is dead and the fight continues (see Battlefield::continue_after_kill)
+    pub target: Unit,
+    // the attacker and the primary target move during the fight (see area::on_movement)
+    pub attacker_position: Position,
+    pub target_position: Position,
     pub event_history: &'a mut Vec<Event>,
     pub attack_history: &'a mut Vec<AttackType>,
     pub time_ms: u64,
     pub cooldowns: &'a mut HashMap<AttackType, u64>,
     pub effects_cooldowns: &'a mut HashMap<PassiveEffect, u64>,
     pub last_attack_time_ms: u64,
-    pub config: &'a mut HashMap<String, String>,
+    // death of the primary target. The fight may go on afterwards against the secondary
+    // targets (see Battlefield::continue_after_kill)
+    pub kill_time_ms: Option<u64>,
+    // scenario config, updated as the fight goes on (e.g. Dark Harvest souls)
+    pub config: &'a mut ScenarioConfig,
     pub attacker_auras: &'a mut HashMap<Aura, AuraApplication>,
     pub target_auras: &'a mut HashMap<Aura, AuraApplication>,
     pub recast_charges: &'a mut Vec<AttackType>,
     pub recast_ready: &'a mut HashSet<AttackType>,
+    // spell -> times at which its spent charges come back, see SpellCharges
+    pub recharges: &'a mut HashMap<AttackType, Vec<u64>>,
+    // current mana (or energy) of the attacker
+    pub mana: f64,
+    pub attacker_health: f64,
     pub is_casting: bool,
+    // true when no command is scheduled because the command policy had nothing to
+    // execute. The policy is consulted again on the next cooldown or aura event.
+    pub is_idle: bool,
+    // source of the random rolls (e.g. crits), seeded for reproducibility
+    pub rng: &'a mut ChaCha8Rng,
 }
 
 impl State<'_> {
@@ -104,6 +180,13 @@ impl State<'_> {
 
         self.cooldowns.retain(|_, end_at| *end_at > current_time);
 
+        // the auras of the secondary targets have no callback
+        for secondary_target in self.secondary_targets.iter_mut() {
+            secondary_target
+                .auras
+                .retain(|_, aura_app| aura_app.end_ms.is_none_or(|x| x > current_time));
+        }
+
         // self.attacker_auras needs to be emptied in a way
         // that callbacks are called repeadly as long as at least one aura
         // has been removed
@@ -145,23 +228,19 @@ impl State<'_> {
         stacks: Option<u64>,
         events: &mut BinaryHeap<Event>,
     ) {
-        let end_time = if duration.is_none() {
-            None
-        } else {
-            Some(self.time_ms + duration.unwrap())
-        };
+        let end_time = duration.map(|duration| self.time_ms + duration);
 
-        insert_aura_attacker_start_event(events, self.time_ms, aura.clone());
-        if end_time.is_some() {
-            insert_aura_attacker_end_event(events, end_time.unwrap(), aura.clone());
+        insert_aura_attacker_start_event(events, self.time_ms, aura);
+        if let Some(end_time) = end_time {
+            insert_aura_attacker_end_event(events, end_time, aura);
         }
         self.attacker_auras.insert(
             aura,
             AuraApplication {
-                aura: aura,
+                aura,
                 start_ms: self.time_ms,
                 end_ms: end_time,
-                stacks: stacks,
+                stacks,
             },
         );
         aura.on_start(self, Unit::Attacker);
@@ -176,11 +255,11 @@ impl State<'_> {
     ) {
         // remove the default 'aura_attacker_end' event (added in add_attacker_aura)
         events.retain(|event| {
-            event.category != EventCategory::AuraAttackerEnd || event.aura != Some(aura.clone())
+            event.category != EventCategory::AuraAttackerEnd || event.aura != Some(*aura)
         });
 
         // then proceed
-        insert_aura_attacker_end_event(events, self.time_ms, aura.clone());
+        insert_aura_attacker_end_event(events, self.time_ms, *aura);
         aura.on_end(self, game_params, event, events, Unit::Attacker, true);
         self.attacker_auras.remove(aura);
     }
@@ -203,23 +282,19 @@ impl State<'_> {
         stacks: Option<u64>,
         events: &mut BinaryHeap<Event>,
     ) {
-        let end_time = if duration.is_none() {
-            None
-        } else {
-            Some(self.time_ms + duration.unwrap())
-        };
+        let end_time = duration.map(|duration| self.time_ms + duration);
 
-        insert_aura_target_start_event(events, self.time_ms, aura.clone());
-        if end_time.is_some() {
-            insert_aura_target_end_event(events, end_time.unwrap(), aura.clone());
+        insert_aura_target_start_event(events, self.time_ms, aura);
+        if let Some(end_time) = end_time {
+            insert_aura_target_end_event(events, end_time, aura);
         }
         self.target_auras.insert(
             aura,
             AuraApplication {
-                aura: aura,
+                aura,
                 start_ms: self.time_ms,
                 end_ms: end_time,
-                stacks: stacks,
+                stacks,
             },
         );
         aura.on_start(self, Unit::Target);
@@ -234,11 +309,11 @@ impl State<'_> {
     ) {
         // remove the default 'aura_target_end' event (added in add_target_aura)
         events.retain(|event| {
-            event.category != EventCategory::AuraTargetEnd || event.aura != Some(aura.clone())
+            event.category != EventCategory::AuraTargetEnd || event.aura != Some(*aura)
         });
 
         // then proceed
-        insert_aura_target_end_event(events, self.time_ms, aura.clone());
+        insert_aura_target_end_event(events, self.time_ms, *aura);
         aura.on_end(self, game_params, event, events, Unit::Target, true);
         self.target_auras.remove(aura);
     }
@@ -255,10 +330,67 @@ impl State<'_> {
     }
 }
 
+// (total damage, damage history, heal history, event history, attack history, end time, kill)
+pub type SimulationResult = (
+    f64,
+    Vec<DamageInfo>,
+    Vec<HealInfo>,
+    Vec<Event>,
+    Vec<AttackType>,
+    u64,
+    bool,
+);
+
 pub fn run(
-    mut selected_commands: VecDeque<AttackType>,
+    command_policy: impl CommandPolicy,
+    game_params: &GameParams,
+) -> Result<SimulationResult, SimError> {
+    run_with_seed(command_policy, game_params, crit_seed(game_params))
+}
+
+// same as run, with the random rolls drawn from the given seed
+pub fn run_with_seed(
+    command_policy: impl CommandPolicy,
+    game_params: &GameParams,
+    seed: u64,
+) -> Result<SimulationResult, SimError> {
+    run_fight(
+        command_policy,
+        &mut TargetPolicy::passive(),
+        game_params,
+        seed,
+    )
+    .map(|(result, _)| result)
+}
+
+// same as run, with the target executing its own commands against the attacker. The health
+// of the attacker at the end of the fight is returned too
+pub fn run_duel(
+    command_policy: impl CommandPolicy,
+    target_policy: &mut TargetPolicy,
+    game_params: &GameParams,
+) -> Result<(SimulationResult, f64), SimError> {
+    run_fight(
+        command_policy,
+        target_policy,
+        game_params,
+        crit_seed(game_params),
+    )
+}
+
+fn crit_seed(game_params: &GameParams) -> u64 {
+    match game_params.crit_handling {
+        CritHandlingChoice::Random { seed } => seed,
+        _ => 0,
+    }
+}
+
+fn run_fight(
+    mut command_policy: impl CommandPolicy,
+    target_policy: &mut TargetPolicy,
     game_params: &GameParams,
-) -> (f64, Vec<DamageInfo>, Vec<Event>, Vec<AttackType>, u64, bool) {
+    seed: u64,
+) -> Result<(SimulationResult, f64), SimError> {
     // use a priority queue to manage the events
     let mut events: BinaryHeap<Event> = BinaryHeap::new();
 
@@ -267,25 +399,52 @@ pub fn run(
         time_ms: 0,
         cooldowns: &mut HashMap::new(),
         last_attack_time_ms: 0,
+        kill_time_ms: None,
         effects_cooldowns: &mut HashMap::new(),
-        config: &mut HashMap::new(),
+        config: &mut game_params.config.clone(),
         attacker_auras: &mut HashMap::new(),
         target_auras: &mut HashMap::new(),
         damage_history: &mut Vec::new(),
+        heal_history: &mut Vec::new(),
+        secondary_targets: &mut vec![
+            SecondaryTargetState::default();
+            game_params.battlefield.secondary_targets.len()
+        ],
+        target: Unit::Target,
+        attacker_position: game_params.battlefield.attacker_position,
+        target_position: Position::default(),
         event_history: &mut Vec::new(),
         attack_history: &mut Vec::new(),
         recast_charges: &mut Vec::new(),
         recast_ready: &mut HashSet::new(),
+        recharges: &mut HashMap::new(),
+        mana: 0.0,
+        attacker_health: attacker_max_health(game_params) * game_params.attacker_hp_perc / 100.0,
         is_casting: false,
+        is_idle: false,
+        rng: &mut ChaCha8Rng::seed_from_u64(seed),
     };
 
-    // add first attack event
-    insert_next_attack_event(&mut events, &mut selected_commands, &mut state, game_params);
-
+    // the initial auras need to be there before the command policy is consulted
     add_initial_auras(game_params, &mut state, &mut events);
 
+    // the fight starts with a full resource bar
+    state.mana = compute_attacker_stats(game_params, &state).mana;
+
+    // add first attack event
+    insert_next_attack_event(&mut events, &mut command_policy, &mut state, game_params);
+    insert_target_cast_start_event(&mut events, target_policy, state.time_ms);
+
     // and launch
-    return execute_commands(&mut events, &mut selected_commands, &mut state, game_params);
+    let result = execute_commands(
+        &mut events,
+        &mut command_policy,
+        target_policy,
+        &mut state,
+        game_params,
+    )?;
+
+    Ok((result, state.attacker_health))
 }
 
 fn add_initial_auras(
@@ -293,11 +452,7 @@ fn add_initial_auras(
     state: &mut State<'_>,
     events: &mut BinaryHeap<Event>,
 ) {
-    if game_params
-        .initial_config
-        .get("ITEM_OPPORTUNITY_PREPARATION_READY")
-        .unwrap_or(&"TRUE".to_string())
-        == "TRUE"
+    if game_params.config.items.opportunity.preparation_ready
         && game_params
             .items
             .iter()
@@ -307,22 +462,13 @@ fn add_initial_auras(
         state.add_attacker_aura(Aura::Preparation, Some(3_000), None, events);
     }
 
-    if game_params
-        .initial_config
-        .get("ITEM_HUBRIS_EMINENCE_ACTIVE")
-        .unwrap_or(&"TRUE".to_string())
-        == "TRUE"
+    if game_params.config.items.hubris.eminence_active
         && game_params
             .items
             .iter()
             .any(|item_data| item_data.item == Item::Hubris)
     {
-        let stacks = game_params
-            .initial_config
-            .get("ITEM_HUBRIS_EMINENCE_STACKS")
-            .unwrap_or(&"17".to_string())
-            .parse::<u64>()
-            .unwrap();
+        let stacks = game_params.config.items.hubris.eminence_stacks;
         // println!("Adding Hubris aura with {} stacks", stacks);
         state.add_attacker_aura(Aura::HubrisEminence, Some(90_000), Some(stacks), events);
     }
@@ -338,51 +484,78 @@ fn add_initial_auras(
 
 fn execute_commands(
     events: &mut BinaryHeap<Event>,
-    remaining_commands: &mut VecDeque<AttackType>,
+    command_policy: &mut dyn CommandPolicy,
+    target_policy: &mut TargetPolicy,
     state: &mut State,
     game_params: &GameParams,
-) -> (f64, Vec<DamageInfo>, Vec<Event>, Vec<AttackType>, u64, bool) {
+) -> Result<SimulationResult, SimError> {
     loop {
         match events.pop() {
-            None => {
-                return (
-                    state.total_damage.clone(),
-                    state.damage_history.clone(),
-                    state.event_history.clone(),
-                    state.attack_history.clone(),
-                    state.last_attack_time_ms,
-                    false,
-                )
-            }
+            None => return Ok(simulation_result(state, game_params)),
             Some(next_event) => {
                 if game_params.capture_event_history {
                     state.event_history.push(next_event.clone());
                 }
 
                 if next_event.category == EventCategory::TargetDied {
-                    return (
-                        state.total_damage.clone(),
-                        state.damage_history.clone(),
-                        state.event_history.clone(),
-                        state.attack_history.clone(),
-                        state.last_attack_time_ms,
-                        true,
-                    );
+                    if state.target == Unit::Target {
+                        state.kill_time_ms = Some(next_event.time_ms);
+                    }
+                    on_takedown(state.target, state, game_params, &next_event, events);
+
+                    // a duel ends with the death of the target
+                    if target_policy.fights_back()
+                        || !switch_to_next_target(state, game_params, command_policy, events)
+                    {
+                        return Ok(simulation_result(state, game_params));
+                    }
+                    continue;
+                }
+
+                if next_event.category == EventCategory::AttackerDied {
+                    return Ok(simulation_result(state, game_params));
                 }
 
-                on_event(&next_event, events, remaining_commands, game_params, state)
+                on_event(
+                    &next_event,
+                    events,
+                    command_policy,
+                    target_policy,
+                    game_params,
+                    state,
+                )?;
+
+                on_secondary_target_takedowns(state, game_params, &next_event, events);
             }
         }
     }
 }
 
+// the kill (and its time) is the one of the primary target, even when the fight went on
+// afterwards
+fn simulation_result(state: &State, game_params: &GameParams) -> SimulationResult {
+    let kill = state.total_damage >= game_params.initial_target_stats.current_health;
+    let time_ms = state.kill_time_ms.unwrap_or(state.last_attack_time_ms);
+
+    (
+        state.total_damage,
+        state.damage_history.clone(),
+        state.heal_history.clone(),
+        state.event_history.clone(),
+        state.attack_history.clone(),
+        time_ms,
+        kill,
+    )
+}
+
 fn on_event(
     event: &Event,
     events: &mut BinaryHeap<Event>,
-    remaining_commands: &mut VecDeque<AttackType>,
+    command_policy: &mut dyn CommandPolicy,
+    target_policy: &mut TargetPolicy,
     game_params: &GameParams,
     state: &mut State,
-) {
+) -> Result<(), SimError> {
     // if state.total_damage >= game_params.initial_target_stats.current_health {
     //     return;
     // }
@@ -397,22 +570,34 @@ fn on_event(
         let time_passed = event.time_ms - state.time_ms;
 
         state.time_ms = event.time_ms;
-        state.refresh_cds_and_auras(game_params, event, events);
+        // the units moved with the auras they had during the time passed
         on_time_passed(event, events, game_params, state, time_passed);
+        state.refresh_cds_and_auras(game_params, event, events);
     }
 
     match event.category {
         EventCategory::AttackCastStart => {
+            // out of range, the attacker walks to its target before casting
+            let walk_time_ms = walk_time_ms(event.attack_type.unwrap(), state, game_params)?;
+            if walk_time_ms > 0 {
+                events.push(Event {
+                    time_ms: state.time_ms + walk_time_ms,
+                    ..event.clone()
+                });
+                return Ok(());
+            }
+
             state.is_casting = true;
 
-            ensure_spell_off_cooldown(event.attack_type.unwrap(), game_params, state);
+            ensure_spell_off_cooldown(event.attack_type.unwrap(), state)?;
+            pay_resource_cost(event.attack_type.unwrap(), state, game_params)?;
             trigger_stealth_exit_if_applicable(event, events, game_params, state);
             let attacker_stats: AttackerStats = compute_attacker_stats(game_params, state);
 
             let cast_time = cast_time(
                 &attacker_stats,
                 event.attack_type.unwrap(),
-                game_params.initial_config,
+                game_params.config,
                 game_params.abilities,
             );
             // println!("cooldown: {:#?}", cooldown);
@@ -429,15 +614,22 @@ fn on_event(
             let attacker_stats: AttackerStats = compute_attacker_stats(game_params, state);
             let target_stats = compute_target_stats(game_params, state);
 
+            let attack_type = event.attack_type.unwrap();
             on_pre_damage_events(
                 &(DamageInfo {
                     amount: 0.0,
                     damage_type: DamageType::Unknown,
                     time_ms: state.time_ms,
-                    source: DamageSource::Ability,
-                    source_ability: Some(event.attack_type.unwrap()),
+                    source: if attack_type.item().is_some() {
+                        DamageSource::ItemActive
+                    } else {
+                        DamageSource::Ability
+                    },
+                    source_ability: attack_type.item().is_none().then_some(attack_type),
                     source_rune: None,
-                    source_item: None,
+                    source_item: attack_type.item(),
+                    source_unit: Unit::Attacker,
+                    target_unit: state.target,
                 }),
                 &attacker_stats,
                 state,
@@ -454,12 +646,12 @@ fn on_event(
                 event.attack_type.unwrap(),
                 event,
                 events,
-            );
+            )?;
 
             // println!("spell_result: {:#?}", spell_result);
-            if spell_result.damage.is_some() {
+            if let Some(damage) = spell_result.damage {
                 let damage_info = on_damage_from_ability(
-                    &spell_result.damage.unwrap(),
+                    &damage,
                     spell_result.damage_type.unwrap(),
                     state,
                     event.attack_type.unwrap(),
@@ -474,37 +666,242 @@ fn on_event(
                 );
             }
 
-            let target_stats = compute_target_stats(game_params, state);
-            if target_stats.current_health <= 0.0 {
-                let event = Event {
-                    attack_type: None,
-                    category: EventCategory::TargetDied,
-                    time_ms: state.time_ms,
-                    passive_effect: None,
-                    aura: None,
-                };
-
-                events.push(event);
-                return;
-            }
-
-            if spell_result.cooldown.is_some() {
-                let cooldown_end_ms = spell_result.cooldown.unwrap() + state.time_ms;
+            if let Some(cooldown) = spell_result.cooldown {
+                let cooldown_end_ms = cooldown_end_ms(attack_type, cooldown, state, game_params);
                 insert_cooldown_ended_event(events, event, cooldown_end_ms);
                 add_cooldown_to_state(state, event.attack_type.unwrap(), cooldown_end_ms);
             }
-            insert_next_attack_event(events, remaining_commands, state, game_params);
+
+            // the next command is decided once the takedown has been handled
+            if insert_target_died_event_if_dead(events, state, game_params) {
+                return Ok(());
+            }
+
+            insert_next_attack_event(events, command_policy, state, game_params);
         }
         // EventCategory::CooldownEnded => on_cooldown_ended(event),
         // EventCategory::PassiveTriggered => on_passive_triggered(event),
-        EventCategory::CooldownEnded => (),
+        EventCategory::CooldownEnded
+        | EventCategory::ResourceRegenerated
+        | EventCategory::AuraAttackerStart
+        | EventCategory::AuraAttackerEnd
+        | EventCategory::AuraTargetStart
+        | EventCategory::AuraTargetEnd => {
+            // something the command policy may depend on has changed
+            if state.is_idle {
+                insert_next_attack_event(events, command_policy, state, game_params);
+            }
+        }
+        EventCategory::IgniteTick => {
+            IGNITE.on_tick(state, game_params);
+
+            if insert_target_died_event_if_dead(events, state, game_params) {
+                return Ok(());
+            }
+
+            // the health of the target has changed
+            if state.is_idle {
+                insert_next_attack_event(events, command_policy, state, game_params);
+            }
+        }
+        EventCategory::DefyTick => items::on_defy_tick(state, game_params),
+        EventCategory::TargetCastStart => {
+            if let Some(cast_time) = target_policy.start_cast(state.time_ms) {
+                insert_target_cast_end_event(events, state.time_ms + cast_time);
+            }
+        }
+        EventCategory::TargetCastEnd => {
+            if let Some((damage, damage_type)) = target_policy.end_cast(state.time_ms) {
+                on_damage_to_attacker(damage, damage_type, state, game_params, event, events);
+
+                if insert_attacker_died_event_if_dead(events, state) {
+                    return Ok(());
+                }
+            }
+
+            insert_target_cast_start_event(events, target_policy, state.time_ms);
+        }
         EventCategory::PassiveTriggered => (),
-        EventCategory::AuraAttackerStart => (),
-        EventCategory::AuraAttackerEnd => (),
-        EventCategory::AuraTargetStart => (),
-        EventCategory::AuraTargetEnd => (),
+        EventCategory::AttackerDied => (),
         EventCategory::TargetDied => (),
     }
+
+    Ok(())
+}
+
+// the attacker scored the takedown of the unit
+fn on_takedown(
+    unit: Unit,
+    state: &mut State,
+    game_params: &GameParams,
+    event: &Event,
+    events: &mut BinaryHeap<Event>,
+) {
+    if let Unit::SecondaryTarget(index) = unit {
+        if state.secondary_targets[index].dead {
+            return;
+        }
+        state.secondary_targets[index].dead = true;
+    }
+
+    for effect in game_params.passive_effects.iter() {
+        effect.handle_on_takedown(unit, state, game_params, event, events);
+    }
+
+    for active_effect in game_params
+        .abilities
+        .iter()
+        .filter_map(|ability| ability.active_effect)
+    {
+        active_effect.on_takedown(unit, state, game_params, event, events);
+    }
+}
+
+// the secondary targets killed by the area damage (the current target of the attacker
+// is handled by the TargetDied event)
+fn on_secondary_target_takedowns(
+    state: &mut State,
+    game_params: &GameParams,
+    event: &Event,
+    events: &mut BinaryHeap<Event>,
+) {
+    for index in 0..state.secondary_targets.len() {
+        if !state.secondary_targets[index].dead
+            && area::secondary_target_stats(index, state, game_params).current_health <= 0.0
+        {
+            on_takedown(
+                Unit::SecondaryTarget(index),
+                state,
+                game_params,
+                event,
+                events,
+            );
+        }
+    }
+}
+
+// once the target is dead, the attacker goes on with the closest secondary target still
+// alive. Returns false when the fight is over
+fn switch_to_next_target(
+    state: &mut State,
+    game_params: &GameParams,
+    command_policy: &mut dyn CommandPolicy,
+    events: &mut BinaryHeap<Event>,
+) -> bool {
+    if !game_params.battlefield.continue_after_kill {
+        return false;
+    }
+    let Some(index) = area::next_target(state, game_params) else {
+        return false;
+    };
+
+    // the debuffs and the periodic damage of the dead target are gone, the new target
+    // keeps its own debuffs
+    events.retain(|event| {
+        !matches!(
+            event.category,
+            EventCategory::AuraTargetEnd | EventCategory::IgniteTick
+        )
+    });
+    *state.target_auras = std::mem::take(&mut state.secondary_targets[index].auras);
+    state.target = Unit::SecondaryTarget(index);
+
+    // the attacker decides what to do next, unless a command is already under way
+    let command_pending = events.iter().any(|event| {
+        matches!(
+            event.category,
+            EventCategory::AttackCastStart | EventCategory::AttackCastEnd
+        )
+    });
+    if !command_pending {
+        insert_next_attack_event(events, command_policy, state, game_params);
+    }
+
+    true
+}
+
+// the ability can be cast again right away (e.g. on takedown). A cast already planned for
+// the end of the cooldown is moved forward
+pub fn reset_cooldown(
+    attack_type: AttackType,
+    state: &mut State,
+    game_params: &GameParams,
+    events: &mut BinaryHeap<Event>,
+) {
+    if state.cooldowns.remove(&attack_type).is_none() {
+        return;
+    }
+
+    let planned_cast = events.iter().any(|event| {
+        event.category == EventCategory::AttackCastStart && event.attack_type == Some(attack_type)
+    });
+    events.retain(|event| {
+        !matches!(
+            event.category,
+            EventCategory::CooldownEnded | EventCategory::AttackCastStart
+        ) || event.attack_type != Some(attack_type)
+    });
+
+    if planned_cast {
+        events.push(Event {
+            attack_type: Some(attack_type),
+            category: EventCategory::AttackCastStart,
+            time_ms: next_earliest_time_possible_for(attack_type, state, game_params),
+            passive_effect: None,
+            aura: None,
+        });
+    } else {
+        // wakes up an idle command policy
+        events.push(Event {
+            attack_type: Some(attack_type),
+            category: EventCategory::CooldownEnded,
+            time_ms: state.time_ms,
+            passive_effect: None,
+            aura: None,
+        });
+    }
+}
+
+// returns true when the target has no health left. The takedown is handled (and the fight
+// ends or goes on) on the TargetDied event
+fn insert_target_died_event_if_dead(
+    events: &mut BinaryHeap<Event>,
+    state: &State,
+    game_params: &GameParams,
+) -> bool {
+    let target_stats = compute_target_stats(game_params, state);
+    if target_stats.current_health > 0.0 {
+        return false;
+    }
+
+    let event = Event {
+        attack_type: None,
+        category: EventCategory::TargetDied,
+        time_ms: state.time_ms,
+        passive_effect: None,
+        aura: None,
+    };
+
+    events.push(event);
+    true
+}
+
+// same as insert_target_died_event_if_dead, for a target fighting back
+fn insert_attacker_died_event_if_dead(events: &mut BinaryHeap<Event>, state: &State) -> bool {
+    if state.attacker_health > 0.0 {
+        return false;
+    }
+
+    let event = Event {
+        attack_type: None,
+        category: EventCategory::AttackerDied,
+        time_ms: state.time_ms,
+        passive_effect: None,
+        aura: None,
+    };
+
+    events.push(event);
+    true
 }
 
 fn on_time_passed(
@@ -519,18 +916,141 @@ fn on_time_passed(
             effect.handle_on_movement(event, events, game_params, state, duration);
         }
     }
+
+    // the attacker gets within range of its next command, and at least within attack range
+    let next_command = match event.category {
+        EventCategory::AttackCastStart => event.attack_type,
+        _ => events
+            .iter()
+            .find(|event| event.category == EventCategory::AttackCastStart)
+            .and_then(|event| event.attack_type),
+    };
+    let attack_range = game_params.champion_stats.attack_range;
+    let chase_range = next_command
+        .and_then(|attack_type| cast_range(attack_type, game_params))
+        .map_or(attack_range, |range| f64::min(range, attack_range));
+    area::on_movement(
+        &compute_attacker_stats(game_params, state),
+        chase_range,
+        !state.is_casting,
+        state,
+        game_params,
+        duration,
+    );
+
+    // mana (or energy) regenerates over time, up to the maximum
+    if game_params.champion_data.resource != ResourceType::Other {
+        let attacker_stats = compute_attacker_stats(game_params, state);
+        state.mana = f64::min(
+            state.mana + attacker_stats.mana_regen * duration as f64 / 1000.0,
+            attacker_stats.mana,
+        );
+    }
+}
+
+// distance from the target within which the command can be cast. None for the commands
+// cast on the attacker
+fn cast_range(attack_type: AttackType, game_params: &GameParams<'_>) -> Option<f64> {
+    if attack_type == AttackType::AA {
+        return Some(game_params.champion_stats.attack_range);
+    }
+
+    find_ability(game_params.abilities, attack_type, game_params.config).range
 }
 
-fn ensure_spell_off_cooldown(
+// time to wait until the attacker has walked within range of its target for the command
+fn walk_time_ms(
     attack_type: AttackType,
+    state: &State<'_>,
     game_params: &GameParams<'_>,
-    state: &mut State<'_>,
-) {
+) -> Result<u64, SimError> {
+    let Some(range) = cast_range(attack_type, game_params) else {
+        return Ok(0);
+    };
+
+    let attacker_stats = compute_attacker_stats(game_params, state);
+    area::walk_time_ms(range, &attacker_stats, state, game_params).ok_or(
+        SimError::TargetOutOfReach {
+            command: attack_type,
+            command_index: state.attack_history.len().saturating_sub(1),
+            time_ms: state.time_ms,
+        },
+    )
+}
+
+fn ensure_spell_off_cooldown(attack_type: AttackType, state: &State<'_>) -> Result<(), SimError> {
     if state.cooldowns.contains_key(&attack_type) && !state.recast_ready.contains(&attack_type) {
-        println!("state.cooldowns: {:#?}", state.cooldowns);
-        println!("state.recast_ready: {:#?}", state.recast_ready);
-        panic!();
+        return Err(SimError::SpellOnCooldown {
+            command: attack_type,
+            command_index: state.attack_history.len().saturating_sub(1),
+            time_ms: state.time_ms,
+        });
     }
+
+    Ok(())
+}
+
+// the command is scheduled once enough resource has been regenerated (see
+// next_earliest_time_possible_for). It is rejected if it costs more than the maximum
+fn pay_resource_cost(
+    attack_type: AttackType,
+    state: &mut State<'_>,
+    game_params: &GameParams<'_>,
+) -> Result<(), SimError> {
+    let cost = resource_cost(attack_type, state, game_params);
+    // leeway for the rounding of the regeneration
+    if state.mana + 1e-6 < cost {
+        return Err(SimError::NotEnoughResource {
+            command: attack_type,
+            command_index: state.attack_history.len().saturating_sub(1),
+            time_ms: state.time_ms,
+        });
+    }
+
+    state.mana = f64::max(state.mana - cost, 0.0);
+    Ok(())
+}
+
+// mana (or energy) cost of the command at its current rank. The variations of an ability
+// share the same cost and the recasts are free.
+fn resource_cost(attack_type: AttackType, state: &State<'_>, game_params: &GameParams<'_>) -> f64 {
+    if state.cooldowns.contains_key(&attack_type) && state.recast_charges.contains(&attack_type) {
+        return 0.0;
+    }
+
+    game_params
+        .abilities
+        .iter()
+        .find(|ability| ability.attack_type == attack_type)
+        .and_then(|ability| ability.cost.as_ref())
+        .and_then(|cost| cost.get(&rank_at_level(game_params, attack_type)))
+        .copied()
+        .unwrap_or_default()
+}
+
+// time to wait, from the given point in time, until the command can be afforded. 0 when the
+// command can never be afforded: casting it will then be rejected
+fn resource_delay_ms(
+    attack_type: AttackType,
+    state: &State<'_>,
+    game_params: &GameParams<'_>,
+    from_ms: u64,
+) -> u64 {
+    let cost = resource_cost(attack_type, state, game_params);
+    if cost <= state.mana {
+        return 0;
+    }
+
+    let attacker_stats = compute_attacker_stats(game_params, state);
+    let mana_at = f64::min(
+        state.mana + attacker_stats.mana_regen * (from_ms - state.time_ms) as f64 / 1000.0,
+        attacker_stats.mana,
+    );
+    if cost <= mana_at || cost > attacker_stats.mana || attacker_stats.mana_regen <= 0.0 {
+        return 0;
+    }
+
+    ((cost - mana_at) / attacker_stats.mana_regen * 1000.0).ceil() as u64
 }
 
 fn handle_dash_if_applicable(
@@ -545,7 +1065,7 @@ fn handle_dash_if_applicable(
         ability = Some(find_ability(
             game_params.abilities,
             attack_type,
-            game_params.initial_config,
+            game_params.config,
         ));
     }
 
@@ -562,6 +1082,8 @@ fn handle_dash_if_applicable(
             effect.handle_dash_event(event, events, game_params, state)
         }
 
+        area::on_dash(attack_type, state, game_params);
+
         // for effect in state
         //     .attacker_auras
         //     .clone()
@@ -586,23 +1108,19 @@ fn trigger_stealth_exit_if_applicable(
     // kha'zix is an exception in the sense that its stealth is broken only at the end
     // of the AA windup. Regarding abilities, it's the same as other champs
     if game_params.champion == Champion::Khazix {
-        if event
+        let is_aa = event
             .attack_type
-            .is_some_and(|attack_type| attack_type == AttackType::AA)
-            && event.category == EventCategory::AttackCastEnd
-        {
-            trigger_stealth_exit(event, events, game_params, state);
-        } else if event
+            .is_some_and(|attack_type| attack_type == AttackType::AA);
+        let is_ability = event
             .attack_type
-            .is_some_and(|attack_type| attack_type != AttackType::AA)
-            && event.category == EventCategory::AttackCastStart
+            .is_some_and(|attack_type| attack_type != AttackType::AA);
+        if (is_aa && event.category == EventCategory::AttackCastEnd)
+            || (is_ability && event.category == EventCategory::AttackCastStart)
         {
             trigger_stealth_exit(event, events, game_params, state);
         }
-    } else {
-        if event.category == EventCategory::AttackCastStart {
-            trigger_stealth_exit(event, events, game_params, state);
-        }
+    } else if event.category == EventCategory::AttackCastStart {
+        trigger_stealth_exit(event, events, game_params, state);
     }
 }
 
@@ -628,34 +1146,79 @@ fn trigger_stealth_exit(
     // }
 }
 
+// with charges left, the cooldown only stands between two casts. Once they are all spent,
+// the spell is available again when the first charge is back
+fn cooldown_end_ms(
+    attack_type: AttackType,
+    cooldown: u64,
+    state: &mut State<'_>,
+    game_params: &GameParams<'_>,
+) -> u64 {
+    let cooldown_end_ms = state.time_ms + cooldown;
+    // auto attacks are not part of the abilities
+    let Some(charges) = game_params
+        .abilities
+        .iter()
+        .find(|ability| ability.attack_type == attack_type)
+        .and_then(|ability| ability.charges)
+    else {
+        return cooldown_end_ms;
+    };
+
+    let recharges = state.recharges.entry(attack_type).or_default();
+    recharges.retain(|recharge_end_ms| *recharge_end_ms > state.time_ms);
+    // the charges come back one after the other
+    let recharge_start_ms = recharges.last().copied().unwrap_or(state.time_ms);
+    recharges.push(recharge_start_ms + charges.recharge_ms);
+
+    if (recharges.len() as u64) < charges.count {
+        cooldown_end_ms
+    } else {
+        u64::max(cooldown_end_ms, recharges[0])
+    }
+}
+
 fn add_cooldown_to_state(state: &mut State<'_>, attack_type: AttackType, cooldown_end_ms: u64) {
     state.cooldowns.insert(attack_type, cooldown_end_ms);
 }
 
+// damage dealt to the current target of the attacker. The damage dealt to the primary target
+// is the one making the result of the simulation
+fn add_damage_to_target(damage: f64, state: &mut State) -> Unit {
+    match state.target {
+        Unit::SecondaryTarget(index) => state.secondary_targets[index].damage_taken += damage,
+        _ => state.total_damage += damage,
+    }
+
+    state.target
+}
+
 pub fn on_damage_from_ability(
     damage: &f64,
     damage_type: DamageType,
     state: &mut State,
     attack_type: AttackType,
 ) -> DamageInfo {
-    state.total_damage += damage;
+    let target_unit = add_damage_to_target(*damage, state);
     state.last_attack_time_ms = state.time_ms;
     let damage = DamageInfo {
         amount: *damage,
-        damage_type: damage_type,
+        damage_type,
         time_ms: state.time_ms,
         source: DamageSource::Ability,
         source_ability: Some(attack_type),
         source_rune: None,
         source_item: None,
+        source_unit: Unit::Attacker,
+        target_unit,
     };
     state.damage_history.push(damage.clone());
 
-    return damage;
+    damage
 }
 
 pub fn on_damage_from_rune(damage: &f64, damage_type: DamageType, state: &mut State, rune: Rune) {
-    state.total_damage += damage;
+    let target_unit = add_damage_to_target(*damage, state);
     state.damage_history.push(DamageInfo {
         amount: *damage,
         damage_type,
@@ -664,16 +1227,44 @@ pub fn on_damage_from_rune(damage: &f64, damage_type: DamageType, state: &mut St
         source_ability: None,
         source_rune: Some(rune),
         source_item: None,
+        source_unit: Unit::Attacker,
+        target_unit,
     });
     state.last_attack_time_ms = state.time_ms;
 }
 
+pub fn on_damage_from_item_active(
+    damage: &f64,
+    damage_type: DamageType,
+    state: &mut State,
+    item_name: Item,
+) -> DamageInfo {
+    let target_unit = add_damage_to_target(*damage, state);
+    let damage_info = DamageInfo {
+        amount: *damage,
+        damage_type,
+        time_ms: state.time_ms,
+        source: DamageSource::ItemActive,
+        source_ability: None,
+        source_rune: None,
+        source_item: Some(item_name),
+        source_unit: Unit::Attacker,
+        target_unit,
+    };
+
+    state.damage_history.push(damage_info.clone());
+    state.last_attack_time_ms = state.time_ms;
+
+    damage_info
+}
+
 pub fn on_damage_from_item(
     damage: &f64,
     damage_type: DamageType,
     state: &mut State,
     item_name: Item,
 ) -> DamageInfo {
+    let target_unit = add_damage_to_target(*damage, state);
     let damage_info = DamageInfo {
         amount: *damage,
         damage_type,
@@ -682,13 +1273,170 @@ pub fn on_damage_from_item(
         source_ability: None,
         source_rune: None,
         source_item: Some(item_name),
+        source_unit: Unit::Attacker,
+        target_unit,
     };
 
-    state.total_damage += damage;
     state.damage_history.push(damage_info.clone());
     state.last_attack_time_ms = state.time_ms;
 
-    return damage_info;
+    damage_info
+}
+
+// damage dealt by a target fighting back. The shields of the attacker absorb it first
+pub fn on_damage_to_attacker(
+    damage: f64,
+    damage_type: DamageType,
+    state: &mut State,
+    game_params: &GameParams,
+    event: &Event,
+    events: &mut BinaryHeap<Event>,
+) -> DamageInfo {
+    let defensive_stats = compute_attacker_defensive_stats(game_params, state);
+    let damage = compute_mitigated_damage(
+        &AttackerStats::default(),
+        &defensive_stats,
+        damage,
+        damage_type,
+    );
+
+    let mut health_damage = damage;
+    for shield_aura in [Aura::EverRisingMoonShield, Aura::Ichorshield] {
+        let Some(shield) = state
+            .attacker_auras
+            .get(&shield_aura)
+            .and_then(|aura_app| aura_app.stacks)
+        else {
+            continue;
+        };
+
+        if health_damage >= shield as f64 {
+            health_damage -= shield as f64;
+            state.end_early_attacker_aura(&shield_aura, game_params, event, events);
+        } else {
+            let aura_app = state.attacker_auras.get_mut(&shield_aura).unwrap();
+            aura_app.stacks = Some(shield - health_damage.round() as u64);
+            health_damage = 0.0;
+        }
+    }
+    state.attacker_health -= health_damage;
+
+    let damage_info = DamageInfo {
+        amount: damage,
+        damage_type,
+        time_ms: state.time_ms,
+        source: DamageSource::TargetCommand,
+        source_ability: None,
+        source_rune: None,
+        source_item: None,
+        source_unit: Unit::Target,
+        target_unit: Unit::Attacker,
+    };
+    state.damage_history.push(damage_info.clone());
+
+    damage_info
+}
+
+// restores the health of the attacker, up to its maximum. Returns the overheal
+pub fn on_heal(
+    amount: f64,
+    source: HealSource,
+    source_ability: Option<AttackType>,
+    source_item: Option<Item>,
+    state: &mut State,
+    game_params: &GameParams,
+) -> f64 {
+    let missing_health = f64::max(
+        attacker_max_health(game_params) - state.attacker_health,
+        0.0,
+    );
+    let healed = f64::min(amount, missing_health);
+
+    if healed > 0.0 {
+        state.attacker_health += healed;
+        state.heal_history.push(HealInfo {
+            amount: healed,
+            is_shield: false,
+            time_ms: state.time_ms,
+            source,
+            source_ability,
+            source_item,
+        });
+    }
+
+    amount - healed
+}
+
+// the stacks of the aura hold the amount of the shield. A shield granted again by the same
+// source adds up to the remaining one
+pub fn on_shield(
+    amount: f64,
+    aura: Aura,
+    duration: Option<u64>,
+    item: Item,
+    state: &mut State,
+    events: &mut BinaryHeap<Event>,
+) {
+    let remaining_shield = state
+        .attacker_auras
+        .get(&aura)
+        .and_then(|aura_app| aura_app.stacks)
+        .unwrap_or_default();
+    state.add_attacker_aura(
+        aura,
+        duration,
+        Some(remaining_shield + amount.round() as u64),
+        events,
+    );
+
+    state.heal_history.push(HealInfo {
+        amount,
+        is_shield: true,
+        time_ms: state.time_ms,
+        source: HealSource::ItemPassive,
+        source_ability: None,
+        source_item: Some(item),
+    });
+}
+
+// life steal heals for a part of the damage of the basic attacks, omnivamp for a part of
+// all the damage going through the post damage events
+fn apply_vamp(
+    damage_info: &DamageInfo,
+    attacker_stats: &AttackerStats,
+    state: &mut State,
+    game_params: &GameParams,
+    events: &mut BinaryHeap<Event>,
+) {
+    if attacker_stats.life_steal > 0.0 && damage_info.source_ability == Some(AttackType::AA) {
+        let overheal = on_heal(
+            damage_info.amount * attacker_stats.life_steal,
+            HealSource::LifeSteal,
+            None,
+            None,
+            state,
+            game_params,
+        );
+
+        if overheal > 0.0
+            && game_params
+                .passive_effects
+                .contains(&PassiveEffect::Ichorshield)
+        {
+            items::ichorshield(overheal, state, game_params, events);
+        }
+    }
+
+    if attacker_stats.omnivamp > 0.0 {
+        on_heal(
+            damage_info.amount * attacker_stats.omnivamp,
+            HealSource::Omnivamp,
+            None,
+            None,
+            state,
+            game_params,
+        );
+    }
 }
 
 fn insert_attack_cast_end_event(
@@ -709,20 +1457,23 @@ fn insert_attack_cast_end_event(
 
 fn insert_next_attack_event(
     events: &mut BinaryHeap<Event>,
-    commands: &mut VecDeque<AttackType>,
+    command_policy: &mut dyn CommandPolicy,
     state: &mut State,
     game_params: &GameParams,
 ) {
-    // If there are no more commands, do nothing
-    let Some(next_command_attack_type) = commands.front() else {
+    // If there is nothing to do for now, wait for the next event
+    let Some(next_command_attack_type) = command_policy.peek_command(state, game_params) else {
+        state.is_idle = true;
+        insert_resource_regenerated_event(events, state, game_params);
         return;
     };
+    state.is_idle = false;
 
     // Calculate when the next attack should occur
     let next_possible_attack_ms =
-        next_earliest_time_possible_for(*next_command_attack_type, state, game_params);
+        next_earliest_time_possible_for(next_command_attack_type, state, game_params);
 
-    if *next_command_attack_type != AttackType::AA
+    if next_command_attack_type != AttackType::AA
         && game_params.weave_auto_attacks
         && can_weave_auto_attack(state, game_params, next_possible_attack_ms)
     {
@@ -740,7 +1491,7 @@ fn insert_next_attack_event(
         state.attack_history.push(AttackType::AA);
     } else {
         let event = Event {
-            attack_type: Some(*next_command_attack_type),
+            attack_type: Some(next_command_attack_type),
             category: EventCategory::AttackCastStart,
             time_ms: next_possible_attack_ms,
             passive_effect: None,
@@ -748,13 +1499,23 @@ fn insert_next_attack_event(
         };
 
         events.push(event);
-        state.attack_history.push(*next_command_attack_type);
-        commands.pop_front();
+        state.attack_history.push(next_command_attack_type);
+        command_policy.on_command_scheduled(next_command_attack_type);
     }
 }
 
-/// Calculates the time when the next attack can occur based on cooldowns and auras
-fn next_earliest_time_possible_for(
+/// Calculates the time when the next attack can occur based on cooldowns, auras and the
+/// resource of the attacker
+pub(crate) fn next_earliest_time_possible_for(
+    attack_type: AttackType,
+    state: &State,
+    game_params: &GameParams,
+) -> u64 {
+    let off_cooldown_ms = next_time_off_cooldown_for(attack_type, state, game_params);
+    off_cooldown_ms + resource_delay_ms(attack_type, state, game_params, off_cooldown_ms)
+}
+
+fn next_time_off_cooldown_for(
     attack_type: AttackType,
     state: &State,
     game_params: &GameParams,
@@ -767,11 +1528,7 @@ fn next_earliest_time_possible_for(
 
     // Special case: ability has recast charges
     if state.recast_charges.contains(&attack_type) {
-        let ability = find_ability(
-            game_params.abilities,
-            attack_type,
-            game_params.initial_config,
-        );
+        let ability = find_ability(game_params.abilities, attack_type, game_params.config);
 
         // Check for invisibility aura
         if let Some(invis_aura_app) = state.attacker_auras.get(&Aura::Invisibility) {
@@ -807,7 +1564,7 @@ fn can_weave_auto_attack(
     let aa_cast_time = cast_time(
         &attacker_stats,
         AttackType::AA,
-        game_params.initial_config,
+        game_params.config,
         game_params.abilities,
     );
 
@@ -816,6 +1573,65 @@ fn can_weave_auto_attack(
     aa_cast_end_ms <= next_possible_attack_ms
 }
 
+// the target decides what to do next as soon as its previous command is over
+fn insert_target_cast_start_event(
+    events: &mut BinaryHeap<Event>,
+    target_policy: &TargetPolicy,
+    time_ms: u64,
+) {
+    let Some(time_ms) = target_policy.next_cast_time(time_ms) else {
+        return;
+    };
+
+    let event = Event {
+        attack_type: None,
+        category: EventCategory::TargetCastStart,
+        time_ms,
+        passive_effect: None,
+        aura: None,
+    };
+
+    events.push(event);
+}
+
+fn insert_target_cast_end_event(events: &mut BinaryHeap<Event>, time_ms: u64) {
+    let event = Event {
+        attack_type: None,
+        category: EventCategory::TargetCastEnd,
+        time_ms,
+        passive_effect: None,
+        aura: None,
+    };
+
+    events.push(event);
+}
+
+// wakes up an idle command policy once the next ability can be afforded, as no other event
+// may happen in the meantime
+fn insert_resource_regenerated_event(
+    events: &mut BinaryHeap<Event>,
+    state: &State,
+    game_params: &GameParams,
+) {
+    let Some(delay_ms) = game_params
+        .abilities
+        .iter()
+        .map(|ability| resource_delay_ms(ability.attack_type, state, game_params, state.time_ms))
+        .filter(|&delay_ms| delay_ms > 0)
+        .min()
+    else {
+        return;
+    };
+
+    events.push(Event {
+        attack_type: None,
+        category: EventCategory::ResourceRegenerated,
+        time_ms: state.time_ms + delay_ms,
+        passive_effect: None,
+        aura: None,
+    });
+}
+
 fn insert_cooldown_ended_event(events: &mut BinaryHeap<Event>, event: &Event, time_ms: u64) {
     let event = Event {
         attack_type: event.attack_type,
@@ -844,6 +1660,30 @@ pub fn insert_passive_triggered_event(
     events.push(event);
 }
 
+pub fn insert_ignite_tick_event(events: &mut BinaryHeap<Event>, time_ms: u64) {
+    let event = Event {
+        attack_type: Some(AttackType::Ignite),
+        category: EventCategory::IgniteTick,
+        time_ms,
+        passive_effect: None,
+        aura: None,
+    };
+
+    events.push(event);
+}
+
+pub fn insert_defy_tick_event(events: &mut BinaryHeap<Event>, time_ms: u64) {
+    let event = Event {
+        attack_type: None,
+        category: EventCategory::DefyTick,
+        time_ms,
+        passive_effect: Some(PassiveEffect::Defy),
+        aura: None,
+    };
+
+    events.push(event);
+}
+
 pub fn insert_aura_attacker_start_event(events: &mut BinaryHeap<Event>, time_ms: u64, aura: Aura) {
     let event = Event {
         attack_type: None,
@@ -952,6 +1792,8 @@ pub fn on_post_damage_events(
     event: &Event,
     events: &mut BinaryHeap<Event>,
 ) {
+    apply_vamp(damage_info, attacker_stats, state, game_params, events);
+
     // println!("on_post_damage_events");
     // println!("passive_effects:");
     for effect in game_params.passive_effects.iter() {
@@ -985,9 +1827,11 @@ pub fn on_post_damage_events(
 #[cfg(test)]
 mod integration_tests {
     use super::*;
+    use crate::area::{Battlefield, Position, SecondaryTarget};
     use crate::data_input::{
         self,
-        common::{Aura, AuraApplication, CritHandlingChoice, GameParams, TargetStats},
+        champions::stat_increase,
+        common::{compile_passive_effects, Aura, CritHandlingChoice, GameParams, TargetStats},
     };
     use std::collections::{HashMap, HashSet, VecDeque};
 
@@ -1000,20 +1844,31 @@ mod integration_tests {
             time_ms: current_time_ms,
             cooldowns: &mut HashMap::new(),
             last_attack_time_ms: 0,
+            kill_time_ms: None,
             effects_cooldowns: &mut HashMap::new(),
-            config: &mut HashMap::new(),
+            config: &mut ScenarioConfig::default(),
             attacker_auras: &mut HashMap::new(),
             target_auras: &mut HashMap::new(),
             damage_history: &mut Vec::new(),
+            heal_history: &mut Vec::new(),
+            secondary_targets: &mut Vec::new(),
+            target: Unit::Target,
+            attacker_position: area::DEFAULT_ATTACKER_POSITION,
+            target_position: Position::default(),
             event_history: &mut Vec::new(),
             attack_history: &mut Vec::new(),
             recast_charges: &mut Vec::new(),
             recast_ready: &mut HashSet::new(),
+            recharges: &mut HashMap::new(),
+            mana: 1_000.0,
+            attacker_health: 1_000.0,
             is_casting: false,
+            is_idle: false,
+            rng: &mut ChaCha8Rng::seed_from_u64(0),
         };
 
-        let config = HashMap::new();
-        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config);
+        let config = ScenarioConfig::default();
+        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config).unwrap();
 
         let mut runes: HashSet<Rune> = HashSet::new();
         runes.insert(Rune::DarkHarvest);
@@ -1023,17 +1878,23 @@ mod integration_tests {
         runes.insert(Rune::AdaptiveForce1);
         runes.insert(Rune::AdaptiveForce2);
 
-        let mut game_params: GameParams<'_> = GameParams {
+        let game_params: GameParams<'_> = GameParams {
             champion: Champion::Khazix,
             champion_data: &static_data.champion_data,
             champion_stats: &static_data.base_champion_stats,
             level: 18,
             items: &Vec::new(),
-            initial_config: &config,
+            config: &config,
             abilities: &static_data.abilities,
             initial_target_stats: &TargetStats {
                 armor: 0.0,
+                armor_bonus: 0.0,
                 magic_resistance: 0.0,
+                magic_resistance_bonus: 0.0,
+                armor_reduction_flat: 0.0,
+                armor_reduction_perc: 0.0,
+                magic_resistance_reduction_flat: 0.0,
+                magic_resistance_reduction_perc: 0.0,
                 max_health: 1000.0,
                 current_health: 1000.0,
             },
@@ -1048,6 +1909,7 @@ mod integration_tests {
             start_time_ms: 0,
             capture_event_history: false,
             weave_auto_attacks: false,
+            battlefield: &Battlefield::default(),
         };
 
         let r_ability = static_data
@@ -1056,13 +1918,12 @@ mod integration_tests {
             .find(|ability| ability.attack_type == AttackType::R)
             .unwrap();
 
-        let next_attack_time =
-            next_earliest_time_possible_for(AttackType::Q, &mut state, &game_params);
+        let next_attack_time = next_earliest_time_possible_for(AttackType::Q, &state, &game_params);
         assert_eq!(next_attack_time, current_time_ms);
 
         fast_forward_to(next_attack_time, &mut state, &game_params);
 
-        ensure_spell_off_cooldown(AttackType::R, &game_params, &mut state);
+        ensure_spell_off_cooldown(AttackType::R, &state).unwrap();
 
         let cast_end_ms = r_ability.cast_time_ms.unwrap_or_default() + next_attack_time;
 
@@ -1082,7 +1943,8 @@ mod integration_tests {
                 aura: None,
             },
             &mut BinaryHeap::new(),
-        );
+        )
+        .unwrap();
     }
 
     #[test]
@@ -1094,20 +1956,31 @@ mod integration_tests {
             time_ms: current_time_ms,
             cooldowns: &mut HashMap::new(),
             last_attack_time_ms: 0,
+            kill_time_ms: None,
             effects_cooldowns: &mut HashMap::new(),
-            config: &mut HashMap::new(),
+            config: &mut ScenarioConfig::default(),
             attacker_auras: &mut HashMap::new(),
             target_auras: &mut HashMap::new(),
             damage_history: &mut Vec::new(),
+            heal_history: &mut Vec::new(),
+            secondary_targets: &mut Vec::new(),
+            target: Unit::Target,
+            attacker_position: area::DEFAULT_ATTACKER_POSITION,
+            target_position: Position::default(),
             event_history: &mut Vec::new(),
             attack_history: &mut Vec::new(),
             recast_charges: &mut Vec::new(),
             recast_ready: &mut HashSet::new(),
+            recharges: &mut HashMap::new(),
+            mana: 1_000.0,
+            attacker_health: 1_000.0,
             is_casting: false,
+            is_idle: false,
+            rng: &mut ChaCha8Rng::seed_from_u64(0),
         };
 
-        let config = HashMap::new();
-        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config);
+        let config = ScenarioConfig::default();
+        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config).unwrap();
 
         let mut runes: HashSet<Rune> = HashSet::new();
         runes.insert(Rune::DarkHarvest);
@@ -1123,11 +1996,17 @@ mod integration_tests {
             champion_stats: &static_data.base_champion_stats,
             level: 18,
             items: &Vec::new(),
-            initial_config: &config,
+            config: &config,
             abilities: &static_data.abilities,
             initial_target_stats: &TargetStats {
                 armor: 0.0,
+                armor_bonus: 0.0,
                 magic_resistance: 0.0,
+                magic_resistance_bonus: 0.0,
+                armor_reduction_flat: 0.0,
+                armor_reduction_perc: 0.0,
+                magic_resistance_reduction_flat: 0.0,
+                magic_resistance_reduction_perc: 0.0,
                 max_health: 1000.0,
                 current_health: 1000.0,
             },
@@ -1142,6 +2021,7 @@ mod integration_tests {
             start_time_ms: 0,
             capture_event_history: false,
             weave_auto_attacks: false,
+            battlefield: &Battlefield::default(),
         };
 
         let spell_result = simulate_spell(
@@ -1158,7 +2038,8 @@ mod integration_tests {
                 aura: None,
             },
             &mut BinaryHeap::new(),
-        );
+        )
+        .unwrap();
 
         let r_ability = static_data
             .abilities
@@ -1188,8 +2069,7 @@ mod integration_tests {
 
         let recast_gap = r_ability.recast_gap_duration.unwrap();
 
-        let next_attack_time =
-            next_earliest_time_possible_for(AttackType::R, &mut state, &game_params);
+        let next_attack_time = next_earliest_time_possible_for(AttackType::R, &state, &game_params);
         assert_eq!(next_attack_time, invis_end_ms + recast_gap);
 
         // cannot fast forward to next attack time directly because of how
@@ -1197,7 +2077,7 @@ mod integration_tests {
         fast_forward_to(invis_end_ms, &mut state, &game_params);
         fast_forward_to(next_attack_time, &mut state, &game_params);
 
-        ensure_spell_off_cooldown(AttackType::R, &game_params, &mut state);
+        ensure_spell_off_cooldown(AttackType::R, &state).unwrap();
 
         let cast_end_ms = r_ability.cast_time_ms.unwrap_or_default() + next_attack_time;
 
@@ -1217,7 +2097,8 @@ mod integration_tests {
                 aura: None,
             },
             &mut BinaryHeap::new(),
-        );
+        )
+        .unwrap();
     }
 
     #[test]
@@ -1229,20 +2110,31 @@ mod integration_tests {
             time_ms: current_time_ms,
             cooldowns: &mut HashMap::new(),
             last_attack_time_ms: 0,
+            kill_time_ms: None,
             effects_cooldowns: &mut HashMap::new(),
-            config: &mut HashMap::new(),
+            config: &mut ScenarioConfig::default(),
             attacker_auras: &mut HashMap::new(),
             target_auras: &mut HashMap::new(),
             damage_history: &mut Vec::new(),
+            heal_history: &mut Vec::new(),
+            secondary_targets: &mut Vec::new(),
+            target: Unit::Target,
+            attacker_position: area::DEFAULT_ATTACKER_POSITION,
+            target_position: Position::default(),
             event_history: &mut Vec::new(),
             attack_history: &mut Vec::new(),
             recast_charges: &mut Vec::new(),
             recast_ready: &mut HashSet::new(),
+            recharges: &mut HashMap::new(),
+            mana: 1_000.0,
+            attacker_health: 1_000.0,
             is_casting: false,
+            is_idle: false,
+            rng: &mut ChaCha8Rng::seed_from_u64(0),
         };
 
-        let config = HashMap::new();
-        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config);
+        let config = ScenarioConfig::default();
+        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config).unwrap();
 
         let mut runes: HashSet<Rune> = HashSet::new();
         runes.insert(Rune::DarkHarvest);
@@ -1258,11 +2150,17 @@ mod integration_tests {
             champion_stats: &static_data.base_champion_stats,
             level: 18,
             items: &Vec::new(),
-            initial_config: &config,
+            config: &config,
             abilities: &static_data.abilities,
             initial_target_stats: &TargetStats {
                 armor: 0.0,
+                armor_bonus: 0.0,
                 magic_resistance: 0.0,
+                magic_resistance_bonus: 0.0,
+                armor_reduction_flat: 0.0,
+                armor_reduction_perc: 0.0,
+                magic_resistance_reduction_flat: 0.0,
+                magic_resistance_reduction_perc: 0.0,
                 max_health: 1000.0,
                 current_health: 1000.0,
             },
@@ -1277,6 +2175,7 @@ mod integration_tests {
             start_time_ms: 0,
             capture_event_history: false,
             weave_auto_attacks: false,
+            battlefield: &Battlefield::default(),
         };
 
         simulate_spell(
@@ -1293,7 +2192,8 @@ mod integration_tests {
                 aura: None,
             },
             &mut BinaryHeap::new(),
-        );
+        )
+        .unwrap();
 
         let r_ability = static_data
             .abilities
@@ -1321,13 +2221,12 @@ mod integration_tests {
             .end_ms
             .unwrap();
 
-        let next_attack_time =
-            next_earliest_time_possible_for(AttackType::R, &mut state, &game_params);
+        let next_attack_time = next_earliest_time_possible_for(AttackType::R, &state, &game_params);
         assert_eq!(next_attack_time, gap_end_ms);
 
         fast_forward_to(next_attack_time, &mut state, &game_params);
 
-        ensure_spell_off_cooldown(AttackType::R, &game_params, &mut state);
+        ensure_spell_off_cooldown(AttackType::R, &state).unwrap();
 
         let cast_end_ms = r_ability.cast_time_ms.unwrap_or_default() + gap_end_ms;
 
@@ -1347,7 +2246,8 @@ mod integration_tests {
                 aura: None,
             },
             &mut BinaryHeap::new(),
-        );
+        )
+        .unwrap();
     }
 
     #[test]
@@ -1359,20 +2259,31 @@ mod integration_tests {
             time_ms: current_time_ms,
             cooldowns: &mut HashMap::new(),
             last_attack_time_ms: 0,
+            kill_time_ms: None,
             effects_cooldowns: &mut HashMap::new(),
-            config: &mut HashMap::new(),
+            config: &mut ScenarioConfig::default(),
             attacker_auras: &mut HashMap::new(),
             target_auras: &mut HashMap::new(),
             damage_history: &mut Vec::new(),
+            heal_history: &mut Vec::new(),
+            secondary_targets: &mut Vec::new(),
+            target: Unit::Target,
+            attacker_position: area::DEFAULT_ATTACKER_POSITION,
+            target_position: Position::default(),
             event_history: &mut Vec::new(),
             attack_history: &mut Vec::new(),
             recast_charges: &mut Vec::new(),
             recast_ready: &mut HashSet::new(),
+            recharges: &mut HashMap::new(),
+            mana: 1_000.0,
+            attacker_health: 1_000.0,
             is_casting: false,
+            is_idle: false,
+            rng: &mut ChaCha8Rng::seed_from_u64(0),
         };
 
-        let config = HashMap::new();
-        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config);
+        let config = ScenarioConfig::default();
+        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config).unwrap();
 
         let mut runes: HashSet<Rune> = HashSet::new();
         runes.insert(Rune::DarkHarvest);
@@ -1388,11 +2299,17 @@ mod integration_tests {
             champion_stats: &static_data.base_champion_stats,
             level: 18,
             items: &Vec::new(),
-            initial_config: &config,
+            config: &config,
             abilities: &static_data.abilities,
             initial_target_stats: &TargetStats {
                 armor: 0.0,
+                armor_bonus: 0.0,
                 magic_resistance: 0.0,
+                magic_resistance_bonus: 0.0,
+                armor_reduction_flat: 0.0,
+                armor_reduction_perc: 0.0,
+                magic_resistance_reduction_flat: 0.0,
+                magic_resistance_reduction_perc: 0.0,
                 max_health: 1000.0,
                 current_health: 1000.0,
             },
@@ -1407,6 +2324,7 @@ mod integration_tests {
             start_time_ms: 0,
             capture_event_history: false,
             weave_auto_attacks: false,
+            battlefield: &Battlefield::default(),
         };
 
         simulate_spell(
@@ -1423,7 +2341,8 @@ mod integration_tests {
                 aura: None,
             },
             &mut BinaryHeap::new(),
-        );
+        )
+        .unwrap();
 
         let r_ability = static_data
             .abilities
@@ -1466,11 +2385,11 @@ mod integration_tests {
             fast_forward_to(i, &mut state, &game_params);
 
             let next_attack_time =
-                next_earliest_time_possible_for(AttackType::R, &mut state, &game_params);
+                next_earliest_time_possible_for(AttackType::R, &state, &game_params);
             assert_eq!(next_attack_time, i);
         }
 
-        ensure_spell_off_cooldown(AttackType::R, &game_params, &mut state);
+        ensure_spell_off_cooldown(AttackType::R, &state).unwrap();
 
         let cast_end_ms = r_ability.cast_time_ms.unwrap_or_default() + state.time_ms;
 
@@ -1490,7 +2409,8 @@ mod integration_tests {
                 aura: None,
             },
             &mut BinaryHeap::new(),
-        );
+        )
+        .unwrap();
     }
 
     #[test]
@@ -1502,20 +2422,31 @@ mod integration_tests {
             time_ms: current_time_ms,
             cooldowns: &mut HashMap::new(),
             last_attack_time_ms: 0,
+            kill_time_ms: None,
             effects_cooldowns: &mut HashMap::new(),
-            config: &mut HashMap::new(),
+            config: &mut ScenarioConfig::default(),
             attacker_auras: &mut HashMap::new(),
             target_auras: &mut HashMap::new(),
             damage_history: &mut Vec::new(),
+            heal_history: &mut Vec::new(),
+            secondary_targets: &mut Vec::new(),
+            target: Unit::Target,
+            attacker_position: area::DEFAULT_ATTACKER_POSITION,
+            target_position: Position::default(),
             event_history: &mut Vec::new(),
             attack_history: &mut Vec::new(),
             recast_charges: &mut Vec::new(),
             recast_ready: &mut HashSet::new(),
+            recharges: &mut HashMap::new(),
+            mana: 1_000.0,
+            attacker_health: 1_000.0,
             is_casting: false,
+            is_idle: false,
+            rng: &mut ChaCha8Rng::seed_from_u64(0),
         };
 
-        let config = HashMap::new();
-        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config);
+        let config = ScenarioConfig::default();
+        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config).unwrap();
 
         let mut runes: HashSet<Rune> = HashSet::new();
         runes.insert(Rune::DarkHarvest);
@@ -1531,11 +2462,17 @@ mod integration_tests {
             champion_stats: &static_data.base_champion_stats,
             level: 18,
             items: &Vec::new(),
-            initial_config: &config,
+            config: &config,
             abilities: &static_data.abilities,
             initial_target_stats: &TargetStats {
                 armor: 0.0,
+                armor_bonus: 0.0,
                 magic_resistance: 0.0,
+                magic_resistance_bonus: 0.0,
+                armor_reduction_flat: 0.0,
+                armor_reduction_perc: 0.0,
+                magic_resistance_reduction_flat: 0.0,
+                magic_resistance_reduction_perc: 0.0,
                 max_health: 1000.0,
                 current_health: 1000.0,
             },
@@ -1550,6 +2487,7 @@ mod integration_tests {
             start_time_ms: 0,
             capture_event_history: false,
             weave_auto_attacks: false,
+            battlefield: &Battlefield::default(),
         };
 
         simulate_spell(
@@ -1566,7 +2504,8 @@ mod integration_tests {
                 aura: None,
             },
             &mut BinaryHeap::new(),
-        );
+        )
+        .unwrap();
 
         let r_ability = static_data
             .abilities
@@ -1605,13 +2544,12 @@ mod integration_tests {
 
         fast_forward_to(possible_recast_end_ms, &mut state, &game_params);
 
-        let next_attack_time =
-            next_earliest_time_possible_for(AttackType::R, &mut state, &game_params);
+        let next_attack_time = next_earliest_time_possible_for(AttackType::R, &state, &game_params);
         assert_eq!(next_attack_time, cd_ms + current_time_ms);
 
         fast_forward_to(next_attack_time, &mut state, &game_params);
 
-        ensure_spell_off_cooldown(AttackType::R, &game_params, &mut state);
+        ensure_spell_off_cooldown(AttackType::R, &state).unwrap();
 
         let cast_end_ms = r_ability.cast_time_ms.unwrap_or_default() + gap_end_ms;
 
@@ -1631,6 +2569,1290 @@ mod integration_tests {
                 aura: None,
             },
             &mut BinaryHeap::new(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn run_returns_an_error_when_casting_an_unlearned_spell() {
+        let config = ScenarioConfig::default();
+        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config).unwrap();
+        let runes: HashSet<Rune> = HashSet::new();
+
+        let mut game_params: GameParams<'_> = GameParams {
+            champion: Champion::Khazix,
+            champion_data: &static_data.champion_data,
+            champion_stats: &static_data.base_champion_stats,
+            level: 3,
+            items: &Vec::new(),
+            config: &config,
+            abilities: &static_data.abilities,
+            initial_target_stats: &TargetStats {
+                armor: 0.0,
+                armor_bonus: 0.0,
+                magic_resistance: 0.0,
+                magic_resistance_bonus: 0.0,
+                armor_reduction_flat: 0.0,
+                armor_reduction_perc: 0.0,
+                magic_resistance_reduction_flat: 0.0,
+                magic_resistance_reduction_perc: 0.0,
+                max_health: 10_000.0,
+                current_health: 10_000.0,
+            },
+            runes: &runes,
+            attacker_hp_perc: 100.0,
+            runes_data: &static_data.runes_data,
+            passive_effects: &mut Vec::new(),
+            crit_handling: CritHandlingChoice::Min,
+            initial_attacker_auras: &Vec::new(),
+            initial_target_auras: &Vec::new(),
+            abilities_extra_data: &static_data.abilities_extra_data,
+            start_time_ms: 0,
+            capture_event_history: false,
+            weave_auto_attacks: false,
+            battlefield: &Battlefield::default(),
+        };
+
+        compile_passive_effects(&mut game_params);
+
+        // R is only learned at level 6
+        let commands = VecDeque::from([AttackType::AA, AttackType::R]);
+
+        assert_eq!(
+            run(commands, &game_params).unwrap_err(),
+            SimError::SpellNotLearned {
+                command: AttackType::R,
+                command_index: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn ignite_deals_true_damage_over_time() {
+        let config = ScenarioConfig::default();
+        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config).unwrap();
+        let runes: HashSet<Rune> = HashSet::new();
+
+        let mut game_params: GameParams<'_> = GameParams {
+            champion: Champion::Khazix,
+            champion_data: &static_data.champion_data,
+            champion_stats: &static_data.base_champion_stats,
+            level: 18,
+            items: &Vec::new(),
+            config: &config,
+            abilities: &static_data.abilities,
+            initial_target_stats: &TargetStats {
+                armor: 100.0,
+                armor_bonus: 0.0,
+                magic_resistance: 0.0,
+                magic_resistance_bonus: 0.0,
+                armor_reduction_flat: 0.0,
+                armor_reduction_perc: 0.0,
+                magic_resistance_reduction_flat: 0.0,
+                magic_resistance_reduction_perc: 0.0,
+                max_health: 10_000.0,
+                current_health: 10_000.0,
+            },
+            runes: &runes,
+            attacker_hp_perc: 100.0,
+            runes_data: &static_data.runes_data,
+            passive_effects: &mut Vec::new(),
+            crit_handling: CritHandlingChoice::Min,
+            initial_attacker_auras: &Vec::new(),
+            initial_target_auras: &Vec::new(),
+            abilities_extra_data: &static_data.abilities_extra_data,
+            start_time_ms: 0,
+            capture_event_history: false,
+            weave_auto_attacks: false,
+            battlefield: &Battlefield::default(),
+        };
+
+        compile_passive_effects(&mut game_params);
+
+        let (damage, damage_history, _, _, _, time_ms, kill) =
+            run(VecDeque::from([AttackType::Ignite]), &game_params).unwrap();
+
+        // 50 + 20 * level true damage, over 5 ticks (one per second)
+        assert!(!kill);
+        assert_eq!(damage_history.len(), 5);
+        assert!((damage - 410.0).abs() < 1e-9);
+        for (tick, damage_info) in damage_history.iter().enumerate() {
+            assert_eq!(damage_info.damage_type, DamageType::True);
+            assert_eq!(damage_info.source_ability, Some(AttackType::Ignite));
+            assert_eq!(damage_info.time_ms, (tick as u64 + 1) * 1_000);
+        }
+        assert_eq!(time_ms, 5_000);
+    }
+
+    #[test]
+    fn smite_scales_with_the_level_and_recharges_its_charges() {
+        let config = ScenarioConfig::default();
+        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config).unwrap();
+        let runes: HashSet<Rune> = HashSet::new();
+
+        let mut game_params: GameParams<'_> = GameParams {
+            champion: Champion::Khazix,
+            champion_data: &static_data.champion_data,
+            champion_stats: &static_data.base_champion_stats,
+            level: 11,
+            items: &Vec::new(),
+            config: &config,
+            abilities: &static_data.abilities,
+            initial_target_stats: &TargetStats {
+                armor: 100.0,
+                armor_bonus: 0.0,
+                magic_resistance: 0.0,
+                magic_resistance_bonus: 0.0,
+                armor_reduction_flat: 0.0,
+                armor_reduction_perc: 0.0,
+                magic_resistance_reduction_flat: 0.0,
+                magic_resistance_reduction_perc: 0.0,
+                max_health: 10_000.0,
+                current_health: 10_000.0,
+            },
+            runes: &runes,
+            attacker_hp_perc: 100.0,
+            runes_data: &static_data.runes_data,
+            passive_effects: &mut Vec::new(),
+            crit_handling: CritHandlingChoice::Min,
+            initial_attacker_auras: &Vec::new(),
+            initial_target_auras: &Vec::new(),
+            abilities_extra_data: &static_data.abilities_extra_data,
+            start_time_ms: 0,
+            capture_event_history: false,
+            weave_auto_attacks: false,
+            battlefield: &Battlefield::default(),
+        };
+
+        compile_passive_effects(&mut game_params);
+
+        let (_, damage_history, _, _, _, _, _) = run(
+            VecDeque::from([AttackType::Smite, AttackType::Smite, AttackType::Smite]),
+            &game_params,
+        )
+        .unwrap();
+
+        // 20 true damage at level 1 up to 160 at level 18
+        assert_eq!(damage_history.len(), 3);
+        for damage_info in damage_history.iter() {
+            assert_eq!(damage_info.damage_type, DamageType::True);
+            assert!((damage_info.amount - (20.0 + 140.0 * 10.0 / 17.0)).abs() < 1e-9);
+        }
+        // 15s between the two charges, then the first charge needs 90s to come back
+        let times: Vec<u64> = damage_history.iter().map(|info| info.time_ms).collect();
+        assert_eq!(times, vec![0, 15_000, 90_000]);
+    }
+
+    #[test]
+    fn infinity_edge_raises_the_crit_damage() {
+        let config = ScenarioConfig::default();
+        let static_data = data_input::parse_files(Champion::Khazix, &[3031], &config).unwrap();
+        let runes: HashSet<Rune> = HashSet::new();
+        let items = vec![&static_data.items_map[&3031]];
+        let target_stats = TargetStats {
+            armor: 0.0,
+            armor_bonus: 0.0,
+            magic_resistance: 0.0,
+            magic_resistance_bonus: 0.0,
+            armor_reduction_flat: 0.0,
+            armor_reduction_perc: 0.0,
+            magic_resistance_reduction_flat: 0.0,
+            magic_resistance_reduction_perc: 0.0,
+            max_health: 10_000.0,
+            current_health: 10_000.0,
+        };
+
+        let aa_damage = |crit_handling: CritHandlingChoice| {
+            let mut game_params: GameParams<'_> = GameParams {
+                champion: Champion::Khazix,
+                champion_data: &static_data.champion_data,
+                champion_stats: &static_data.base_champion_stats,
+                level: 18,
+                items: &items,
+                config: &config,
+                abilities: &static_data.abilities,
+                initial_target_stats: &target_stats,
+                runes: &runes,
+                attacker_hp_perc: 100.0,
+                runes_data: &static_data.runes_data,
+                passive_effects: &mut Vec::new(),
+                crit_handling,
+                initial_attacker_auras: &Vec::new(),
+                initial_target_auras: &Vec::new(),
+                abilities_extra_data: &static_data.abilities_extra_data,
+                start_time_ms: 0,
+                capture_event_history: false,
+                weave_auto_attacks: false,
+                battlefield: &Battlefield::default(),
+            };
+
+            compile_passive_effects(&mut game_params);
+
+            run(VecDeque::from([AttackType::AA]), &game_params)
+                .unwrap()
+                .0
+        };
+
+        // 40% crit damage on top of the one of the champion
+        let champion_stats = &static_data.base_champion_stats;
+        let crit_multiplier = (champion_stats.critical_strike_damage + 0.4)
+            * champion_stats.critical_strike_damage_modifier;
+        assert_eq!(items[0].offensive_stats.crit_damage, 0.4);
+        assert!(
+            (aa_damage(CritHandlingChoice::Max)
+                - aa_damage(CritHandlingChoice::Min) * crit_multiplier)
+                .abs()
+                < 1e-9
+        );
+    }
+
+    #[test]
+    fn flash_triggers_dash_effects() {
+        let config = ScenarioConfig::default();
+        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config).unwrap();
+        let runes: HashSet<Rune> = HashSet::from([Rune::SuddenImpact]);
+
+        let mut game_params: GameParams<'_> = GameParams {
+            champion: Champion::Khazix,
+            champion_data: &static_data.champion_data,
+            champion_stats: &static_data.base_champion_stats,
+            level: 18,
+            items: &Vec::new(),
+            config: &config,
+            abilities: &static_data.abilities,
+            initial_target_stats: &TargetStats {
+                armor: 100.0,
+                armor_bonus: 0.0,
+                magic_resistance: 0.0,
+                magic_resistance_bonus: 0.0,
+                armor_reduction_flat: 0.0,
+                armor_reduction_perc: 0.0,
+                magic_resistance_reduction_flat: 0.0,
+                magic_resistance_reduction_perc: 0.0,
+                max_health: 10_000.0,
+                current_health: 10_000.0,
+            },
+            runes: &runes,
+            attacker_hp_perc: 100.0,
+            runes_data: &static_data.runes_data,
+            passive_effects: &mut Vec::new(),
+            crit_handling: CritHandlingChoice::Min,
+            initial_attacker_auras: &Vec::new(),
+            initial_target_auras: &Vec::new(),
+            abilities_extra_data: &static_data.abilities_extra_data,
+            start_time_ms: 0,
+            capture_event_history: false,
+            weave_auto_attacks: false,
+            battlefield: &Battlefield::default(),
+        };
+
+        compile_passive_effects(&mut game_params);
+
+        let (_, damage_history, _, _, _, _, _) = run(
+            VecDeque::from([AttackType::Flash, AttackType::AA]),
+            &game_params,
+        )
+        .unwrap();
+
+        // Sudden Impact is ready after the blink and consumed by the auto attack
+        assert!(damage_history
+            .iter()
+            .any(|damage_info| damage_info.source_rune == Some(Rune::SuddenImpact)));
+    }
+
+    #[test]
+    fn item_actives_trigger_item_passives() {
+        let config = ScenarioConfig::default();
+        // Profane Hydra and Eclipse
+        let static_data =
+            data_input::parse_files(Champion::Khazix, &[6698, 6692], &config).unwrap();
+        let runes: HashSet<Rune> = HashSet::new();
+        let items = vec![&static_data.items_map[&6698], &static_data.items_map[&6692]];
+
+        let mut game_params: GameParams<'_> = GameParams {
+            champion: Champion::Khazix,
+            champion_data: &static_data.champion_data,
+            champion_stats: &static_data.base_champion_stats,
+            level: 18,
+            items: &items,
+            config: &config,
+            abilities: &static_data.abilities,
+            initial_target_stats: &TargetStats {
+                armor: 100.0,
+                armor_bonus: 0.0,
+                magic_resistance: 0.0,
+                magic_resistance_bonus: 0.0,
+                armor_reduction_flat: 0.0,
+                armor_reduction_perc: 0.0,
+                magic_resistance_reduction_flat: 0.0,
+                magic_resistance_reduction_perc: 0.0,
+                max_health: 10_000.0,
+                current_health: 10_000.0,
+            },
+            runes: &runes,
+            attacker_hp_perc: 100.0,
+            runes_data: &static_data.runes_data,
+            passive_effects: &mut Vec::new(),
+            crit_handling: CritHandlingChoice::Min,
+            initial_attacker_auras: &Vec::new(),
+            initial_target_auras: &Vec::new(),
+            abilities_extra_data: &static_data.abilities_extra_data,
+            start_time_ms: 0,
+            capture_event_history: false,
+            weave_auto_attacks: false,
+            battlefield: &Battlefield::default(),
+        };
+
+        compile_passive_effects(&mut game_params);
+
+        let (_, damage_history, _, _, _, _, _) = run(
+            VecDeque::from([AttackType::Q, AttackType::ProfaneHydra]),
+            &game_params,
+        )
+        .unwrap();
+
+        let hydra_damage = damage_history
+            .iter()
+            .find(|damage_info| damage_info.source_item == Some(Item::ProfaneHydra))
+            .unwrap();
+        assert_eq!(hydra_damage.source, DamageSource::ItemActive);
+        assert_eq!(hydra_damage.damage_type, DamageType::Physical);
+
+        // the Q and the active are the two hits needed by Eclipse
+        assert!(damage_history
+            .iter()
+            .any(|damage_info| damage_info.source_item == Some(Item::Eclipse)));
+    }
+
+    #[test]
+    fn run_returns_an_error_when_the_item_of_an_active_is_missing() {
+        let config = ScenarioConfig::default();
+        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config).unwrap();
+        let runes: HashSet<Rune> = HashSet::new();
+
+        let mut game_params: GameParams<'_> = GameParams {
+            champion: Champion::Khazix,
+            champion_data: &static_data.champion_data,
+            champion_stats: &static_data.base_champion_stats,
+            level: 18,
+            items: &Vec::new(),
+            config: &config,
+            abilities: &static_data.abilities,
+            initial_target_stats: &TargetStats {
+                armor: 100.0,
+                armor_bonus: 0.0,
+                magic_resistance: 0.0,
+                magic_resistance_bonus: 0.0,
+                armor_reduction_flat: 0.0,
+                armor_reduction_perc: 0.0,
+                magic_resistance_reduction_flat: 0.0,
+                magic_resistance_reduction_perc: 0.0,
+                max_health: 10_000.0,
+                current_health: 10_000.0,
+            },
+            runes: &runes,
+            attacker_hp_perc: 100.0,
+            runes_data: &static_data.runes_data,
+            passive_effects: &mut Vec::new(),
+            crit_handling: CritHandlingChoice::Min,
+            initial_attacker_auras: &Vec::new(),
+            initial_target_auras: &Vec::new(),
+            abilities_extra_data: &static_data.abilities_extra_data,
+            start_time_ms: 0,
+            capture_event_history: false,
+            weave_auto_attacks: false,
+            battlefield: &Battlefield::default(),
+        };
+
+        compile_passive_effects(&mut game_params);
+
+        let commands = VecDeque::from([AttackType::Q, AttackType::YoumuusGhostblade]);
+
+        assert_eq!(
+            run(commands, &game_params).unwrap_err(),
+            SimError::ItemNotEquipped {
+                command: AttackType::YoumuusGhostblade,
+                command_index: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn electrocute_triggers_on_the_third_hit() {
+        let config = ScenarioConfig::default();
+        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config).unwrap();
+        let runes: HashSet<Rune> = HashSet::from([Rune::Electrocute]);
+
+        let mut game_params: GameParams<'_> = GameParams {
+            champion: Champion::Khazix,
+            champion_data: &static_data.champion_data,
+            champion_stats: &static_data.base_champion_stats,
+            level: 18,
+            items: &Vec::new(),
+            config: &config,
+            abilities: &static_data.abilities,
+            initial_target_stats: &TargetStats {
+                armor: 100.0,
+                armor_bonus: 0.0,
+                magic_resistance: 0.0,
+                magic_resistance_bonus: 0.0,
+                armor_reduction_flat: 0.0,
+                armor_reduction_perc: 0.0,
+                magic_resistance_reduction_flat: 0.0,
+                magic_resistance_reduction_perc: 0.0,
+                max_health: 10_000.0,
+                current_health: 10_000.0,
+            },
+            runes: &runes,
+            attacker_hp_perc: 100.0,
+            runes_data: &static_data.runes_data,
+            passive_effects: &mut Vec::new(),
+            crit_handling: CritHandlingChoice::Min,
+            initial_attacker_auras: &Vec::new(),
+            initial_target_auras: &Vec::new(),
+            abilities_extra_data: &static_data.abilities_extra_data,
+            start_time_ms: 0,
+            capture_event_history: false,
+            weave_auto_attacks: false,
+            battlefield: &Battlefield::default(),
+        };
+
+        compile_passive_effects(&mut game_params);
+
+        let (_, damage_history, _, _, _, _, _) =
+            run(VecDeque::from([AttackType::AA; 6]), &game_params).unwrap();
+
+        // a single proc as the rune is then on cooldown
+        let electrocute_hits: Vec<usize> = damage_history
+            .iter()
+            .enumerate()
+            .filter(|(_, damage_info)| damage_info.source_rune == Some(Rune::Electrocute))
+            .map(|(index, _)| index)
+            .collect();
+        assert_eq!(electrocute_hits, vec![3]);
+        assert_eq!(damage_history[3].time_ms, damage_history[2].time_ms);
+        assert_eq!(damage_history[3].damage_type, DamageType::Physical);
+    }
+
+    #[test]
+    fn hail_of_blades_speeds_up_the_next_attacks() {
+        let config = ScenarioConfig::default();
+        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config).unwrap();
+        let runes: HashSet<Rune> = HashSet::from([Rune::HailOfBlades]);
+
+        let mut game_params: GameParams<'_> = GameParams {
+            champion: Champion::Khazix,
+            champion_data: &static_data.champion_data,
+            champion_stats: &static_data.base_champion_stats,
+            level: 18,
+            items: &Vec::new(),
+            config: &config,
+            abilities: &static_data.abilities,
+            initial_target_stats: &TargetStats {
+                armor: 100.0,
+                armor_bonus: 0.0,
+                magic_resistance: 0.0,
+                magic_resistance_bonus: 0.0,
+                armor_reduction_flat: 0.0,
+                armor_reduction_perc: 0.0,
+                magic_resistance_reduction_flat: 0.0,
+                magic_resistance_reduction_perc: 0.0,
+                max_health: 10_000.0,
+                current_health: 10_000.0,
+            },
+            runes: &runes,
+            attacker_hp_perc: 100.0,
+            runes_data: &static_data.runes_data,
+            passive_effects: &mut Vec::new(),
+            crit_handling: CritHandlingChoice::Min,
+            initial_attacker_auras: &Vec::new(),
+            initial_target_auras: &Vec::new(),
+            abilities_extra_data: &static_data.abilities_extra_data,
+            start_time_ms: 0,
+            capture_event_history: false,
+            weave_auto_attacks: false,
+            battlefield: &Battlefield::default(),
+        };
+
+        compile_passive_effects(&mut game_params);
+
+        let (_, damage_history, _, _, _, _, _) =
+            run(VecDeque::from([AttackType::AA; 5]), &game_params).unwrap();
+
+        assert!(damage_history
+            .iter()
+            .all(|damage_info| damage_info.source_ability == Some(AttackType::AA)));
+        let gaps: Vec<u64> = damage_history
+            .windows(2)
+            .map(|hits| hits[1].time_ms - hits[0].time_ms)
+            .collect();
+        // the attack triggering the rune started with the base attack speed. The two
+        // following attacks are empowered, the ones after are not anymore.
+        assert!(gaps[1] < gaps[2]);
+        assert!(gaps[2] < gaps[0]);
+        assert!(gaps[0] < gaps[3]);
+    }
+
+    #[test]
+    fn first_strike_amplifies_the_damage_of_the_window() {
+        let config = ScenarioConfig::default();
+        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config).unwrap();
+        let runes: HashSet<Rune> = HashSet::from([Rune::FirstStrike]);
+
+        let mut game_params: GameParams<'_> = GameParams {
+            champion: Champion::Khazix,
+            champion_data: &static_data.champion_data,
+            champion_stats: &static_data.base_champion_stats,
+            level: 18,
+            items: &Vec::new(),
+            config: &config,
+            abilities: &static_data.abilities,
+            initial_target_stats: &TargetStats {
+                armor: 100.0,
+                armor_bonus: 0.0,
+                magic_resistance: 0.0,
+                magic_resistance_bonus: 0.0,
+                armor_reduction_flat: 0.0,
+                armor_reduction_perc: 0.0,
+                magic_resistance_reduction_flat: 0.0,
+                magic_resistance_reduction_perc: 0.0,
+                max_health: 10_000.0,
+                current_health: 10_000.0,
+            },
+            runes: &runes,
+            attacker_hp_perc: 100.0,
+            runes_data: &static_data.runes_data,
+            passive_effects: &mut Vec::new(),
+            crit_handling: CritHandlingChoice::Min,
+            initial_attacker_auras: &Vec::new(),
+            initial_target_auras: &Vec::new(),
+            abilities_extra_data: &static_data.abilities_extra_data,
+            start_time_ms: 0,
+            capture_event_history: false,
+            weave_auto_attacks: false,
+            battlefield: &Battlefield::default(),
+        };
+
+        compile_passive_effects(&mut game_params);
+
+        let (_, damage_history, _, _, _, _, _) = run(
+            VecDeque::from([AttackType::Q, AttackType::AA]),
+            &game_params,
+        )
+        .unwrap();
+
+        // each hit is followed by 7% of its damage as true damage
+        assert_eq!(damage_history.len(), 4);
+        for pair in damage_history.chunks(2) {
+            let (hit, bonus) = (&pair[0], &pair[1]);
+            assert_eq!(hit.source, DamageSource::Ability);
+            assert_eq!(bonus.source_rune, Some(Rune::FirstStrike));
+            assert_eq!(bonus.damage_type, DamageType::True);
+            assert!((bonus.amount - hit.amount * 0.07).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn casts_are_delayed_until_the_mana_is_regenerated() {
+        let config = ScenarioConfig::default();
+        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config).unwrap();
+        let runes: HashSet<Rune> = HashSet::new();
+
+        let mut game_params: GameParams<'_> = GameParams {
+            champion: Champion::Khazix,
+            champion_data: &static_data.champion_data,
+            champion_stats: &static_data.base_champion_stats,
+            level: 18,
+            items: &Vec::new(),
+            config: &config,
+            abilities: &static_data.abilities,
+            initial_target_stats: &TargetStats {
+                armor: 0.0,
+                armor_bonus: 0.0,
+                magic_resistance: 0.0,
+                magic_resistance_bonus: 0.0,
+                armor_reduction_flat: 0.0,
+                armor_reduction_perc: 0.0,
+                magic_resistance_reduction_flat: 0.0,
+                magic_resistance_reduction_perc: 0.0,
+                max_health: 10_000_000.0,
+                current_health: 10_000_000.0,
+            },
+            runes: &runes,
+            attacker_hp_perc: 100.0,
+            runes_data: &static_data.runes_data,
+            passive_effects: &mut Vec::new(),
+            crit_handling: CritHandlingChoice::Min,
+            initial_attacker_auras: &Vec::new(),
+            initial_target_auras: &Vec::new(),
+            abilities_extra_data: &static_data.abilities_extra_data,
+            start_time_ms: 0,
+            capture_event_history: false,
+            weave_auto_attacks: false,
+            battlefield: &Battlefield::default(),
+        };
+
+        compile_passive_effects(&mut game_params);
+
+        // Q costs more mana than what is regenerated during its cooldown
+        let commands = VecDeque::from([AttackType::Q; 200]);
+        let (_, damage_history, _, _, _, _, _) = run(commands, &game_params).unwrap();
+        let gaps: Vec<u64> = damage_history
+            .windows(2)
+            .map(|damages| damages[1].time_ms - damages[0].time_ms)
+            .collect();
+
+        let q_ability = static_data
+            .abilities
+            .iter()
+            .find(|ability| ability.attack_type == AttackType::Q)
+            .unwrap();
+        let cast_time_ms = q_ability.cast_time_ms.unwrap_or_default();
+        let cooldown_ms = *q_ability.cooldown_ms.as_ref().unwrap().get(&5).unwrap();
+        let cost = *q_ability.cost.as_ref().unwrap().get(&5).unwrap();
+        let champion_stats = &static_data.base_champion_stats;
+        let mana_regen = (champion_stats.mana_regen_flat
+            + stat_increase(champion_stats.mana_regen_per_level, 18.0))
+            / 5.0;
+
+        // Q is cast on cooldown while the mana lasts
+        assert_eq!(gaps[0], cooldown_ms + cast_time_ms);
+        // then as soon as its cost has been regenerated
+        let starved_gap_ms = (cost / mana_regen * 1000.0).ceil() as u64;
+        assert!(starved_gap_ms > cooldown_ms + cast_time_ms);
+        assert!(gaps.last().unwrap().abs_diff(starved_gap_ms) <= 1);
+    }
+
+    #[test]
+    fn heals_are_recorded_in_the_heal_history() {
+        let config = ScenarioConfig::default();
+        // Bloodthirster and Death's Dance
+        let static_data =
+            data_input::parse_files(Champion::Khazix, &[3072, 6333], &config).unwrap();
+        let runes: HashSet<Rune> = HashSet::new();
+        let items = vec![&static_data.items_map[&3072], &static_data.items_map[&6333]];
+        let target_stats = TargetStats {
+            armor: 0.0,
+            armor_bonus: 0.0,
+            magic_resistance: 0.0,
+            magic_resistance_bonus: 0.0,
+            armor_reduction_flat: 0.0,
+            armor_reduction_perc: 0.0,
+            magic_resistance_reduction_flat: 0.0,
+            magic_resistance_reduction_perc: 0.0,
+            max_health: 1_000.0,
+            current_health: 1_000.0,
+        };
+        // the fight goes on after the kill, so that the heal of Death's Dance has the time
+        // to tick
+        let battlefield = Battlefield {
+            secondary_targets: vec![SecondaryTarget {
+                stats: target_stats.clone(),
+                position: Position { x: 2_000.0, y: 0.0 },
+            }],
+            continue_after_kill: true,
+            ..Default::default()
+        };
+
+        let mut game_params: GameParams<'_> = GameParams {
+            champion: Champion::Khazix,
+            champion_data: &static_data.champion_data,
+            champion_stats: &static_data.base_champion_stats,
+            level: 18,
+            items: &items,
+            config: &config,
+            abilities: &static_data.abilities,
+            initial_target_stats: &target_stats,
+            runes: &runes,
+            attacker_hp_perc: 10.0,
+            runes_data: &static_data.runes_data,
+            passive_effects: &mut Vec::new(),
+            crit_handling: CritHandlingChoice::Min,
+            initial_attacker_auras: &Vec::new(),
+            initial_target_auras: &Vec::new(),
+            abilities_extra_data: &static_data.abilities_extra_data,
+            start_time_ms: 0,
+            capture_event_history: false,
+            weave_auto_attacks: false,
+            battlefield: &battlefield,
+        };
+
+        compile_passive_effects(&mut game_params);
+
+        let commands = VecDeque::from([
+            AttackType::AA,
+            AttackType::W,
+            AttackType::AA,
+            AttackType::AA,
+            AttackType::AA,
+            AttackType::AA,
+        ]);
+        let (_, damage_history, heal_history, _, _, kill_time_ms, kill) =
+            run(commands, &game_params).unwrap();
+        assert!(kill);
+
+        // the life steal of Bloodthirster heals for a part of the auto attacks
+        assert_eq!(heal_history[0].source, HealSource::LifeSteal);
+        assert!((heal_history[0].amount - 0.15 * damage_history[0].amount).abs() < 1e-9);
+
+        // Void Spike at rank 5, without any AP
+        let w_heal = heal_history
+            .iter()
+            .find(|heal_info| heal_info.source_ability == Some(AttackType::W))
+            .unwrap();
+        assert_eq!(w_heal.source, HealSource::Ability);
+        assert_eq!(w_heal.amount, 135.0);
+
+        // the takedown heals through Death's Dance, over 2 seconds
+        let defy_heals: Vec<&HealInfo> = heal_history
+            .iter()
+            .filter(|heal_info| heal_info.source_item == Some(Item::DeathsDance))
+            .collect();
+        assert_eq!(defy_heals.len(), 8);
+        assert!(defy_heals
+            .iter()
+            .all(|heal_info| !heal_info.is_shield && heal_info.amount == defy_heals[0].amount));
+        assert_eq!(defy_heals[0].time_ms, kill_time_ms + 250);
+        assert_eq!(defy_heals[7].time_ms, kill_time_ms + 2_000);
+    }
+
+    #[test]
+    fn life_steal_overheal_becomes_a_shield_with_bloodthirster() {
+        let config = ScenarioConfig::default();
+        let static_data = data_input::parse_files(Champion::Khazix, &[3072], &config).unwrap();
+        let runes: HashSet<Rune> = HashSet::new();
+        let items = vec![&static_data.items_map[&3072]];
+
+        let mut game_params: GameParams<'_> = GameParams {
+            champion: Champion::Khazix,
+            champion_data: &static_data.champion_data,
+            champion_stats: &static_data.base_champion_stats,
+            level: 18,
+            items: &items,
+            config: &config,
+            abilities: &static_data.abilities,
+            initial_target_stats: &TargetStats {
+                armor: 0.0,
+                armor_bonus: 0.0,
+                magic_resistance: 0.0,
+                magic_resistance_bonus: 0.0,
+                armor_reduction_flat: 0.0,
+                armor_reduction_perc: 0.0,
+                magic_resistance_reduction_flat: 0.0,
+                magic_resistance_reduction_perc: 0.0,
+                max_health: 10_000.0,
+                current_health: 10_000.0,
+            },
+            runes: &runes,
+            attacker_hp_perc: 100.0,
+            runes_data: &static_data.runes_data,
+            passive_effects: &mut Vec::new(),
+            crit_handling: CritHandlingChoice::Min,
+            initial_attacker_auras: &Vec::new(),
+            initial_target_auras: &Vec::new(),
+            abilities_extra_data: &static_data.abilities_extra_data,
+            start_time_ms: 0,
+            capture_event_history: false,
+            weave_auto_attacks: false,
+            battlefield: &Battlefield::default(),
+        };
+
+        compile_passive_effects(&mut game_params);
+
+        let (_, damage_history, heal_history, _, _, _, _) =
+            run(VecDeque::from([AttackType::AA]), &game_params).unwrap();
+
+        // at full health, the whole life steal is converted into Ichorshield
+        assert_eq!(heal_history.len(), 1);
+        assert!(heal_history[0].is_shield);
+        assert_eq!(heal_history[0].source_item, Some(Item::Bloodthirster));
+        assert!((heal_history[0].amount - 0.15 * damage_history[0].amount).abs() < 1e-9);
+    }
+
+    #[test]
+    fn area_abilities_and_cleave_hit_the_secondary_targets_in_range() {
+        let config = ScenarioConfig::default();
+        let static_data = data_input::parse_files(Champion::Khazix, &[6698], &config).unwrap();
+        let runes: HashSet<Rune> = HashSet::new();
+        let items = vec![&static_data.items_map[&6698]];
+        let target_stats = TargetStats {
+            armor: 0.0,
+            armor_bonus: 0.0,
+            magic_resistance: 0.0,
+            magic_resistance_bonus: 0.0,
+            armor_reduction_flat: 0.0,
+            armor_reduction_perc: 0.0,
+            magic_resistance_reduction_flat: 0.0,
+            magic_resistance_reduction_perc: 0.0,
+            max_health: 10_000.0,
+            current_health: 10_000.0,
+        };
+        let battlefield = Battlefield {
+            secondary_targets: vec![
+                SecondaryTarget {
+                    stats: target_stats.clone(),
+                    position: Position { x: 200.0, y: 0.0 },
+                },
+                SecondaryTarget {
+                    stats: target_stats.clone(),
+                    position: Position { x: 1_000.0, y: 0.0 },
+                },
+            ],
+            ..Default::default()
+        };
+
+        let mut game_params: GameParams<'_> = GameParams {
+            champion: Champion::Khazix,
+            champion_data: &static_data.champion_data,
+            champion_stats: &static_data.base_champion_stats,
+            level: 18,
+            items: &items,
+            config: &config,
+            abilities: &static_data.abilities,
+            initial_target_stats: &target_stats,
+            runes: &runes,
+            attacker_hp_perc: 100.0,
+            runes_data: &static_data.runes_data,
+            passive_effects: &mut Vec::new(),
+            crit_handling: CritHandlingChoice::Min,
+            initial_attacker_auras: &Vec::new(),
+            initial_target_auras: &Vec::new(),
+            abilities_extra_data: &static_data.abilities_extra_data,
+            start_time_ms: 0,
+            capture_event_history: false,
+            weave_auto_attacks: false,
+            battlefield: &battlefield,
+        };
+
+        compile_passive_effects(&mut game_params);
+
+        let (_, damage_history, _, _, _, _, _) = run(
+            VecDeque::from([AttackType::AA, AttackType::E]),
+            &game_params,
+        )
+        .unwrap();
+
+        let secondary_damage: Vec<&DamageInfo> = damage_history
+            .iter()
+            .filter(|damage_info| damage_info.target_unit != Unit::Target)
+            .collect();
+
+        // the far target is out of range of both
+        assert!(secondary_damage
+            .iter()
+            .all(|damage_info| damage_info.target_unit == Unit::SecondaryTarget(0)));
+
+        // Profane Hydra cleaves 40% of the attack damage of a melee attacker
+        let cleave = secondary_damage
+            .iter()
+            .find(|damage_info| damage_info.source_item == Some(Item::ProfaneHydra))
+            .unwrap();
+        let auto_attack = &damage_history[0];
+        assert_eq!(auto_attack.target_unit, Unit::Target);
+        assert!((cleave.amount - 0.4 * auto_attack.amount).abs() < 1e-9);
+        assert!(secondary_damage
+            .iter()
+            .any(|damage_info| damage_info.source_ability == Some(AttackType::E)));
+
+        let results = crate::area::secondary_target_results(&damage_history, &battlefield);
+        assert!(results[0].damage > 0.0);
+        assert_eq!(results[1].damage, 0.0);
+    }
+
+    #[test]
+    fn q_deals_increased_damage_when_no_secondary_target_stands_nearby() {
+        let mut config = ScenarioConfig::default();
+        config.champion.khazix.q_evolved = true;
+        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config).unwrap();
+        let runes: HashSet<Rune> = HashSet::new();
+        let target_stats = TargetStats {
+            armor: 0.0,
+            armor_bonus: 0.0,
+            magic_resistance: 0.0,
+            magic_resistance_bonus: 0.0,
+            armor_reduction_flat: 0.0,
+            armor_reduction_perc: 0.0,
+            magic_resistance_reduction_flat: 0.0,
+            magic_resistance_reduction_perc: 0.0,
+            max_health: 10_000.0,
+            current_health: 10_000.0,
+        };
+
+        // first Q damage and time of the second Q, for a secondary target at the given distance
+        let first_q_and_recast_ms = |distance: f64| {
+            let battlefield = Battlefield {
+                secondary_targets: vec![SecondaryTarget {
+                    stats: target_stats.clone(),
+                    position: Position {
+                        x: distance,
+                        y: 0.0,
+                    },
+                }],
+                ..Default::default()
+            };
+            let mut game_params: GameParams<'_> = GameParams {
+                champion: Champion::Khazix,
+                champion_data: &static_data.champion_data,
+                champion_stats: &static_data.base_champion_stats,
+                level: 1,
+                items: &Vec::new(),
+                config: &config,
+                abilities: &static_data.abilities,
+                initial_target_stats: &target_stats,
+                runes: &runes,
+                attacker_hp_perc: 100.0,
+                runes_data: &static_data.runes_data,
+                passive_effects: &mut Vec::new(),
+                crit_handling: CritHandlingChoice::Min,
+                initial_attacker_auras: &Vec::new(),
+                initial_target_auras: &Vec::new(),
+                abilities_extra_data: &static_data.abilities_extra_data,
+                start_time_ms: 0,
+                capture_event_history: false,
+                weave_auto_attacks: false,
+                battlefield: &battlefield,
+            };
+
+            compile_passive_effects(&mut game_params);
+
+            let (_, damage_history, _, _, _, _, _) =
+                run(VecDeque::from([AttackType::Q, AttackType::Q]), &game_params).unwrap();
+            let q_damage: Vec<&DamageInfo> = damage_history
+                .iter()
+                .filter(|damage_info| damage_info.source_ability == Some(AttackType::Q))
+                .collect();
+
+            (q_damage[0].amount, q_damage[1].time_ms)
+        };
+
+        let (crowded_damage, crowded_recast_ms) = first_q_and_recast_ms(300.0);
+        let (isolated_damage, isolated_recast_ms) = first_q_and_recast_ms(500.0);
+
+        assert!(isolated_damage > crowded_damage);
+        // the evolved Q refunds a part of its cooldown against isolated targets only
+        assert!(isolated_recast_ms < crowded_recast_ms);
+    }
+
+    #[test]
+    fn the_fight_goes_on_with_the_next_target_and_the_evolved_e_resets_on_takedown() {
+        let target_stats = |health: f64| TargetStats {
+            armor: 0.0,
+            armor_bonus: 0.0,
+            magic_resistance: 0.0,
+            magic_resistance_bonus: 0.0,
+            armor_reduction_flat: 0.0,
+            armor_reduction_perc: 0.0,
+            magic_resistance_reduction_flat: 0.0,
+            magic_resistance_reduction_perc: 0.0,
+            max_health: 10_000.0,
+            current_health: health,
+        };
+        let battlefield = Battlefield {
+            secondary_targets: vec![SecondaryTarget {
+                stats: target_stats(10_000.0),
+                position: Position { x: 500.0, y: 0.0 },
+            }],
+            continue_after_kill: true,
+            ..Default::default()
+        };
+
+        // E kills the primary target, then E again
+        let e_damage = |e_evolved: bool| {
+            let mut config = ScenarioConfig::default();
+            config.champion.khazix.e_evolved = e_evolved;
+            let static_data =
+                data_input::parse_files(Champion::Khazix, &Vec::new(), &config).unwrap();
+            let runes: HashSet<Rune> = HashSet::new();
+
+            let mut game_params: GameParams<'_> = GameParams {
+                champion: Champion::Khazix,
+                champion_data: &static_data.champion_data,
+                champion_stats: &static_data.base_champion_stats,
+                level: 18,
+                items: &Vec::new(),
+                config: &config,
+                abilities: &static_data.abilities,
+                initial_target_stats: &target_stats(1.0),
+                runes: &runes,
+                attacker_hp_perc: 100.0,
+                runes_data: &static_data.runes_data,
+                passive_effects: &mut Vec::new(),
+                crit_handling: CritHandlingChoice::Min,
+                initial_attacker_auras: &Vec::new(),
+                initial_target_auras: &Vec::new(),
+                abilities_extra_data: &static_data.abilities_extra_data,
+                start_time_ms: 0,
+                capture_event_history: false,
+                weave_auto_attacks: false,
+                battlefield: &battlefield,
+            };
+
+            compile_passive_effects(&mut game_params);
+
+            let (_, damage_history, _, _, _, time_ms, kill) =
+                run(VecDeque::from([AttackType::E, AttackType::E]), &game_params).unwrap();
+            assert!(kill);
+
+            let e_damage = damage_history
+                .into_iter()
+                .filter(|damage_info| damage_info.source_ability == Some(AttackType::E))
+                .collect::<Vec<DamageInfo>>();
+            // the kill time is the one of the primary target, killed by the first E
+            assert_eq!(time_ms, e_damage[0].time_ms);
+
+            (e_damage, time_ms)
+        };
+
+        let (reset, _) = e_damage(true);
+        assert_eq!(reset.len(), 2);
+        assert_eq!(reset[0].target_unit, Unit::Target);
+        assert_eq!(reset[1].target_unit, Unit::SecondaryTarget(0));
+
+        let (no_reset, kill_time_ms) = e_damage(false);
+        assert_eq!(no_reset[1].target_unit, Unit::SecondaryTarget(0));
+        assert!(reset[1].time_ms < no_reset[1].time_ms);
+        // the fight went on after the kill
+        assert!(kill_time_ms < no_reset[1].time_ms);
+    }
+
+    #[test]
+    fn the_attacker_walks_or_leaps_to_a_distant_target() {
+        let config = ScenarioConfig::default();
+        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config).unwrap();
+        let runes: HashSet<Rune> = HashSet::new();
+        let target_stats = TargetStats {
+            armor: 0.0,
+            armor_bonus: 0.0,
+            magic_resistance: 0.0,
+            magic_resistance_bonus: 0.0,
+            armor_reduction_flat: 0.0,
+            armor_reduction_perc: 0.0,
+            magic_resistance_reduction_flat: 0.0,
+            magic_resistance_reduction_perc: 0.0,
+            max_health: 10_000.0,
+            current_health: 10_000.0,
+        };
+
+        // time of each hit on the target, for an attacker starting at the given distance
+        let hit_times_ms = |distance: f64, target_movement_speed: f64, commands: &[AttackType]| {
+            let battlefield = Battlefield {
+                attacker_position: Position {
+                    x: -distance,
+                    y: 0.0,
+                },
+                target_movement_speed,
+                ..Default::default()
+            };
+            let mut game_params: GameParams<'_> = GameParams {
+                champion: Champion::Khazix,
+                champion_data: &static_data.champion_data,
+                champion_stats: &static_data.base_champion_stats,
+                level: 18,
+                items: &Vec::new(),
+                config: &config,
+                abilities: &static_data.abilities,
+                initial_target_stats: &target_stats,
+                runes: &runes,
+                attacker_hp_perc: 100.0,
+                runes_data: &static_data.runes_data,
+                passive_effects: &mut Vec::new(),
+                crit_handling: CritHandlingChoice::Min,
+                initial_attacker_auras: &Vec::new(),
+                initial_target_auras: &Vec::new(),
+                abilities_extra_data: &static_data.abilities_extra_data,
+                start_time_ms: 0,
+                capture_event_history: false,
+                weave_auto_attacks: false,
+                battlefield: &battlefield,
+            };
+
+            compile_passive_effects(&mut game_params);
+
+            run(VecDeque::from(commands.to_vec()), &game_params).map(
+                |(_, damage_history, _, _, _, _, _)| {
+                    damage_history
+                        .iter()
+                        .filter(|damage_info| damage_info.source_ability.is_some())
+                        .map(|damage_info| damage_info.time_ms)
+                        .collect::<Vec<u64>>()
+                },
+            )
+        };
+
+        // 575 units to walk at 350 movement speed before the first attack
+        let in_range = hit_times_ms(125.0, 0.0, &[AttackType::AA]).unwrap();
+        let walking = hit_times_ms(700.0, 0.0, &[AttackType::AA]).unwrap();
+        assert_eq!(walking[0], in_range[0] + 1_643);
+
+        // a target running away at 175 halves the closing speed
+        let chasing = hit_times_ms(700.0, 175.0, &[AttackType::AA]).unwrap();
+        assert_eq!(chasing[0], in_range[0] + 3_286);
+
+        // the target runs away faster than the attacker
+        assert!(matches!(
+            hit_times_ms(700.0, 400.0, &[AttackType::AA]),
+            Err(SimError::TargetOutOfReach { .. })
+        ));
+
+        // E leaps from 700 units away and lands within attack range
+        let in_range = hit_times_ms(125.0, 0.0, &[AttackType::E, AttackType::AA]).unwrap();
+        let leaping = hit_times_ms(700.0, 0.0, &[AttackType::E, AttackType::AA]).unwrap();
+        assert_eq!(leaping, in_range);
+    }
+
+    #[test]
+    fn evolved_void_spike_slows_isolated_targets_more_and_heals_within_its_explosion() {
+        let static_data =
+            data_input::parse_files(Champion::Khazix, &Vec::new(), &ScenarioConfig::default())
+                .unwrap();
+        let runes: HashSet<Rune> = HashSet::new();
+        let target_stats = TargetStats {
+            armor: 0.0,
+            armor_bonus: 0.0,
+            magic_resistance: 0.0,
+            magic_resistance_bonus: 0.0,
+            armor_reduction_flat: 0.0,
+            armor_reduction_perc: 0.0,
+            magic_resistance_reduction_flat: 0.0,
+            magic_resistance_reduction_perc: 0.0,
+            max_health: 10_000.0,
+            current_health: 10_000.0,
+        };
+
+        // time of the auto attack following W, and heal of W, for an attacker starting at the
+        // given distance from a target running away at 300
+        let w_then_aa = |distance: f64, allies_nearby: bool| {
+            let mut config = ScenarioConfig::default();
+            config.champion.khazix.w_evolved = true;
+            config.champion.khazix.allies_nearby = allies_nearby;
+            let battlefield = Battlefield {
+                attacker_position: Position {
+                    x: -distance,
+                    y: 0.0,
+                },
+                target_movement_speed: 300.0,
+                ..Default::default()
+            };
+            let mut game_params: GameParams<'_> = GameParams {
+                champion: Champion::Khazix,
+                champion_data: &static_data.champion_data,
+                champion_stats: &static_data.base_champion_stats,
+                level: 18,
+                items: &Vec::new(),
+                config: &config,
+                abilities: &static_data.abilities,
+                initial_target_stats: &target_stats,
+                runes: &runes,
+                attacker_hp_perc: 50.0,
+                runes_data: &static_data.runes_data,
+                passive_effects: &mut Vec::new(),
+                crit_handling: CritHandlingChoice::Min,
+                initial_attacker_auras: &Vec::new(),
+                initial_target_auras: &Vec::new(),
+                abilities_extra_data: &static_data.abilities_extra_data,
+                start_time_ms: 0,
+                capture_event_history: false,
+                weave_auto_attacks: false,
+                battlefield: &battlefield,
+            };
+
+            compile_passive_effects(&mut game_params);
+
+            let (_, damage_history, heal_history, _, _, _, _) = run(
+                VecDeque::from([AttackType::W, AttackType::AA]),
+                &game_params,
+            )
+            .unwrap();
+            let aa_time_ms = damage_history
+                .iter()
+                .find(|damage_info| damage_info.source_ability == Some(AttackType::AA))
+                .unwrap()
+                .time_ms;
+            let w_heal: f64 = heal_history
+                .iter()
+                .filter(|heal_info| heal_info.source_ability == Some(AttackType::W))
+                .map(|heal_info| heal_info.amount)
+                .sum();
+
+            (aa_time_ms, w_heal)
+        };
+
+        // slowed by 60% when isolated, by 40% otherwise
+        let (isolated_aa_ms, _) = w_then_aa(600.0, false);
+        let (escorted_aa_ms, _) = w_then_aa(600.0, true);
+        assert!(isolated_aa_ms < escorted_aa_ms);
+
+        // the explosion around the target only heals an attacker standing within it
+        assert_eq!(w_then_aa(125.0, false).1, 135.0);
+        assert_eq!(w_then_aa(600.0, false).1, 0.0);
+    }
+
+    #[test]
+    fn ravenous_hydra_is_cast_once_the_target_is_within_its_cone() {
+        let config = ScenarioConfig::default();
+        let static_data = data_input::parse_files(Champion::Khazix, &[3074], &config).unwrap();
+        let runes: HashSet<Rune> = HashSet::new();
+        let items = vec![&static_data.items_map[&3074]];
+        let target_stats = TargetStats {
+            armor: 0.0,
+            armor_bonus: 0.0,
+            magic_resistance: 0.0,
+            magic_resistance_bonus: 0.0,
+            armor_reduction_flat: 0.0,
+            armor_reduction_perc: 0.0,
+            magic_resistance_reduction_flat: 0.0,
+            magic_resistance_reduction_perc: 0.0,
+            max_health: 10_000.0,
+            current_health: 10_000.0,
+        };
+        let battlefield = Battlefield {
+            attacker_position: Position { x: -700.0, y: 0.0 },
+            ..Default::default()
+        };
+
+        let mut game_params: GameParams<'_> = GameParams {
+            champion: Champion::Khazix,
+            champion_data: &static_data.champion_data,
+            champion_stats: &static_data.base_champion_stats,
+            level: 18,
+            items: &items,
+            config: &config,
+            abilities: &static_data.abilities,
+            initial_target_stats: &target_stats,
+            runes: &runes,
+            attacker_hp_perc: 100.0,
+            runes_data: &static_data.runes_data,
+            passive_effects: &mut Vec::new(),
+            crit_handling: CritHandlingChoice::Min,
+            initial_attacker_auras: &Vec::new(),
+            initial_target_auras: &Vec::new(),
+            abilities_extra_data: &static_data.abilities_extra_data,
+            start_time_ms: 0,
+            capture_event_history: false,
+            weave_auto_attacks: false,
+            battlefield: &battlefield,
+        };
+
+        compile_passive_effects(&mut game_params);
+
+        let (_, damage_history, _, _, _, _, _) =
+            run(VecDeque::from([AttackType::RavenousHydra]), &game_params).unwrap();
+        let active = damage_history
+            .iter()
+            .find(|damage_info| damage_info.source == DamageSource::ItemActive)
+            .unwrap();
+
+        // 250 units to walk at 350 movement speed before the cone reaches the target
+        assert_eq!(active.time_ms, 715);
+    }
+
+    #[test]
+    fn parse_files_rejects_unknown_items() {
+        let config = ScenarioConfig::default();
+
+        assert_eq!(
+            data_input::parse_files(Champion::Khazix, &[42], &config).err(),
+            Some(SimError::UnknownItem { item_id: 42 })
         );
     }
 
@@ -1655,9 +3877,10 @@ mod integration_tests {
 #[cfg(test)]
 mod insert_next_attack_event_tests {
     use super::*;
+    use crate::area::{Battlefield, Position};
     use crate::data_input::{
         self,
-        common::{Aura, AuraApplication, CritHandlingChoice, GameParams, TargetStats},
+        common::{CritHandlingChoice, GameParams, TargetStats},
     };
     use std::collections::{HashMap, HashSet, VecDeque};
 
@@ -1670,20 +3893,31 @@ mod insert_next_attack_event_tests {
             time_ms: current_time_ms,
             cooldowns: &mut HashMap::new(),
             last_attack_time_ms: 0,
+            kill_time_ms: None,
             effects_cooldowns: &mut HashMap::new(),
-            config: &mut HashMap::new(),
+            config: &mut ScenarioConfig::default(),
             attacker_auras: &mut HashMap::new(),
             target_auras: &mut HashMap::new(),
             damage_history: &mut Vec::new(),
+            heal_history: &mut Vec::new(),
+            secondary_targets: &mut Vec::new(),
+            target: Unit::Target,
+            attacker_position: area::DEFAULT_ATTACKER_POSITION,
+            target_position: Position::default(),
             event_history: &mut Vec::new(),
             attack_history: &mut Vec::new(),
             recast_charges: &mut Vec::new(),
             recast_ready: &mut HashSet::new(),
+            recharges: &mut HashMap::new(),
+            mana: 1_000.0,
+            attacker_health: 1_000.0,
             is_casting: false,
+            is_idle: false,
+            rng: &mut ChaCha8Rng::seed_from_u64(0),
         };
 
-        let config = HashMap::new();
-        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config);
+        let config = ScenarioConfig::default();
+        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config).unwrap();
 
         let mut runes: HashSet<Rune> = HashSet::new();
         runes.insert(Rune::DarkHarvest);
@@ -1699,11 +3933,17 @@ mod insert_next_attack_event_tests {
             champion_stats: &static_data.base_champion_stats,
             level: 18,
             items: &Vec::new(),
-            initial_config: &config,
+            config: &config,
             abilities: &static_data.abilities,
             initial_target_stats: &TargetStats {
                 armor: 0.0,
+                armor_bonus: 0.0,
                 magic_resistance: 0.0,
+                magic_resistance_bonus: 0.0,
+                armor_reduction_flat: 0.0,
+                armor_reduction_perc: 0.0,
+                magic_resistance_reduction_flat: 0.0,
+                magic_resistance_reduction_perc: 0.0,
                 max_health: 1000.0,
                 current_health: 1000.0,
             },
@@ -1718,6 +3958,7 @@ mod insert_next_attack_event_tests {
             start_time_ms: 0,
             capture_event_history: false,
             weave_auto_attacks: false,
+            battlefield: &Battlefield::default(),
         };
 
         let events: &mut BinaryHeap<Event> = &mut BinaryHeap::new();
@@ -1750,20 +3991,31 @@ mod insert_next_attack_event_tests {
             time_ms: current_time_ms,
             cooldowns: &mut HashMap::new(),
             last_attack_time_ms: 0,
+            kill_time_ms: None,
             effects_cooldowns: &mut HashMap::new(),
-            config: &mut HashMap::new(),
+            config: &mut ScenarioConfig::default(),
             attacker_auras: &mut HashMap::new(),
             target_auras: &mut HashMap::new(),
             damage_history: &mut Vec::new(),
+            heal_history: &mut Vec::new(),
+            secondary_targets: &mut Vec::new(),
+            target: Unit::Target,
+            attacker_position: area::DEFAULT_ATTACKER_POSITION,
+            target_position: Position::default(),
             event_history: &mut Vec::new(),
             attack_history: &mut Vec::new(),
             recast_charges: &mut Vec::new(),
             recast_ready: &mut HashSet::new(),
+            recharges: &mut HashMap::new(),
+            mana: 1_000.0,
+            attacker_health: 1_000.0,
             is_casting: false,
+            is_idle: false,
+            rng: &mut ChaCha8Rng::seed_from_u64(0),
         };
 
-        let config = HashMap::new();
-        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config);
+        let config = ScenarioConfig::default();
+        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config).unwrap();
 
         let mut runes: HashSet<Rune> = HashSet::new();
         runes.insert(Rune::DarkHarvest);
@@ -1779,11 +4031,17 @@ mod insert_next_attack_event_tests {
             champion_stats: &static_data.base_champion_stats,
             level: 18,
             items: &Vec::new(),
-            initial_config: &config,
+            config: &config,
             abilities: &static_data.abilities,
             initial_target_stats: &TargetStats {
                 armor: 0.0,
+                armor_bonus: 0.0,
                 magic_resistance: 0.0,
+                magic_resistance_bonus: 0.0,
+                armor_reduction_flat: 0.0,
+                armor_reduction_perc: 0.0,
+                magic_resistance_reduction_flat: 0.0,
+                magic_resistance_reduction_perc: 0.0,
                 max_health: 1000.0,
                 current_health: 1000.0,
             },
@@ -1798,6 +4056,7 @@ mod insert_next_attack_event_tests {
             start_time_ms: 0,
             capture_event_history: false,
             weave_auto_attacks: true,
+            battlefield: &Battlefield::default(),
         };
 
         let attacker_stats = compute_attacker_stats(&game_params, &state);
@@ -1839,20 +4098,31 @@ mod insert_next_attack_event_tests {
             time_ms: current_time_ms,
             cooldowns: &mut HashMap::new(),
             last_attack_time_ms: 0,
+            kill_time_ms: None,
             effects_cooldowns: &mut HashMap::new(),
-            config: &mut HashMap::new(),
+            config: &mut ScenarioConfig::default(),
             attacker_auras: &mut HashMap::new(),
             target_auras: &mut HashMap::new(),
             damage_history: &mut Vec::new(),
+            heal_history: &mut Vec::new(),
+            secondary_targets: &mut Vec::new(),
+            target: Unit::Target,
+            attacker_position: area::DEFAULT_ATTACKER_POSITION,
+            target_position: Position::default(),
             event_history: &mut Vec::new(),
             attack_history: &mut Vec::new(),
             recast_charges: &mut Vec::new(),
             recast_ready: &mut HashSet::new(),
+            recharges: &mut HashMap::new(),
+            mana: 1_000.0,
+            attacker_health: 1_000.0,
             is_casting: false,
+            is_idle: false,
+            rng: &mut ChaCha8Rng::seed_from_u64(0),
         };
 
-        let config = HashMap::new();
-        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config);
+        let config = ScenarioConfig::default();
+        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config).unwrap();
 
         let mut runes: HashSet<Rune> = HashSet::new();
         runes.insert(Rune::DarkHarvest);
@@ -1868,11 +4138,17 @@ mod insert_next_attack_event_tests {
             champion_stats: &static_data.base_champion_stats,
             level: 18,
             items: &Vec::new(),
-            initial_config: &config,
+            config: &config,
             abilities: &static_data.abilities,
             initial_target_stats: &TargetStats {
                 armor: 0.0,
+                armor_bonus: 0.0,
                 magic_resistance: 0.0,
+                magic_resistance_bonus: 0.0,
+                armor_reduction_flat: 0.0,
+                armor_reduction_perc: 0.0,
+                magic_resistance_reduction_flat: 0.0,
+                magic_resistance_reduction_perc: 0.0,
                 max_health: 1000.0,
                 current_health: 1000.0,
             },
@@ -1887,6 +4163,7 @@ mod insert_next_attack_event_tests {
             start_time_ms: 0,
             capture_event_history: false,
             weave_auto_attacks: true,
+            battlefield: &Battlefield::default(),
         };
 
         let attacker_stats = compute_attacker_stats(&game_params, &state);
@@ -1929,20 +4206,31 @@ mod insert_next_attack_event_tests {
             time_ms: current_time_ms,
             cooldowns: &mut HashMap::new(),
             last_attack_time_ms: 0,
+            kill_time_ms: None,
             effects_cooldowns: &mut HashMap::new(),
-            config: &mut HashMap::new(),
+            config: &mut ScenarioConfig::default(),
             attacker_auras: &mut HashMap::new(),
             target_auras: &mut HashMap::new(),
             damage_history: &mut Vec::new(),
+            heal_history: &mut Vec::new(),
+            secondary_targets: &mut Vec::new(),
+            target: Unit::Target,
+            attacker_position: area::DEFAULT_ATTACKER_POSITION,
+            target_position: Position::default(),
             event_history: &mut Vec::new(),
             attack_history: &mut Vec::new(),
             recast_charges: &mut Vec::new(),
             recast_ready: &mut HashSet::new(),
+            recharges: &mut HashMap::new(),
+            mana: 1_000.0,
+            attacker_health: 1_000.0,
             is_casting: false,
+            is_idle: false,
+            rng: &mut ChaCha8Rng::seed_from_u64(0),
         };
 
-        let config = HashMap::new();
-        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config);
+        let config = ScenarioConfig::default();
+        let static_data = data_input::parse_files(Champion::Khazix, &Vec::new(), &config).unwrap();
 
         let mut runes: HashSet<Rune> = HashSet::new();
         runes.insert(Rune::DarkHarvest);
@@ -1958,11 +4246,17 @@ mod insert_next_attack_event_tests {
             champion_stats: &static_data.base_champion_stats,
             level: 18,
             items: &Vec::new(),
-            initial_config: &config,
+            config: &config,
             abilities: &static_data.abilities,
             initial_target_stats: &TargetStats {
                 armor: 0.0,
+                armor_bonus: 0.0,
                 magic_resistance: 0.0,
+                magic_resistance_bonus: 0.0,
+                armor_reduction_flat: 0.0,
+                armor_reduction_perc: 0.0,
+                magic_resistance_reduction_flat: 0.0,
+                magic_resistance_reduction_perc: 0.0,
                 max_health: 1000.0,
                 current_health: 1000.0,
             },
@@ -1977,6 +4271,7 @@ mod insert_next_attack_event_tests {
             start_time_ms: 0,
             capture_event_history: false,
             weave_auto_attacks: true,
+            battlefield: &Battlefield::default(),
         };
 
         let attacker_stats = compute_attacker_stats(&game_params, &state);
@@ -58,6 +58,8 @@ pub struct Stats {
     pub cooldown_reduction: Option<StatDetails>,
     #[serde(rename(deserialize = "criticalStrikeChance"))]
     pub critical_strike_chance: Option<StatDetails>,
+    #[serde(rename(deserialize = "criticalStrikeDamage"))]
+    pub critical_strike_damage: Option<StatDetails>,
     #[serde(rename(deserialize = "goldPer10"))]
     pub gold_per10: Option<StatDetails>,
     #[serde(rename(deserialize = "healAndShieldPower"))]